@@ -0,0 +1,60 @@
+//! Benchmarks `calculate_enhanced_stats` over a synthetic diary large enough
+//! (5,000 entries) to be representative of the heaviest real users, since the
+//! genre/director/yearly breakdowns each walk the full diary and allocate a
+//! `HashMap` per pass.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use lbxd::letterboxd_client_rust::LetterboxdClient;
+use lbxd::profile::{DetailedMovie, UserMovieEntry};
+
+fn synthetic_diary(size: usize) -> Vec<UserMovieEntry> {
+    (0..size)
+        .map(|i| UserMovieEntry {
+            movie: DetailedMovie {
+                title: format!("Movie {}", i),
+                year: Some(1990 + (i % 35) as u16),
+                director: Some(format!("Director {}", i % 200)),
+                genres: vec![
+                    format!("Genre {}", i % 20),
+                    format!("Genre {}", (i + 1) % 20),
+                ],
+                runtime: Some(90 + (i % 60) as u16),
+                poster_url: None,
+                letterboxd_url: format!("https://letterboxd.com/film/movie-{}/", i),
+                tmdb_url: None,
+                cast: Vec::new(),
+                synopsis: None,
+                letterboxd_rating: None,
+                imdb_rating: None,
+                rotten_tomatoes_rating: None,
+                metacritic_rating: None,
+                imdb_id: None,
+                release_date: None,
+                plot: None,
+                awards: None,
+            },
+            user_rating: Some(((i % 10) as f32) / 2.0),
+            review: None,
+            watched_date: Some(chrono::Utc::now()),
+            liked: i % 3 == 0,
+            rewatched: false,
+            tags: Vec::new(),
+        })
+        .collect()
+}
+
+fn bench_calculate_enhanced_stats(c: &mut Criterion) {
+    let movies = synthetic_diary(5_000);
+    let client = LetterboxdClient::new().unwrap();
+
+    c.bench_function("calculate_enhanced_stats_5000", |b| {
+        b.iter(|| {
+            client
+                .calculate_enhanced_stats_for_bench(&movies, false)
+                .unwrap()
+        })
+    });
+}
+
+criterion_group!(benches, bench_calculate_enhanced_stats);
+criterion_main!(benches);
@@ -0,0 +1,21 @@
+//! Fetches a Letterboxd user's profile using lbxd as a library and prints a summary.
+//!
+//! Run with: cargo run --example fetch -- <username>
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let username = std::env::args().nth(1).unwrap_or_else(|| "a24".to_string());
+
+    let profile = lbxd::fetch_profile(&username, false).await?;
+
+    println!("{} (@{})", profile.name, profile.username);
+    println!("Films watched: {}", profile.total_films);
+    println!("Films this year: {}", profile.films_this_year);
+    println!("Watchlist size: {}", profile.total_watchlist_available);
+
+    if let Some(recent) = profile.recent_activity.first() {
+        println!("Most recent: {}", recent.movie.title);
+    }
+
+    Ok(())
+}
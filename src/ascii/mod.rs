@@ -0,0 +1,146 @@
+use crate::config::AsciiColorDepth;
+use anyhow::Result;
+
+/// Detects the terminal's color capability from the conventional
+/// `COLORTERM`/`TERM` environment variables, for `AsciiColorDepth::Auto`.
+/// Falls back to the safe 16-color assumption when neither variable gives a
+/// clear answer.
+pub fn detect_terminal_colors() -> AsciiColorDepth {
+    if let Ok(colorterm) = std::env::var("COLORTERM") {
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            return AsciiColorDepth::TrueColor;
+        }
+    }
+
+    if let Ok(term) = std::env::var("TERM") {
+        if term.contains("256color") {
+            return AsciiColorDepth::Color256;
+        }
+        if term == "dumb" {
+            return AsciiColorDepth::Mono;
+        }
+    }
+
+    AsciiColorDepth::Color16
+}
+
+/// Renders `image_bytes` as a grid of solid-color block characters `width`
+/// columns wide, entirely in Rust. This is the fallback used when the
+/// external `viu` binary isn't installed: each character cell's color is the
+/// average of the source pixels it covers (via `image`'s triangle-filter
+/// resize), encoded as a truecolor, 256-color, or 16-color ANSI escape
+/// depending on `depth`. `Mono` carries no color at all and instead varies
+/// the block character by brightness.
+pub fn render(image_bytes: &[u8], width: u32, depth: AsciiColorDepth) -> Result<String> {
+    let depth = match depth {
+        AsciiColorDepth::Auto => detect_terminal_colors(),
+        other => other,
+    };
+
+    let image = image::load_from_memory(image_bytes)?.to_rgb8();
+    let (src_width, src_height) = image.dimensions();
+
+    // Terminal character cells are roughly twice as tall as they are wide,
+    // so halving the row count keeps the rendered poster close to the source
+    // image's aspect ratio instead of looking stretched.
+    let height = ((width as f32 / src_width as f32) * src_height as f32 / 2.0)
+        .round()
+        .max(1.0) as u32;
+    let cells =
+        image::imageops::resize(&image, width, height, image::imageops::FilterType::Triangle);
+
+    let mut output = String::with_capacity((width * height * 12) as usize);
+    for y in 0..height {
+        for x in 0..width {
+            let [r, g, b] = cells.get_pixel(x, y).0;
+            output.push_str(&render_cell(r, g, b, depth));
+        }
+        output.push('\n');
+    }
+
+    Ok(output)
+}
+
+fn render_cell(r: u8, g: u8, b: u8, depth: AsciiColorDepth) -> String {
+    match depth {
+        AsciiColorDepth::TrueColor => format!("\x1b[38;2;{r};{g};{b}m█\x1b[0m"),
+        AsciiColorDepth::Color256 => format!("\x1b[38;5;{}m█\x1b[0m", rgb_to_256(r, g, b)),
+        AsciiColorDepth::Color16 => format!("\x1b[{}m█\x1b[0m", rgb_to_16(r, g, b)),
+        AsciiColorDepth::Mono => density_char(r, g, b).to_string(),
+        AsciiColorDepth::Auto => unreachable!("render() resolves Auto to a concrete depth first"),
+    }
+}
+
+/// Maps a pixel's brightness to a character from a light-to-dark ramp, for
+/// `Mono` output where there's no color channel left to carry detail.
+fn density_char(r: u8, g: u8, b: u8) -> char {
+    const RAMP: [char; 10] = [' ', '.', ':', '-', '=', '+', '*', '#', '%', '@'];
+    let luminance = 0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32;
+    let index = ((luminance / 255.0) * (RAMP.len() - 1) as f32).round() as usize;
+    RAMP[index.min(RAMP.len() - 1)]
+}
+
+/// Bit weights for the 8 dots of a Braille cell (U+2800 block), addressed by
+/// their position in the 2-wide x 4-tall dot grid that each character packs.
+const BRAILLE_DOT_BITS: [[u16; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// Renders `image_bytes` as Braille-dot art `width` columns wide. Each
+/// character cell packs a 2x4 grid of monochrome dots (via the U+2800
+/// Braille block), so a rendered poster carries roughly 8x the spatial
+/// detail of [`render`]'s solid blocks at the cost of color: a dot is either
+/// on or off, so there's no per-cell channel left to encode a pixel's hue.
+/// `threshold` is the luminance (0-255) at or above which a dot is
+/// considered "on", matching `Config::high_contrast_threshold`'s semantics.
+pub fn render_braille(image_bytes: &[u8], width: u32, threshold: u8) -> Result<String> {
+    let image = image::load_from_memory(image_bytes)?.to_luma8();
+    let (src_width, src_height) = image.dimensions();
+
+    // Each cell covers a 2x4 dot grid, so the full dot canvas is twice as
+    // wide and four times as tall as the character grid.
+    let height = ((width as f32 / src_width as f32) * src_height as f32 / 2.0)
+        .round()
+        .max(1.0) as u32;
+    let dot_width = width * 2;
+    let dot_height = height * 4;
+    let dots = image::imageops::resize(
+        &image,
+        dot_width,
+        dot_height,
+        image::imageops::FilterType::Triangle,
+    );
+
+    let mut output = String::with_capacity((width * height + height) as usize);
+    for cell_y in 0..height {
+        for cell_x in 0..width {
+            let mut pattern: u16 = 0;
+            for (row, bits) in BRAILLE_DOT_BITS.iter().enumerate() {
+                for (col, bit) in bits.iter().enumerate() {
+                    let x = cell_x * 2 + col as u32;
+                    let y = cell_y * 4 + row as u32;
+                    if dots.get_pixel(x, y).0[0] >= threshold {
+                        pattern |= bit;
+                    }
+                }
+            }
+            let codepoint = 0x2800 + pattern as u32;
+            output.push(char::from_u32(codepoint).unwrap_or(' '));
+        }
+        output.push('\n');
+    }
+
+    Ok(output)
+}
+
+/// Quantizes an RGB color to the 6x6x6 color cube of the standard xterm
+/// 256-color palette (indices 16-231).
+fn rgb_to_256(r: u8, g: u8, b: u8) -> u8 {
+    let scale = |c: u8| (c as u16 * 5 / 255) as u8;
+    16 + 36 * scale(r) + 6 * scale(g) + scale(b)
+}
+
+/// Picks the nearest of the 8 basic ANSI foreground colors (30-37) by
+/// rounding each channel to on/off, for terminals without 256-color support.
+fn rgb_to_16(r: u8, g: u8, b: u8) -> u8 {
+    let bit = |c: u8| u8::from(c > 127);
+    30 + (bit(r) | (bit(g) << 1) | (bit(b) << 2))
+}
@@ -27,9 +27,19 @@ impl BatchLoader {
     pub async fn process_entries_with_progress(&self, entries: &[&UserEntry]) -> Vec<BatchResult> {
         let total = entries.len();
         let mut results = Vec::new();
+        let is_terminal = crate::util::stdout_is_terminal();
 
-        // Start the progress indicator
-        let progress_handle = tokio::spawn(Self::show_unified_progress_static(total));
+        if !is_terminal {
+            println!(
+                "Loading {} movie{} and poster{}...",
+                total,
+                if total == 1 { "" } else { "s" },
+                if total == 1 { "" } else { "s" }
+            );
+        }
+
+        // Start the progress indicator (no-op on non-terminal stdout)
+        let progress_handle = tokio::spawn(Self::show_unified_progress_static(total, is_terminal));
 
         // Process all entries concurrently
         let mut handles = Vec::new();
@@ -93,14 +103,26 @@ impl BatchLoader {
         // Stop progress indicator
         progress_handle.abort();
 
-        // Clear the progress line
-        print!("\r\x1b[2K");
+        if is_terminal {
+            // Clear the progress line
+            print!("\r\x1b[2K");
+        } else {
+            println!("Loading done");
+        }
         io::stdout().flush().unwrap();
 
         results
     }
 
-    async fn show_unified_progress_static(total: usize) {
+    /// Animates a braille spinner on an interactive terminal. On a non-terminal
+    /// stdout (piped to a file, CI logs, etc.) this is a no-op — the caller
+    /// already printed a plain "Loading..." line instead.
+    async fn show_unified_progress_static(total: usize, is_terminal: bool) {
+        if !is_terminal {
+            std::future::pending::<()>().await;
+            return;
+        }
+
         let frames = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
         let mut frame_index = 0;
         let mut interval = interval(Duration::from_millis(100));
@@ -120,19 +142,6 @@ impl BatchLoader {
     }
 
     fn clean_title_for_search(title: &str) -> String {
-        // Remove common problematic characters and patterns that might interfere with TMDB search
-        let mut cleaned = title.to_string();
-
-        // Remove trailing asterisks (like "Thunderbolts*")
-        cleaned = cleaned.trim_end_matches('*').to_string();
-
-        // Remove extra whitespace and normalize
-        cleaned = cleaned.trim().to_string();
-
-        // Replace multiple spaces with single space
-        let re = regex::Regex::new(r"\s+").unwrap();
-        cleaned = re.replace_all(&cleaned, " ").to_string();
-
-        cleaned
+        crate::util::normalize_title(title)
     }
 }
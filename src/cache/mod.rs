@@ -1,10 +1,53 @@
+use crate::feed::FeedCacheMeta;
 use crate::models::UserProfile;
+use crate::profile::UserList;
 use anyhow::Result;
 use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
 use serde_json;
+use std::env;
 use std::fs;
 use std::path::PathBuf;
 
+/// Bumped whenever `UserProfile` (or a type it embeds) changes shape in a way
+/// that would make an old cache file deserialize incorrectly or silently lose
+/// data. Cache entries written by a different version are treated as a miss
+/// rather than risking a partial/garbled read.
+const CACHE_SCHEMA_VERSION: u32 = 1;
+
+/// On-disk envelope around a cached `UserProfile`, tagged with the schema
+/// version it was written under.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedProfile {
+    #[serde(default)]
+    schema_version: u32,
+    profile: UserProfile,
+}
+
+/// On-disk envelope around a cached `UserList`, mirroring `CachedProfile`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedList {
+    #[serde(default)]
+    schema_version: u32,
+    list: UserList,
+}
+
+/// On-disk record of when a user's `recent` view was last invoked, used by
+/// `--since-last-run` to show only entries watched after that point.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LastRunMeta {
+    timestamp: DateTime<Utc>,
+}
+
+/// Turns a list URL into a filesystem-safe cache key, since lists are keyed by
+/// URL/slug rather than by username.
+fn list_cache_key(url: &str) -> String {
+    url.trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .trim_end_matches('/')
+        .replace(['/', '.'], "_")
+}
+
 pub struct CacheManager {
     cache_dir: PathBuf,
 }
@@ -17,8 +60,97 @@ impl CacheManager {
         Ok(Self { cache_dir })
     }
 
+    /// Points a `CacheManager` at an arbitrary directory instead of the real
+    /// `~/.cache/lbxd`, so tests can exercise cache reads/writes against a
+    /// scratch directory.
+    #[cfg(test)]
+    pub(crate) fn with_cache_dir(cache_dir: PathBuf) -> Self {
+        Self { cache_dir }
+    }
+
+    /// Returns the cached profile for `username` if one exists and is fresh
+    /// enough, using `max_age` as the freshness window if given, or the
+    /// default 6-hour TTL otherwise. `max_age` lets a single invocation (e.g.
+    /// `recent --max-age 1h`) demand fresher data than the default TTL
+    /// without touching any persisted config.
+    pub fn get_cached_profile_with_max_age(
+        &self,
+        username: &str,
+        max_age: Option<Duration>,
+    ) -> Option<UserProfile> {
+        let cache_file = self.cache_dir.join(format!("{username}.json"));
+
+        if !cache_file.exists() {
+            return None;
+        }
+
+        let metadata = fs::metadata(&cache_file).ok()?;
+        let modified = metadata.modified().ok()?;
+        let modified_dt: DateTime<Utc> = modified.into();
+
+        if Utc::now() - modified_dt > max_age.unwrap_or_else(|| Duration::hours(6)) {
+            return None;
+        }
+
+        let content = fs::read_to_string(&cache_file).ok()?;
+        let cached: CachedProfile = serde_json::from_str(&content).ok()?;
+        if cached.schema_version != CACHE_SCHEMA_VERSION {
+            return None;
+        }
+        Some(cached.profile)
+    }
+
+    /// As [`Self::get_cached_profile_with_max_age`], using the default
+    /// 6-hour TTL.
     pub fn get_cached_profile(&self, username: &str) -> Option<UserProfile> {
+        self.get_cached_profile_with_max_age(username, None)
+    }
+
+    pub fn cache_profile(&self, profile: &UserProfile) -> Result<()> {
+        let cache_file = self.cache_dir.join(format!("{}.json", profile.username));
+        let cached = CachedProfile {
+            schema_version: CACHE_SCHEMA_VERSION,
+            profile: profile.clone(),
+        };
+        let content = serde_json::to_string_pretty(&cached)?;
+        crate::util::atomic_write(&cache_file, &content)?;
+        Ok(())
+    }
+
+    /// Removes the cached profile and feed metadata for `username`. Used when a
+    /// rename is detected so the old username's entry doesn't linger as a stale,
+    /// never-refreshed duplicate of the renamed account.
+    pub fn invalidate_profile(&self, username: &str) -> Result<()> {
+        let cache_file = self.cache_dir.join(format!("{username}.json"));
+        let meta_file = self.cache_dir.join(format!("{username}.etag.json"));
+
+        if cache_file.exists() {
+            fs::remove_file(cache_file)?;
+        }
+        if meta_file.exists() {
+            fs::remove_file(meta_file)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the cached profile regardless of TTL, for use when a conditional
+    /// fetch confirms (via 304) that it's still current.
+    pub fn get_stale_profile(&self, username: &str) -> Option<UserProfile> {
         let cache_file = self.cache_dir.join(format!("{username}.json"));
+        let content = fs::read_to_string(&cache_file).ok()?;
+        let cached: CachedProfile = serde_json::from_str(&content).ok()?;
+        if cached.schema_version != CACHE_SCHEMA_VERSION {
+            return None;
+        }
+        Some(cached.profile)
+    }
+
+    /// Returns a cached list fetched from `url`, if one exists and is still
+    /// within the same 6-hour TTL used for profiles.
+    pub fn get_cached_list(&self, url: &str) -> Option<UserList> {
+        let cache_file = self
+            .cache_dir
+            .join(format!("list_{}.json", list_cache_key(url)));
 
         if !cache_file.exists() {
             return None;
@@ -33,16 +165,67 @@ impl CacheManager {
         }
 
         let content = fs::read_to_string(&cache_file).ok()?;
+        let cached: CachedList = serde_json::from_str(&content).ok()?;
+        if cached.schema_version != CACHE_SCHEMA_VERSION {
+            return None;
+        }
+        Some(cached.list)
+    }
+
+    /// Caches a fetched list, like [`cache_profile`](Self::cache_profile) does for profiles.
+    pub fn cache_list(&self, url: &str, list: &UserList) -> Result<()> {
+        let cache_file = self
+            .cache_dir
+            .join(format!("list_{}.json", list_cache_key(url)));
+        let cached = CachedList {
+            schema_version: CACHE_SCHEMA_VERSION,
+            list: list.clone(),
+        };
+        let content = serde_json::to_string_pretty(&cached)?;
+        crate::util::atomic_write(&cache_file, &content)?;
+        Ok(())
+    }
+
+    /// Loads the ETag/Last-Modified headers saved alongside a cached profile, if any.
+    pub fn get_feed_meta(&self, username: &str) -> Option<FeedCacheMeta> {
+        let meta_file = self.cache_dir.join(format!("{username}.etag.json"));
+        let content = fs::read_to_string(&meta_file).ok()?;
         serde_json::from_str(&content).ok()
     }
 
-    pub fn cache_profile(&self, profile: &UserProfile) -> Result<()> {
-        let cache_file = self.cache_dir.join(format!("{}.json", profile.username));
-        let content = serde_json::to_string_pretty(profile)?;
-        fs::write(cache_file, content)?;
+    /// Saves the ETag/Last-Modified headers from a feed fetch for use in the next
+    /// conditional request.
+    pub fn save_feed_meta(&self, username: &str, meta: &FeedCacheMeta) -> Result<()> {
+        let meta_file = self.cache_dir.join(format!("{username}.etag.json"));
+        let content = serde_json::to_string_pretty(meta)?;
+        crate::util::atomic_write(&meta_file, &content)?;
+        Ok(())
+    }
+
+    /// Returns the timestamp of the last `recent` invocation for `username`,
+    /// if one was recorded, for use by `--since-last-run`.
+    pub fn get_last_run_timestamp(&self, username: &str) -> Option<DateTime<Utc>> {
+        let meta_file = self.cache_dir.join(format!("{username}.last_run.json"));
+        let content = fs::read_to_string(&meta_file).ok()?;
+        let meta: LastRunMeta = serde_json::from_str(&content).ok()?;
+        Some(meta.timestamp)
+    }
+
+    /// Records `timestamp` as the last `recent` invocation for `username`,
+    /// so the next `--since-last-run` call knows where to pick up from.
+    pub fn save_last_run_timestamp(&self, username: &str, timestamp: DateTime<Utc>) -> Result<()> {
+        let meta_file = self.cache_dir.join(format!("{username}.last_run.json"));
+        let meta = LastRunMeta { timestamp };
+        let content = serde_json::to_string_pretty(&meta)?;
+        crate::util::atomic_write(&meta_file, &content)?;
         Ok(())
     }
 
+    /// Returns the resolved cache directory, for display in `config show`.
+    pub fn cache_dir(&self) -> &std::path::Path {
+        &self.cache_dir
+    }
+
     pub fn clear_cache(&self) -> Result<()> {
         for entry in fs::read_dir(&self.cache_dir)? {
             let entry = entry?;
@@ -53,10 +236,79 @@ impl CacheManager {
         Ok(())
     }
 
+    /// Resolves the cache directory, honoring `$XDG_CACHE_HOME` when set
+    /// (and non-empty) and falling back to `~/.cache/lbxd` otherwise.
     fn get_cache_dir() -> Result<PathBuf> {
+        if let Ok(xdg_cache_home) = env::var("XDG_CACHE_HOME") {
+            if !xdg_cache_home.is_empty() {
+                return Ok(PathBuf::from(xdg_cache_home).join("lbxd"));
+            }
+        }
+
         let home_dir =
             dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Could not find home directory"))?;
 
         Ok(home_dir.join(".cache").join("lbxd"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::UserProfile;
+
+    fn sample_profile(username: &str) -> UserProfile {
+        UserProfile {
+            username: username.to_string(),
+            display_name: None,
+            avatar_url: None,
+            rss_url: format!("https://letterboxd.com/{username}/rss/"),
+            entries: Vec::new(),
+        }
+    }
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("lbxd_cache_test_{}_{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn mismatched_schema_version_is_treated_as_a_miss() {
+        let dir = scratch_dir("mismatched_version");
+        let manager = CacheManager::with_cache_dir(dir.clone());
+
+        let cache_file = dir.join("filmfan.json");
+        let stale_envelope = CachedProfile {
+            schema_version: 0,
+            profile: sample_profile("filmfan"),
+        };
+        let content = serde_json::to_string_pretty(&stale_envelope).unwrap();
+        fs::write(&cache_file, content).unwrap();
+
+        assert!(manager.get_cached_profile("filmfan").is_none());
+        assert!(manager.get_stale_profile("filmfan").is_none());
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn missing_schema_version_field_is_treated_as_a_miss() {
+        let dir = scratch_dir("missing_version_field");
+        let manager = CacheManager::with_cache_dir(dir.clone());
+
+        let cache_file = dir.join("filmfan.json");
+        let legacy_json = serde_json::json!({ "profile": sample_profile("filmfan") });
+        fs::write(
+            &cache_file,
+            serde_json::to_string_pretty(&legacy_json).unwrap(),
+        )
+        .unwrap();
+
+        assert!(manager.get_cached_profile("filmfan").is_none());
+        assert!(manager.get_stale_profile("filmfan").is_none());
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -12,28 +12,109 @@ pub struct Cli {
     pub reconfig: bool,
     #[arg(help = "Show profile stats for username (or use 'browse' for interactive TUI)")]
     pub username: Option<String>,
+    #[arg(
+        long,
+        help = "Color palette for CLI output (letterboxd/solarized/mono)",
+        value_enum
+    )]
+    pub theme: Option<ThemeArg>,
+    #[arg(
+        long,
+        global = true,
+        help = "Abort the whole operation if it takes longer than this many seconds (default: no timeout)"
+    )]
+    pub timeout: Option<u64>,
+    #[arg(
+        long,
+        global = true,
+        help = "Render posters with a binary filled/empty threshold instead of a gray ramp, for better readability"
+    )]
+    pub high_contrast: bool,
+    #[arg(
+        long,
+        global = true,
+        help = "Print extra diagnostics, e.g. warnings about discarded future-dated diary entries"
+    )]
+    pub verbose: bool,
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        help = "Data source for commands that support either: 'rss' (fast, ~50 most recent entries) or 'native' (slower, complete). Defaults to the configured default-client, itself 'rss' unless changed"
+    )]
+    pub client: Option<ClientArg>,
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        help = "Character set for the pure-Rust ASCII poster fallback: 'blocks' (colored, used when viu isn't installed) or 'braille' (monochrome, higher detail)"
+    )]
+    pub poster_style: Option<PosterStyleArg>,
+    #[arg(
+        long,
+        global = true,
+        help = "Render posters even when stdout isn't a terminal (e.g. piped to a file or another program)"
+    )]
+    pub force_posters: bool,
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
 
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+pub enum ClientArg {
+    Rss,
+    Native,
+}
+
 #[derive(Subcommand)]
 pub enum Commands {
     #[command(about = "★ Show recent activity for a user")]
     Recent {
         #[arg(help = "Letterboxd username")]
         username: String,
-        #[arg(short, long, help = "Number of entries to show", default_value = "3")]
+        #[arg(
+            short,
+            long,
+            help = "Number of entries to show. Ignored if --all is given",
+            default_value = "3"
+        )]
         limit: Option<usize>,
+        #[arg(
+            long,
+            help = "Show every entry in the feed instead of just --limit, overriding it"
+        )]
+        all: bool,
+        #[arg(
+            long,
+            help = "Show only entries watched since the last 'recent' run for this user, like unread mail. Falls back to the normal limited view if there's no prior run"
+        )]
+        since_last_run: bool,
         #[arg(short, long, help = "Filter by date (YYYY-MM-DD)")]
         date: Option<String>,
+        #[arg(
+            long,
+            help = "Only use cached data if it's younger than this, e.g. '1h', '30m', '2d'; overrides the default cache TTL for this run only"
+        )]
+        max_age: Option<String>,
         #[arg(short, long, help = "Show only rated films")]
         rated: bool,
         #[arg(short = 'w', long, help = "Show only reviewed films")]
         reviewed: bool,
         #[arg(short = 'v', long, help = "Display in vertical layout")]
         vertical: bool,
+        #[arg(
+            long,
+            help = "Group vertical output by watch date, with a date header before each day's films"
+        )]
+        group_by_date: bool,
         #[arg(long, help = "Width in characters (30-120)", value_parser = clap::value_parser!(u32).range(30..=120), default_value = "45")]
         width: u32,
+        #[arg(
+            long,
+            help = "Poster width in characters (30-120), independent of text width; defaults to --width",
+            value_parser = clap::value_parser!(u32).range(30..=120)
+        )]
+        poster_width: Option<u32>,
     },
     #[command(about = "◆ Search for specific titles in user history")]
     Search {
@@ -43,11 +124,67 @@ pub enum Commands {
         title: String,
         #[arg(long, help = "Width in characters (30-120)", value_parser = clap::value_parser!(u32).range(30..=120), default_value = "45")]
         width: u32,
+        #[arg(
+            long,
+            help = "Poster width in characters (30-120), independent of text width; defaults to --width",
+            value_parser = clap::value_parser!(u32).range(30..=120)
+        )]
+        poster_width: Option<u32>,
+        #[arg(
+            long,
+            help = "If no diary match is found, fall back to a TMDB search for the title"
+        )]
+        discover: bool,
+    },
+    #[command(about = "📌 Look up the exact diary entry for a film watched on a given date")]
+    Entry {
+        #[arg(help = "Letterboxd username")]
+        username: String,
+        #[arg(help = "Movie title")]
+        title: String,
+        #[arg(help = "Date the film was watched, as YYYY-MM-DD")]
+        date: String,
+        #[arg(long, help = "Width in characters (30-120)", value_parser = clap::value_parser!(u32).range(30..=120), default_value = "45")]
+        width: u32,
+        #[arg(
+            long,
+            help = "Poster width in characters (30-120), independent of text width; defaults to --width",
+            value_parser = clap::value_parser!(u32).range(30..=120)
+        )]
+        poster_width: Option<u32>,
     },
     #[command(about = "▲ Compare multiple users")]
     Compare {
-        #[arg(help = "Letterboxd usernames", num_args = 2..)]
+        #[arg(help = "Letterboxd usernames", num_args = 0..)]
         usernames: Vec<String>,
+        #[arg(
+            long,
+            help = "Also read usernames from stdin, one per line (blank lines and '#' comments ignored)"
+        )]
+        stdin: bool,
+        #[arg(
+            long,
+            value_enum,
+            help = "Sort the per-user summary table by this column, descending (default: input order)"
+        )]
+        sort_by: Option<CompareSortArg>,
+    },
+    #[command(about = "🌐 Merge recent activity from multiple users into one timeline")]
+    Feed {
+        #[arg(help = "Letterboxd usernames", num_args = 0..)]
+        usernames: Vec<String>,
+        #[arg(
+            short,
+            long,
+            help = "Maximum number of entries to show across all users",
+            default_value = "30"
+        )]
+        limit: usize,
+        #[arg(
+            long,
+            help = "Also read usernames from stdin, one per line (blank lines and '#' comments ignored)"
+        )]
+        stdin: bool,
     },
     #[command(about = "● Export data to JSON/Markdown")]
     Export {
@@ -55,15 +192,119 @@ pub enum Commands {
         username: String,
         #[arg(short, long, help = "Output format", value_enum)]
         format: ExportFormat,
-        #[arg(short, long, help = "Output file path")]
+        #[arg(short, long, help = "Output file path, or - to stream to stdout")]
         output: String,
     },
+    #[command(about = "📦 Export multiple users' data at once")]
+    ExportBatch {
+        #[arg(help = "Letterboxd usernames", num_args = 0..)]
+        usernames: Vec<String>,
+        #[arg(short, long, help = "Output format", value_enum)]
+        format: ExportFormat,
+        #[arg(
+            long,
+            help = "Directory to write each user's export into (created if missing)"
+        )]
+        output_dir: String,
+        #[arg(
+            long,
+            help = "Also read usernames from stdin, one per line (blank lines and '#' comments ignored)"
+        )]
+        stdin: bool,
+    },
+    #[command(
+        about = "📊 Compare a user's stats between two years, or export a full stats report"
+    )]
+    Stats {
+        #[arg(help = "Letterboxd username")]
+        username: String,
+        #[arg(
+            long,
+            num_args = 2,
+            value_names = ["YEAR_A", "YEAR_B"],
+            help = "Two years to diff, e.g. --compare-years 2023 2024. Required unless --export is given"
+        )]
+        compare_years: Vec<i32>,
+        #[arg(
+            long,
+            help = "Write the full enhanced-statistics report (genre/director/yearly/rating breakdowns) to this path instead of diffing two years"
+        )]
+        export: Option<String>,
+        #[arg(
+            long,
+            value_enum,
+            default_value = "markdown",
+            help = "Report format for --export"
+        )]
+        export_format: ExportFormat,
+        #[arg(
+            long,
+            help = "List every genre watched with its film count, uncapped (unlike the top-10 breakdown)"
+        )]
+        list_genres: bool,
+        #[arg(
+            long,
+            help = "List every director watched with their film count, uncapped (unlike the top-10 breakdown)"
+        )]
+        list_directors: bool,
+        #[arg(
+            long,
+            default_value = "1",
+            help = "With --list-genres/--list-directors, only show entries with at least this many films"
+        )]
+        min_count: u32,
+    },
     #[command(about = "◉ Generate viewing summary")]
     Summary {
         #[arg(help = "Letterboxd username")]
         username: String,
         #[arg(short, long, help = "Year for summary")]
         year: Option<i32>,
+        #[arg(
+            long,
+            help = "Embed a first-sentence excerpt of each top film's review, when present"
+        )]
+        with_reviews: bool,
+    },
+    #[command(about = "🎞 Show a user's watchlist")]
+    Watchlist {
+        #[arg(help = "Letterboxd username")]
+        username: String,
+        #[arg(
+            long,
+            help = "Flag watchlist films currently in theaters (🎟 In theaters now)"
+        )]
+        in_theaters: bool,
+        #[arg(
+            long,
+            help = "ISO 3166-1 region code for --in-theaters (e.g. US, GB); defaults to the saved tmdb region"
+        )]
+        region: Option<String>,
+        #[arg(
+            long,
+            help = "Mark films you've already seen, based on your saved `me` diary (✓ you've seen this)"
+        )]
+        mark_seen: bool,
+    },
+    #[command(about = "🎁 Generate a shareable year-in-review card")]
+    Wrapped {
+        #[arg(help = "Letterboxd username")]
+        username: String,
+        #[arg(short, long, help = "Year to generate the wrapped card for")]
+        year: i32,
+        #[arg(short, long, help = "Output PNG path", default_value = "wrapped.png")]
+        output: String,
+        #[arg(long, help = "Card width in pixels", default_value = "1080")]
+        width: u32,
+        #[arg(long, help = "Card height in pixels", default_value = "1920")]
+        height: u32,
+        #[arg(
+            long,
+            help = "Card color theme",
+            default_value = "dark",
+            value_parser = ["dark", "light", "vibrant"]
+        )]
+        theme: String,
     },
     #[command(about = "✽ Search for movies using TMDB database")]
     Movie {
@@ -71,6 +312,17 @@ pub enum Commands {
         title: String,
         #[arg(short, long, help = "Width in characters (30-120)", value_parser = clap::value_parser!(u32).range(30..=120), default_value = "45")]
         width: u32,
+        #[arg(
+            long,
+            help = "Poster width in characters (30-120), independent of text width; defaults to --width",
+            value_parser = clap::value_parser!(u32).range(30..=120)
+        )]
+        poster_width: Option<u32>,
+        #[arg(
+            long,
+            help = "Show a side-by-side bar comparison of TMDB/IMDb/Rotten Tomatoes/Metacritic scores"
+        )]
+        compare: bool,
     },
     #[command(about = "⚙ Manage user configuration settings")]
     Config {
@@ -81,6 +333,49 @@ pub enum Commands {
     Browse {
         #[arg(help = "Letterboxd username")]
         username: String,
+        #[arg(
+            help = "A second username to compare against, opening a 'Watch Party' tab with shared films highlighted"
+        )]
+        compare_username: Option<String>,
+        #[arg(
+            long,
+            help = "Skip OMDB enrichment (IMDb/RT/Metacritic) for a faster load"
+        )]
+        no_enrich: bool,
+        #[arg(
+            long,
+            help = "Open the Watchlist tab sorted by predicted interest based on your taste profile"
+        )]
+        recommend: bool,
+        #[arg(
+            long,
+            help = "Only load the N most recent diary entries, overriding the configured max-diary-entries for this run"
+        )]
+        limit: Option<u32>,
+        #[arg(long, help = "Initial sort order for the Watchlist tab", value_enum)]
+        watchlist_sort_by: Option<WatchlistSortArg>,
+    },
+    #[command(about = "📋 View any public Letterboxd list by URL")]
+    List {
+        #[arg(
+            help = "Full Letterboxd list URL, e.g. https://letterboxd.com/user/list/best-of-2023/"
+        )]
+        url: String,
+        #[arg(long, help = "Width in characters (30-120)", value_parser = clap::value_parser!(u32).range(30..=120), default_value = "45")]
+        width: u32,
+        #[arg(
+            long,
+            help = "Poster width in characters (30-120), independent of text width; defaults to --width",
+            value_parser = clap::value_parser!(u32).range(30..=120)
+        )]
+        poster_width: Option<u32>,
+    },
+    #[command(
+        about = "🌐 Serve a local JSON API over cached Letterboxd data (requires the `server` build feature)"
+    )]
+    Serve {
+        #[arg(long, help = "Port to listen on", default_value = "8787")]
+        port: u16,
     },
 }
 
@@ -100,11 +395,175 @@ pub enum ConfigCommands {
         #[arg(help = "Color mode (color/grayscale)", value_enum)]
         mode: ColorModeArg,
     },
+    #[command(
+        about = "🖼 Switch whether posters are desaturated, independent of the text color mode"
+    )]
+    SetPosterGrayscale {
+        #[arg(
+            help = "Poster desaturation mode: 'auto' follows the text color mode, or force 'on'/'off'",
+            value_enum
+        )]
+        mode: PosterGrayscaleArg,
+    },
     #[command(about = "🖼 Switch between pixelated and full resolution posters")]
     SetMode {
         #[arg(help = "Display mode (pixelated/full)", value_enum)]
         mode: DisplayModeArg,
     },
+    #[command(about = "🎨 Switch the CLI color theme")]
+    SetTheme {
+        #[arg(help = "Theme (letterboxd/solarized/mono)", value_enum)]
+        theme: ThemeArg,
+    },
+    #[command(about = "🔑 Set your own TMDB/OMDB API key", alias = "set-key")]
+    SetApiKey {
+        #[arg(help = "Which service the key is for", value_enum)]
+        service: ApiServiceArg,
+        #[arg(help = "API key value")]
+        key: String,
+        #[arg(
+            long,
+            help = "Store the key in the OS keyring instead of plaintext config.toml"
+        )]
+        keyring: bool,
+    },
+    #[command(about = "📺 Set how logged TV episodes are folded into stats")]
+    SetTvAggregation {
+        #[arg(help = "Aggregation mode (per-episode/per-series)", value_enum)]
+        mode: TvAggregationArg,
+    },
+    #[command(about = "⚡ Set whether `browse` skips OMDB enrichment by default")]
+    SetNoEnrich {
+        #[arg(help = "Skip OMDB enrichment by default (true/false)")]
+        enabled: bool,
+    },
+    #[command(about = "📰 Set how many recent diary entries are surfaced in profile stats")]
+    SetRecentActivityCount {
+        #[arg(help = "Number of recent activity entries to show")]
+        count: u32,
+    },
+    #[command(about = "♿ Toggle high-contrast (binary threshold) poster rendering")]
+    SetHighContrast {
+        #[arg(help = "Enable high-contrast posters (true/false)")]
+        enabled: bool,
+    },
+    #[command(about = "♿ Set the luminance threshold used by high-contrast posters")]
+    SetContrastThreshold {
+        #[arg(help = "Luminance cutoff 0-255; pixels at or above render as filled", value_parser = clap::value_parser!(u8).range(0..=255))]
+        threshold: u8,
+    },
+    #[command(about = "🛡 Cap how many bytes a single poster download may stream before aborting")]
+    SetMaxImageDownloadBytes {
+        #[arg(help = "Maximum bytes, e.g. 10485760 for 10 MB")]
+        max_bytes: u64,
+    },
+    #[command(about = "📼 Cap how many recent diary entries are loaded (0 = no cap)")]
+    SetMaxDiaryEntries {
+        #[arg(help = "Max diary entries to load, or 0 for no cap")]
+        max: u32,
+    },
+    #[command(
+        about = "🔁 Toggle merging same-film, same-day diary entries into one ×N rewatch entry"
+    )]
+    SetMergeSameDayRewatches {
+        #[arg(help = "Enable merging same-day rewatches (true/false)")]
+        enabled: bool,
+    },
+    #[command(
+        about = "🕒 Toggle showing watch dates relative to now (e.g. \"3 days ago\") instead of absolute dates"
+    )]
+    SetRelativeDates {
+        #[arg(help = "Enable relative dates (true/false)")]
+        enabled: bool,
+    },
+    #[command(
+        about = "🎨 Set the color depth for the pure-Rust ASCII poster fallback (used when viu isn't installed)"
+    )]
+    SetAsciiColorDepth {
+        #[arg(
+            help = "Color depth (auto/truecolor/color256/color16/mono)",
+            value_enum
+        )]
+        depth: AsciiColorDepthArg,
+    },
+    #[command(
+        about = "🔲 Set the character set for the pure-Rust ASCII poster fallback (used when viu isn't installed)"
+    )]
+    SetPosterStyle {
+        #[arg(help = "Poster style (blocks/braille)", value_enum)]
+        style: PosterStyleArg,
+    },
+    #[command(
+        about = "🚦 Cap how many background network tasks (poster prefetch, OMDB enrichment) the TUI runs at once"
+    )]
+    SetBackgroundTaskLimit {
+        #[arg(help = "Maximum concurrent background tasks (minimum 1)")]
+        limit: usize,
+    },
+    #[command(about = "🏷 Set which stat is shown prominently in the TUI header")]
+    SetHeadlineStat {
+        #[arg(
+            help = "Headline stat (total-films/viewing-hours/films-this-year)",
+            value_enum
+        )]
+        stat: HeadlineStatArg,
+    },
+    #[command(
+        about = "🔔 Toggle a bell/desktop notification when a long `browse` or `export-batch` finishes"
+    )]
+    SetNotifyOnCompletion {
+        #[arg(help = "Enable completion notifications (true/false)")]
+        enabled: bool,
+    },
+    #[command(
+        about = "📅 Set how dates are rendered (iso/us/eu presets, or a custom strftime pattern)"
+    )]
+    SetDateFormat {
+        #[arg(help = "Date format preset", value_enum)]
+        preset: DateFormatArg,
+        #[arg(
+            help = "Custom strftime pattern, required when preset is 'custom' (e.g. '%d/%m/%Y')"
+        )]
+        pattern: Option<String>,
+    },
+    #[command(
+        about = "📡 Set the contact info (URL or email) sent in the RSS fetcher's User-Agent header"
+    )]
+    SetRssContact {
+        #[arg(help = "Contact URL or email, e.g. 'https://example.com' or 'me@example.com'")]
+        contact: String,
+    },
+    #[command(
+        about = "🌐 Set the TMDB content-negotiation language for titles/overviews (e.g. fr-FR)"
+    )]
+    SetTmdbLanguage {
+        #[arg(help = "TMDB language tag, e.g. 'fr-FR' or 'ja-JP'")]
+        language: String,
+    },
+    #[command(about = "🌍 Set the TMDB region used for 'watchlist --in-theaters' (e.g. US, GB)")]
+    SetTmdbRegion {
+        #[arg(help = "ISO 3166-1 region code, e.g. 'US' or 'GB'")]
+        region: String,
+    },
+    #[command(
+        about = "🔀 Set the default data source for commands that support either RSS or native"
+    )]
+    SetDefaultClient {
+        #[arg(
+            help = "'rss' (fast, limited) or 'native' (slower, complete)",
+            value_enum
+        )]
+        client: ClientArg,
+    },
+    #[command(
+        about = "🔖 Save a username shortcut, e.g. `lbxd config add-alias club some_username`"
+    )]
+    AddAlias {
+        #[arg(help = "Alias name, e.g. 'club'")]
+        name: String,
+        #[arg(help = "Letterboxd username the alias resolves to")]
+        username: String,
+    },
 }
 
 #[derive(clap::ValueEnum, Clone, Debug)]
@@ -119,8 +578,107 @@ pub enum DisplayModeArg {
     Full,
 }
 
+#[derive(clap::ValueEnum, Clone, Debug)]
+pub enum PosterGrayscaleArg {
+    /// Follow the text color mode (`color`/`grayscale`)
+    Auto,
+    On,
+    Off,
+}
+
 #[derive(clap::ValueEnum, Clone)]
 pub enum ExportFormat {
     Json,
     Markdown,
+    /// One `UserEntry` per line as a standalone JSON object, streamed
+    /// directly to the output writer. Keeps memory flat for huge diaries
+    /// and is pipe-friendly for tools like `jq`.
+    #[value(name = "jsonl")]
+    JsonLines,
+    /// A well-formed XML document mirroring the JSON structure, for
+    /// third-party tools that ingest XML.
+    Xml,
+    /// One row per date (`date,count`) across the diary's full date range,
+    /// including zero-count days, for feeding a calendar heatmap or
+    /// plotting library.
+    #[value(name = "heatmap-csv")]
+    HeatmapCsv,
+}
+
+impl ExportFormat {
+    /// File extension (without the dot) to use for a standalone export file
+    /// named after the format rather than passed explicitly by the user.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Json => "json",
+            ExportFormat::Markdown => "md",
+            ExportFormat::JsonLines => "jsonl",
+            ExportFormat::Xml => "xml",
+            ExportFormat::HeatmapCsv => "csv",
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Clone, Debug)]
+pub enum ThemeArg {
+    Letterboxd,
+    Solarized,
+    Mono,
+}
+
+#[derive(clap::ValueEnum, Clone, Debug)]
+pub enum ApiServiceArg {
+    Tmdb,
+    Omdb,
+}
+
+#[derive(clap::ValueEnum, Clone, Debug)]
+pub enum TvAggregationArg {
+    PerEpisode,
+    PerSeries,
+}
+
+#[derive(clap::ValueEnum, Clone, Debug)]
+pub enum HeadlineStatArg {
+    TotalFilms,
+    ViewingHours,
+    FilmsThisYear,
+}
+
+#[derive(clap::ValueEnum, Clone, Debug)]
+pub enum AsciiColorDepthArg {
+    Auto,
+    Truecolor,
+    Color256,
+    Color16,
+    Mono,
+}
+
+#[derive(clap::ValueEnum, Clone, Debug)]
+pub enum PosterStyleArg {
+    Blocks,
+    Braille,
+}
+
+#[derive(clap::ValueEnum, Clone, Debug)]
+pub enum DateFormatArg {
+    Iso,
+    Us,
+    Eu,
+    Custom,
+}
+
+#[derive(clap::ValueEnum, Clone, Debug)]
+pub enum WatchlistSortArg {
+    Added,
+    Release,
+    Title,
+    Runtime,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+pub enum CompareSortArg {
+    Films,
+    Rating,
+    Reviews,
 }
@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+
+use crate::profile::ComprehensiveProfile;
+
+/// Below this many distinct genres rated by a user, a cosine similarity
+/// against another user's genre distribution is too noisy to call a "taste
+/// match" — report insufficient data instead of a misleadingly precise
+/// percentage.
+const MIN_GENRES_FOR_SCORE: usize = 3;
+
+/// Genre taste is the primary signal for compatibility; director overlap is
+/// a smaller nudge, since it's common for two fans with very similar genre
+/// taste to still share zero favorite directors.
+const GENRE_WEIGHT: f32 = 0.8;
+const DIRECTOR_WEIGHT: f32 = 1.0 - GENRE_WEIGHT;
+
+/// A 0-100% "taste match" between two users, loosely modeled on Letterboxd's
+/// own friend-compatibility score.
+pub struct CompatibilityScore {
+    pub percentage: f32,
+}
+
+/// Computes a taste-match score between two users' `EnhancedStatistics`,
+/// using cosine similarity between their normalized genre-watch distributions
+/// (and, as a smaller factor, their director-watch distributions). Returns
+/// `None` when either profile is missing enhanced stats or hasn't watched
+/// enough distinct genres for the comparison to mean anything, so callers can
+/// report "insufficient data" rather than a bogus score.
+pub fn compute(a: &ComprehensiveProfile, b: &ComprehensiveProfile) -> Option<CompatibilityScore> {
+    let stats_a = a.enhanced_stats.as_ref()?;
+    let stats_b = b.enhanced_stats.as_ref()?;
+
+    if stats_a.genre_breakdown.len() < MIN_GENRES_FOR_SCORE
+        || stats_b.genre_breakdown.len() < MIN_GENRES_FOR_SCORE
+    {
+        return None;
+    }
+
+    let genre_similarity = cosine_similarity(
+        &stats_a
+            .genre_breakdown
+            .iter()
+            .map(|g| (g.name.as_str(), g.percentage))
+            .collect::<Vec<_>>(),
+        &stats_b
+            .genre_breakdown
+            .iter()
+            .map(|g| (g.name.as_str(), g.percentage))
+            .collect::<Vec<_>>(),
+    );
+
+    let director_similarity = cosine_similarity(
+        &stats_a
+            .director_stats
+            .iter()
+            .map(|d| (d.name.as_str(), d.film_count as f32))
+            .collect::<Vec<_>>(),
+        &stats_b
+            .director_stats
+            .iter()
+            .map(|d| (d.name.as_str(), d.film_count as f32))
+            .collect::<Vec<_>>(),
+    );
+
+    let combined = genre_similarity * GENRE_WEIGHT + director_similarity * DIRECTOR_WEIGHT;
+
+    Some(CompatibilityScore {
+        percentage: (combined * 100.0).clamp(0.0, 100.0),
+    })
+}
+
+/// Cosine similarity between two sparse named vectors, treating a name absent
+/// from one side as a zero weight. Returns 0.0 if either vector is entirely
+/// zero (e.g. a profile with no director data).
+fn cosine_similarity(a: &[(&str, f32)], b: &[(&str, f32)]) -> f32 {
+    let weights_b: HashMap<&str, f32> = b.iter().copied().collect();
+
+    let dot: f32 = a
+        .iter()
+        .map(|(name, weight)| weight * weights_b.get(name).copied().unwrap_or(0.0))
+        .sum();
+    let norm_a: f32 = a.iter().map(|(_, w)| w * w).sum::<f32>().sqrt();
+    let norm_b: f32 = b.iter().map(|(_, w)| w * w).sum::<f32>().sqrt();
+
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a * norm_b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile::{
+        ComprehensiveProfile, DirectorStats, EnhancedStatistics, GenreStats, RatingDistribution,
+        UserStatistics,
+    };
+
+    fn profile(genres: &[(&str, f32)], directors: &[(&str, u32)]) -> ComprehensiveProfile {
+        ComprehensiveProfile {
+            name: "Test User".to_string(),
+            username: "testuser".to_string(),
+            avatar_url: None,
+            bio: None,
+            location: None,
+            website: None,
+            total_films: 0,
+            films_this_year: 0,
+            lists_count: 0,
+            following_count: 0,
+            followers_count: 0,
+            favorite_films: Vec::new(),
+            recent_activity: Vec::new(),
+            all_movies: Vec::new(),
+            watchlist: Vec::new(),
+            lists: Vec::new(),
+            member_since: None,
+            movies_loaded: 0,
+            total_movies_available: 0,
+            watchlist_loaded: 0,
+            total_watchlist_available: 0,
+            enhanced_stats: Some(EnhancedStatistics {
+                basic_stats: UserStatistics {
+                    total_viewing_time_hours: 0.0,
+                    average_film_length: 0.0,
+                    longest_streak_days: 0,
+                    current_streak_days: 0,
+                    days_with_multiple_films: 0,
+                    unique_directors_count: 0,
+                    unique_countries_count: 0,
+                    unique_genres_count: 0,
+                    average_rating: 0.0,
+                    most_watched_year: None,
+                    most_watched_decade: None,
+                    average_watches_per_week: None,
+                    projected_year_end_total: None,
+                },
+                genre_breakdown: genres
+                    .iter()
+                    .map(|(name, percentage)| GenreStats {
+                        name: name.to_string(),
+                        count: 1,
+                        percentage: *percentage,
+                        average_rating: 0.0,
+                        emoji: String::new(),
+                        top_film: None,
+                    })
+                    .collect(),
+                country_breakdown: Vec::new(),
+                director_stats: directors
+                    .iter()
+                    .map(|(name, film_count)| DirectorStats {
+                        name: name.to_string(),
+                        film_count: *film_count,
+                        average_rating: 0.0,
+                        favorite_film: None,
+                    })
+                    .collect(),
+                yearly_breakdown: Vec::new(),
+                rating_distribution: Vec::<RatingDistribution>::new(),
+                viewing_patterns: Vec::new(),
+                data_source: "calculated".to_string(),
+                capped_at: None,
+                longest_film: None,
+                shortest_film: None,
+                runtime_sample_size: 0,
+                average_contrarianness: None,
+                community_comparison: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn compute_returns_none_below_min_genre_count() {
+        let a = profile(&[("Drama", 50.0), ("Comedy", 50.0)], &[]);
+        let b = profile(&[("Drama", 40.0), ("Comedy", 30.0), ("Horror", 30.0)], &[]);
+        assert!(compute(&a, &b).is_none());
+    }
+
+    #[test]
+    fn compute_scores_identical_profiles_near_100_percent() {
+        let genres = [("Drama", 50.0), ("Comedy", 30.0), ("Horror", 20.0)];
+        let directors = [("Bong Joon-ho", 3u32)];
+        let a = profile(&genres, &directors);
+        let b = profile(&genres, &directors);
+
+        let score = compute(&a, &b).expect("enough genres on both sides");
+        assert!(
+            (score.percentage - 100.0).abs() < 0.01,
+            "expected ~100%, got {}",
+            score.percentage
+        );
+    }
+
+    #[test]
+    fn compute_handles_missing_director_data_without_nan_or_panic() {
+        let a = profile(&[("Drama", 50.0), ("Comedy", 30.0), ("Horror", 20.0)], &[]);
+        let b = profile(&[("Drama", 20.0), ("Comedy", 30.0), ("Horror", 50.0)], &[]);
+
+        let score = compute(&a, &b).expect("enough genres on both sides");
+        assert!(score.percentage.is_finite());
+        assert!((0.0..=100.0).contains(&score.percentage));
+    }
+
+    #[test]
+    fn cosine_similarity_of_two_zero_vectors_is_zero() {
+        assert_eq!(cosine_similarity(&[], &[]), 0.0);
+    }
+}
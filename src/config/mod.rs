@@ -1,25 +1,276 @@
 use anyhow::Result;
+use colored::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::env;
 use std::fs;
 use std::path::PathBuf;
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub enum ColorMode {
+    #[default]
     Color,
     Grayscale,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// Whether posters rendered via `viu` are desaturated, independent of
+/// `ColorMode` (which otherwise only affects text colors). `Auto` follows
+/// `ColorMode::Grayscale` so the two stay in sync by default; `On`/`Off`
+/// decouple poster desaturation from the text color mode for users who want
+/// one without the other.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum PosterGrayscale {
+    #[default]
+    Auto,
+    On,
+    Off,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub enum DisplayMode {
+    #[default]
     Pixelated,
     FullResolution,
 }
 
+/// Color depth for the pure-Rust ASCII poster fallback used when the
+/// external `viu` binary isn't installed (see `crate::ascii`). `Auto`
+/// detects the terminal's capability from `COLORTERM`/`TERM`; the others
+/// force a specific depth, e.g. for recording output that will be viewed
+/// somewhere with different terminal capabilities than the one it was
+/// captured in.
+/// Which character set the pure-Rust ASCII poster fallback draws with.
+/// `Blocks` fills each cell with a single averaged-color block character
+/// (see `crate::ascii::render`); `Braille` instead packs a 2x4 grid of
+/// Braille dots (U+2800 block) into each cell for much higher spatial
+/// detail, at the cost of being monochrome — dot patterns can't carry a
+/// per-cell color the way a solid block can.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum PosterStyle {
+    #[default]
+    Blocks,
+    Braille,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum AsciiColorDepth {
+    #[default]
+    Auto,
+    TrueColor,
+    Color256,
+    Color16,
+    Mono,
+}
+
+/// Named color presets for non-TUI CLI output, independent of the TUI theme.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub enum Theme {
+    #[default]
+    Letterboxd,
+    Solarized,
+    Mono,
+}
+
+/// Controls how logged TV episodes are folded into stats once TV-series
+/// support lands. Currently a no-op: the diary only contains films.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub enum TvAggregationMode {
+    #[default]
+    PerEpisode,
+    PerSeries,
+}
+
+/// Which stat is shown prominently in the TUI header.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub enum HeadlineStat {
+    #[default]
+    TotalFilms,
+    ViewingHours,
+    FilmsThisYear,
+}
+
+/// Which data-fetching strategy a command uses when both are available.
+/// `Rss` hits Letterboxd's RSS feed: fast, but capped to its ~50 most recent
+/// entries and missing some fields (e.g. rewatch flags). `Native` goes
+/// through `rustboxd` (HTML scraping): slower, but complete.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum DataClient {
+    #[default]
+    Rss,
+    Native,
+}
+
+/// Which layout the TUI's details pane uses, cycled with `v` in `browse`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum DetailsViewMode {
+    #[default]
+    Full,
+    PosterOnly,
+    ReviewOnly,
+}
+
+/// How dates (watched dates, export timestamps) are rendered across
+/// `DisplayEngine`, the TUI, and exports. `Custom` holds a user-supplied
+/// chrono strftime string, validated at config-set time.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub enum DateFormat {
+    /// "2024-03-14"
+    #[default]
+    Iso,
+    /// "March 14, 2024"
+    Us,
+    /// "14 March 2024" — the common international (day-month) order.
+    Eu,
+    Custom(String),
+}
+
+impl DateFormat {
+    /// The chrono strftime pattern this format renders with.
+    pub fn strftime_pattern(&self) -> &str {
+        match self {
+            DateFormat::Iso => "%Y-%m-%d",
+            DateFormat::Us => "%B %d, %Y",
+            DateFormat::Eu => "%d %B %Y",
+            DateFormat::Custom(pattern) => pattern,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     pub username: Option<String>,
+    /// Color mode for CLI text output. Defaults to `Color` so config files
+    /// predating this field still deserialize.
+    #[serde(default)]
     pub color_mode: ColorMode,
+    /// Whether posters rendered via `viu` are desaturated. See
+    /// `PosterGrayscale` for how this relates to `color_mode`.
+    #[serde(default)]
+    pub poster_grayscale: PosterGrayscale,
+    /// Poster rendering style: blocky/pixelated vs. full-resolution. Defaults
+    /// to `Pixelated` so config files predating this field still deserialize.
+    #[serde(default)]
     pub display_mode: DisplayMode,
+    #[serde(default)]
+    pub theme: Theme,
+    #[serde(default)]
+    pub tmdb_api_key: Option<String>,
+    #[serde(default)]
+    pub omdb_api_key: Option<String>,
+    #[serde(default)]
+    pub tv_aggregation: TvAggregationMode,
+    #[serde(default)]
+    pub skip_enrichment_by_default: bool,
+    /// Number of diary entries surfaced as "recent activity" in profile stats.
+    #[serde(default = "default_recent_activity_count")]
+    pub recent_activity_count: u32,
+    /// Render posters as binary filled/empty blocks instead of a gray ramp,
+    /// for users who find shade-ramp ASCII art hard to read. Takes effect
+    /// once rendering goes through the native poster renderer rather than
+    /// `viu`, which has no notion of ASCII shade ramps to threshold.
+    #[serde(default)]
+    pub high_contrast_posters: bool,
+    /// Luminance cutoff (0-255) used by `high_contrast_posters`: pixels at or
+    /// above this value render as "filled", everything else as "empty".
+    #[serde(default = "default_high_contrast_threshold")]
+    pub high_contrast_threshold: u8,
+    /// Caps how many of the most recent diary entries are loaded and have
+    /// stats computed over them. `None` means no cap (load the whole diary).
+    /// Keeps huge diaries (thousands of films) fast to load.
+    #[serde(default)]
+    pub max_diary_entries: Option<u32>,
+    /// Which stat is shown prominently in the TUI header.
+    #[serde(default)]
+    pub headline_stat: HeadlineStat,
+    /// Last-used layout for the TUI's details pane, restored on the next `browse`.
+    #[serde(default)]
+    pub details_view_mode: DetailsViewMode,
+    /// Ring the terminal bell (and, when built with the `desktop-notify`
+    /// feature, show a desktop notification) when a long-running `browse`
+    /// load or `export-batch` finishes. Off by default since a bell on every
+    /// run would be more annoying than helpful for fast fetches.
+    #[serde(default)]
+    pub notify_on_completion: bool,
+    /// How dates are rendered across `DisplayEngine`, the TUI, and exports.
+    #[serde(default)]
+    pub date_format: DateFormat,
+    /// Contact info (URL or email) embedded in the RSS fetcher's User-Agent header,
+    /// e.g. `lbxd/3.0.0 (https://example.com)`. Defaults to the project homepage;
+    /// override this if you're running lbxd as part of a larger service and
+    /// Letterboxd needs a way to reach you about its traffic.
+    #[serde(default)]
+    pub rss_contact: Option<String>,
+    /// TMDB content-negotiation language, e.g. `fr-FR`, sent as TMDB's
+    /// `language` query param so titles/overviews come back localized.
+    /// `None` means auto-detect from `$LANG`, falling back to `en-US`.
+    #[serde(default)]
+    pub tmdb_language: Option<String>,
+    /// TMDB region code, e.g. `US`, `GB`, sent as TMDB's `region` query param
+    /// so "now playing" reflects theatrical releases in the user's market.
+    /// `None` means TMDB falls back to its own default region.
+    #[serde(default)]
+    pub tmdb_region: Option<String>,
+    /// Maximum bytes a poster download may stream before being aborted, to
+    /// bound memory use against a malicious or mis-linked image URL.
+    #[serde(default = "default_max_image_download_bytes")]
+    pub max_image_download_bytes: u64,
+    /// Default data-fetching strategy for commands that can use either RSS
+    /// or the native `rustboxd` client (e.g. `recent`, `search`). Overridden
+    /// per-invocation by `--client`.
+    #[serde(default)]
+    pub default_client: DataClient,
+    /// Collapse same-film, same-calendar-day diary entries into a single
+    /// `UserMovieEntry` with a "×N" `same_day_rewatch_count`, instead of
+    /// keeping each logged entry separate. On by default since separate
+    /// same-day entries otherwise inflate per-day/per-film counts in stats
+    /// and exports.
+    #[serde(default = "default_merge_same_day_rewatches")]
+    pub merge_same_day_rewatches: bool,
+    /// Color depth for the pure-Rust ASCII poster fallback. See
+    /// `AsciiColorDepth`.
+    #[serde(default)]
+    pub ascii_color_depth: AsciiColorDepth,
+    /// Character set for the pure-Rust ASCII poster fallback. See
+    /// `PosterStyle`.
+    #[serde(default)]
+    pub poster_style: PosterStyle,
+    /// Maximum number of background network tasks (poster prefetch, OMDB
+    /// enrichment) the TUI runs at once. Bounds the thundering-herd
+    /// potential of rapid scrolling or pagination against TMDB/OMDB.
+    #[serde(default = "default_tui_background_task_limit")]
+    pub tui_background_task_limit: usize,
+    /// Show watch dates relative to now (e.g. "3 days ago", "last month")
+    /// instead of the absolute `date_format`, in `recent`/`diary` and the
+    /// TUI details view. Dates a year or older always fall back to
+    /// absolute, since "11 months ago" stops being more useful than a date.
+    #[serde(default)]
+    pub relative_dates: bool,
+    /// Shortcuts for commonly-used usernames, e.g. `club -> "some_username"`,
+    /// expanded by `resolve_username` before the literal argument is used.
+    /// `me` is a separate built-in shortcut (see `username`) and isn't
+    /// stored here.
+    #[serde(default)]
+    pub aliases: HashMap<String, String>,
+}
+
+fn default_recent_activity_count() -> u32 {
+    10
+}
+
+fn default_tui_background_task_limit() -> usize {
+    4
+}
+
+fn default_high_contrast_threshold() -> u8 {
+    128
+}
+
+fn default_max_image_download_bytes() -> u64 {
+    10 * 1024 * 1024 // 10 MB
+}
+
+fn default_merge_same_day_rewatches() -> bool {
+    true
 }
 
 impl Default for Config {
@@ -27,13 +278,42 @@ impl Default for Config {
         Self {
             username: None,
             color_mode: ColorMode::Color,
+            poster_grayscale: PosterGrayscale::Auto,
             display_mode: DisplayMode::Pixelated,
+            theme: Theme::default(),
+            tmdb_api_key: None,
+            omdb_api_key: None,
+            tv_aggregation: TvAggregationMode::default(),
+            skip_enrichment_by_default: false,
+            recent_activity_count: default_recent_activity_count(),
+            high_contrast_posters: false,
+            high_contrast_threshold: default_high_contrast_threshold(),
+            max_diary_entries: None,
+            headline_stat: HeadlineStat::default(),
+            details_view_mode: DetailsViewMode::default(),
+            notify_on_completion: false,
+            date_format: DateFormat::default(),
+            rss_contact: None,
+            tmdb_language: None,
+            tmdb_region: None,
+            max_image_download_bytes: default_max_image_download_bytes(),
+            default_client: DataClient::default(),
+            merge_same_day_rewatches: default_merge_same_day_rewatches(),
+            ascii_color_depth: AsciiColorDepth::default(),
+            poster_style: PosterStyle::default(),
+            tui_background_task_limit: default_tui_background_task_limit(),
+            relative_dates: false,
+            aliases: HashMap::new(),
         }
     }
 }
 
 pub struct ConfigManager {
     config_path: PathBuf,
+    /// Pre-3.x config location. No longer written to, but still read once by
+    /// `load_config` so users upgrading from a JSON config aren't reset to
+    /// defaults; the first `save_config` after that migrates them to TOML.
+    legacy_json_path: PathBuf,
 }
 
 impl ConfigManager {
@@ -41,25 +321,80 @@ impl ConfigManager {
         let config_dir = Self::get_config_dir()?;
         fs::create_dir_all(&config_dir)?;
 
-        let config_path = config_dir.join("config.json");
+        let config_path = config_dir.join("config.toml");
+        let legacy_json_path = config_dir.join("config.json");
+
+        Ok(Self {
+            config_path,
+            legacy_json_path,
+        })
+    }
 
-        Ok(Self { config_path })
+    /// Builds a `ConfigManager` pointed at explicit paths instead of the
+    /// real config directory. Only used by tests that need to exercise
+    /// `load_config`/`save_config` against a scratch directory.
+    #[cfg(test)]
+    pub(crate) fn with_paths(config_path: PathBuf, legacy_json_path: PathBuf) -> Self {
+        Self {
+            config_path,
+            legacy_json_path,
+        }
     }
 
+    /// Loads the config from `config.toml`, falling back to a legacy
+    /// `config.json` (pre-3.x) when no TOML config has been written yet. The
+    /// legacy file is left in place — the migration completes silently the
+    /// next time any setting is saved, which rewrites it as TOML.
+    ///
+    /// A `config.toml` that fails to parse (e.g. hand-edited into invalid
+    /// syntax) is backed up to `config.toml.bak` rather than silently
+    /// discarded, a warning is printed, and a fresh default config is
+    /// written and returned.
     pub fn load_config(&self) -> Result<Config> {
-        if !self.config_path.exists() {
-            return Ok(Config::default());
+        if self.config_path.exists() {
+            let content = fs::read_to_string(&self.config_path)?;
+            return match toml::from_str(&content) {
+                Ok(config) => Ok(config),
+                Err(_) => self.recover_from_corrupt_config(&content),
+            };
         }
 
-        let content = fs::read_to_string(&self.config_path)?;
-        let config: Config = serde_json::from_str(&content).unwrap_or_else(|_| Config::default());
+        if self.legacy_json_path.exists() {
+            let content = fs::read_to_string(&self.legacy_json_path)?;
+            let config: Config =
+                serde_json::from_str(&content).unwrap_or_else(|_| Config::default());
+            return Ok(config);
+        }
 
-        Ok(config)
+        Ok(Config::default())
+    }
+
+    /// Backs up a config file that failed to parse to `<path>.bak`, warns
+    /// the user, writes a fresh default config in its place, and returns
+    /// that default.
+    fn recover_from_corrupt_config(&self, corrupt_content: &str) -> Result<Config> {
+        let backup_path = PathBuf::from(format!("{}.bak", self.config_path.display()));
+        crate::util::atomic_write(&backup_path, corrupt_content)?;
+
+        eprintln!(
+            "{}",
+            format!(
+                "Warning: config file at {} was malformed and has been backed up to {}. \
+                 Starting from a fresh default configuration.",
+                self.config_path.display(),
+                backup_path.display()
+            )
+            .yellow()
+        );
+
+        let default_config = Config::default();
+        self.save_config(&default_config)?;
+        Ok(default_config)
     }
 
     pub fn save_config(&self, config: &Config) -> Result<()> {
-        let content = serde_json::to_string_pretty(config)?;
-        fs::write(&self.config_path, content)?;
+        let content = toml::to_string_pretty(config)?;
+        crate::util::atomic_write(&self.config_path, &content)?;
         Ok(())
     }
 
@@ -103,6 +438,334 @@ impl ConfigManager {
         Ok(config.color_mode)
     }
 
+    pub fn set_poster_grayscale(&self, mode: PosterGrayscale) -> Result<()> {
+        let mut config = self.load_config()?;
+        config.poster_grayscale = mode;
+        self.save_config(&config)
+    }
+
+    pub fn get_poster_grayscale(&self) -> Result<PosterGrayscale> {
+        let config = self.load_config()?;
+        Ok(config.poster_grayscale)
+    }
+
+    /// Whether posters should actually be desaturated, resolving `Auto`
+    /// against `color_mode` so callers don't need to know about the two
+    /// settings' interaction.
+    pub fn get_effective_poster_grayscale(&self) -> Result<bool> {
+        let config = self.load_config()?;
+        Ok(match config.poster_grayscale {
+            PosterGrayscale::Auto => config.color_mode == ColorMode::Grayscale,
+            PosterGrayscale::On => true,
+            PosterGrayscale::Off => false,
+        })
+    }
+
+    pub fn set_theme(&self, theme: Theme) -> Result<()> {
+        let mut config = self.load_config()?;
+        config.theme = theme;
+        self.save_config(&config)
+    }
+
+    pub fn get_theme(&self) -> Result<Theme> {
+        let config = self.load_config()?;
+        Ok(config.theme)
+    }
+
+    pub fn set_tmdb_api_key(&self, key: String) -> Result<()> {
+        let mut config = self.load_config()?;
+        config.tmdb_api_key = Some(key);
+        self.save_config(&config)
+    }
+
+    pub fn get_tmdb_api_key(&self) -> Result<Option<String>> {
+        let config = self.load_config()?;
+        Ok(config.tmdb_api_key)
+    }
+
+    pub fn set_omdb_api_key(&self, key: String) -> Result<()> {
+        let mut config = self.load_config()?;
+        config.omdb_api_key = Some(key);
+        self.save_config(&config)
+    }
+
+    pub fn get_omdb_api_key(&self) -> Result<Option<String>> {
+        let config = self.load_config()?;
+        Ok(config.omdb_api_key)
+    }
+
+    pub fn set_tv_aggregation(&self, mode: TvAggregationMode) -> Result<()> {
+        let mut config = self.load_config()?;
+        config.tv_aggregation = mode;
+        self.save_config(&config)
+    }
+
+    pub fn get_tv_aggregation(&self) -> Result<TvAggregationMode> {
+        let config = self.load_config()?;
+        Ok(config.tv_aggregation)
+    }
+
+    pub fn set_skip_enrichment_by_default(&self, skip: bool) -> Result<()> {
+        let mut config = self.load_config()?;
+        config.skip_enrichment_by_default = skip;
+        self.save_config(&config)
+    }
+
+    pub fn get_skip_enrichment_by_default(&self) -> Result<bool> {
+        let config = self.load_config()?;
+        Ok(config.skip_enrichment_by_default)
+    }
+
+    pub fn set_recent_activity_count(&self, count: u32) -> Result<()> {
+        let mut config = self.load_config()?;
+        config.recent_activity_count = count;
+        self.save_config(&config)
+    }
+
+    pub fn get_recent_activity_count(&self) -> Result<u32> {
+        let config = self.load_config()?;
+        Ok(config.recent_activity_count)
+    }
+
+    pub fn set_high_contrast_posters(&self, enabled: bool) -> Result<()> {
+        let mut config = self.load_config()?;
+        config.high_contrast_posters = enabled;
+        self.save_config(&config)
+    }
+
+    pub fn get_high_contrast_posters(&self) -> Result<bool> {
+        let config = self.load_config()?;
+        Ok(config.high_contrast_posters)
+    }
+
+    pub fn set_high_contrast_threshold(&self, threshold: u8) -> Result<()> {
+        let mut config = self.load_config()?;
+        config.high_contrast_threshold = threshold;
+        self.save_config(&config)
+    }
+
+    pub fn set_max_image_download_bytes(&self, max_bytes: u64) -> Result<()> {
+        let mut config = self.load_config()?;
+        config.max_image_download_bytes = max_bytes;
+        self.save_config(&config)
+    }
+
+    pub fn get_max_image_download_bytes(&self) -> Result<u64> {
+        let config = self.load_config()?;
+        Ok(config.max_image_download_bytes)
+    }
+
+    pub fn get_high_contrast_threshold(&self) -> Result<u8> {
+        let config = self.load_config()?;
+        Ok(config.high_contrast_threshold)
+    }
+
+    pub fn set_merge_same_day_rewatches(&self, enabled: bool) -> Result<()> {
+        let mut config = self.load_config()?;
+        config.merge_same_day_rewatches = enabled;
+        self.save_config(&config)
+    }
+
+    pub fn get_merge_same_day_rewatches(&self) -> Result<bool> {
+        let config = self.load_config()?;
+        Ok(config.merge_same_day_rewatches)
+    }
+
+    pub fn set_relative_dates(&self, enabled: bool) -> Result<()> {
+        let mut config = self.load_config()?;
+        config.relative_dates = enabled;
+        self.save_config(&config)
+    }
+
+    pub fn get_relative_dates(&self) -> Result<bool> {
+        let config = self.load_config()?;
+        Ok(config.relative_dates)
+    }
+
+    pub fn add_alias(&self, name: String, username: String) -> Result<()> {
+        let mut config = self.load_config()?;
+        config.aliases.insert(name, username);
+        self.save_config(&config)
+    }
+
+    pub fn remove_alias(&self, name: &str) -> Result<bool> {
+        let mut config = self.load_config()?;
+        let removed = config.aliases.remove(name).is_some();
+        self.save_config(&config)?;
+        Ok(removed)
+    }
+
+    pub fn get_alias(&self, name: &str) -> Result<Option<String>> {
+        let config = self.load_config()?;
+        Ok(config.aliases.get(name).cloned())
+    }
+
+    pub fn get_aliases(&self) -> Result<HashMap<String, String>> {
+        let config = self.load_config()?;
+        Ok(config.aliases)
+    }
+
+    pub fn set_ascii_color_depth(&self, depth: AsciiColorDepth) -> Result<()> {
+        let mut config = self.load_config()?;
+        config.ascii_color_depth = depth;
+        self.save_config(&config)
+    }
+
+    pub fn get_ascii_color_depth(&self) -> Result<AsciiColorDepth> {
+        let config = self.load_config()?;
+        Ok(config.ascii_color_depth)
+    }
+
+    /// Resolves `AsciiColorDepth::Auto` against the terminal's advertised
+    /// capability, so callers always get a concrete depth to render with.
+    pub fn get_effective_ascii_color_depth(&self) -> Result<AsciiColorDepth> {
+        Ok(match self.get_ascii_color_depth()? {
+            AsciiColorDepth::Auto => crate::ascii::detect_terminal_colors(),
+            other => other,
+        })
+    }
+
+    pub fn set_poster_style(&self, style: PosterStyle) -> Result<()> {
+        let mut config = self.load_config()?;
+        config.poster_style = style;
+        self.save_config(&config)
+    }
+
+    pub fn get_poster_style(&self) -> Result<PosterStyle> {
+        let config = self.load_config()?;
+        Ok(config.poster_style)
+    }
+
+    /// Sets the concurrency cap for the TUI's background task pool (poster
+    /// prefetch, OMDB enrichment). Clamped to at least 1: a cap of 0 would
+    /// deadlock the pool's semaphore.
+    pub fn set_tui_background_task_limit(&self, limit: usize) -> Result<()> {
+        let mut config = self.load_config()?;
+        config.tui_background_task_limit = limit.max(1);
+        self.save_config(&config)
+    }
+
+    pub fn get_tui_background_task_limit(&self) -> Result<usize> {
+        let config = self.load_config()?;
+        Ok(config.tui_background_task_limit)
+    }
+
+    /// Sets the diary fetch cap. Pass `None` to remove the cap (load the whole diary).
+    pub fn set_max_diary_entries(&self, max: Option<u32>) -> Result<()> {
+        let mut config = self.load_config()?;
+        config.max_diary_entries = max;
+        self.save_config(&config)
+    }
+
+    pub fn get_max_diary_entries(&self) -> Result<Option<u32>> {
+        let config = self.load_config()?;
+        Ok(config.max_diary_entries)
+    }
+
+    pub fn set_headline_stat(&self, stat: HeadlineStat) -> Result<()> {
+        let mut config = self.load_config()?;
+        config.headline_stat = stat;
+        self.save_config(&config)
+    }
+
+    pub fn get_headline_stat(&self) -> Result<HeadlineStat> {
+        let config = self.load_config()?;
+        Ok(config.headline_stat)
+    }
+
+    pub fn set_details_view_mode(&self, mode: DetailsViewMode) -> Result<()> {
+        let mut config = self.load_config()?;
+        config.details_view_mode = mode;
+        self.save_config(&config)
+    }
+
+    pub fn get_details_view_mode(&self) -> Result<DetailsViewMode> {
+        let config = self.load_config()?;
+        Ok(config.details_view_mode)
+    }
+
+    /// Sets the date display format. `DateFormat::Custom` strings are
+    /// validated against chrono's strftime parser first, so a typo is
+    /// caught here rather than surfacing as garbled output everywhere a
+    /// date is rendered.
+    pub fn set_date_format(&self, format: DateFormat) -> Result<()> {
+        if let DateFormat::Custom(ref pattern) = format {
+            Self::validate_date_format(pattern)?;
+        }
+        let mut config = self.load_config()?;
+        config.date_format = format;
+        self.save_config(&config)
+    }
+
+    pub fn get_date_format(&self) -> Result<DateFormat> {
+        let config = self.load_config()?;
+        Ok(config.date_format)
+    }
+
+    fn validate_date_format(pattern: &str) -> Result<()> {
+        let has_error = chrono::format::StrftimeItems::new(pattern)
+            .any(|item| matches!(item, chrono::format::Item::Error));
+        if has_error {
+            anyhow::bail!("Invalid date format string: {}", pattern);
+        }
+        Ok(())
+    }
+
+    pub fn set_notify_on_completion(&self, enabled: bool) -> Result<()> {
+        let mut config = self.load_config()?;
+        config.notify_on_completion = enabled;
+        self.save_config(&config)
+    }
+
+    pub fn get_notify_on_completion(&self) -> Result<bool> {
+        let config = self.load_config()?;
+        Ok(config.notify_on_completion)
+    }
+
+    pub fn set_rss_contact(&self, contact: String) -> Result<()> {
+        let mut config = self.load_config()?;
+        config.rss_contact = Some(contact);
+        self.save_config(&config)
+    }
+
+    pub fn get_rss_contact(&self) -> Result<Option<String>> {
+        let config = self.load_config()?;
+        Ok(config.rss_contact)
+    }
+
+    pub fn set_tmdb_language(&self, language: String) -> Result<()> {
+        let mut config = self.load_config()?;
+        config.tmdb_language = Some(language);
+        self.save_config(&config)
+    }
+
+    pub fn get_tmdb_language(&self) -> Result<Option<String>> {
+        let config = self.load_config()?;
+        Ok(config.tmdb_language)
+    }
+
+    pub fn set_tmdb_region(&self, region: String) -> Result<()> {
+        let mut config = self.load_config()?;
+        config.tmdb_region = Some(region);
+        self.save_config(&config)
+    }
+
+    pub fn get_tmdb_region(&self) -> Result<Option<String>> {
+        let config = self.load_config()?;
+        Ok(config.tmdb_region)
+    }
+
+    pub fn set_default_client(&self, client: DataClient) -> Result<()> {
+        let mut config = self.load_config()?;
+        config.default_client = client;
+        self.save_config(&config)
+    }
+
+    pub fn get_default_client(&self) -> Result<DataClient> {
+        let config = self.load_config()?;
+        Ok(config.default_client)
+    }
+
     pub fn change_username(&self, new_username: String) -> Result<()> {
         let mut config = self.load_config()?;
         config.username = Some(new_username);
@@ -113,7 +776,20 @@ impl ConfigManager {
         self.load_config()
     }
 
+    /// Returns the resolved config file path, for display in `config show`.
+    pub fn config_path(&self) -> &std::path::Path {
+        &self.config_path
+    }
+
+    /// Resolves the config directory, honoring `$XDG_CONFIG_HOME` when set
+    /// (and non-empty) and falling back to `~/.config/lbxd` otherwise.
     fn get_config_dir() -> Result<PathBuf> {
+        if let Ok(xdg_config_home) = env::var("XDG_CONFIG_HOME") {
+            if !xdg_config_home.is_empty() {
+                return Ok(PathBuf::from(xdg_config_home).join("lbxd"));
+            }
+        }
+
         let home_dir =
             dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Could not find home directory"))?;
 
@@ -1,10 +1,9 @@
-use crate::config::{ColorMode, ConfigManager, DisplayMode};
-use crate::models::{UserEntry, UserProfile, ViewingSummary};
-use crate::profile::ProfileStats;
+use crate::config::{ColorMode, ConfigManager, DisplayMode, Theme};
+use crate::models::{EntryType, MonthBreakdown, UserEntry, UserProfile, ViewingSummary};
+use crate::profile::{DetailedMovie, DirectorStats, EnhancedStatistics, GenreStats, ProfileStats};
 use crate::tmdb::{TMDBClient, TMDBMovie};
 use crate::viu::ViuViewer;
 use colored::*;
-use regex::Regex;
 use std::time::Duration;
 use terminal_size::{terminal_size, Height, Width};
 use tokio::time::interval;
@@ -12,6 +11,9 @@ use tokio::time::interval;
 pub struct DisplayEngine {
     tmdb_client: TMDBClient,
     viu_viewer: ViuViewer,
+    /// When true, render posters even if stdout isn't a terminal. Set from
+    /// the `--force-posters` CLI flag.
+    force_posters: bool,
 }
 
 impl Default for DisplayEngine {
@@ -25,9 +27,27 @@ impl DisplayEngine {
         Self {
             tmdb_client: TMDBClient::new(),
             viu_viewer: ViuViewer::new(),
+            force_posters: false,
         }
     }
 
+    /// Like [`Self::new`], but renders posters even when stdout isn't a
+    /// terminal (e.g. `lbxd recent me > file.txt`). Posters are otherwise
+    /// skipped automatically in that case, since image escape sequences
+    /// written to a pipe just produce garbage.
+    pub fn with_force_posters(force_posters: bool) -> Self {
+        Self {
+            force_posters,
+            ..Self::new()
+        }
+    }
+
+    /// Whether poster images should actually be rendered: either stdout is a
+    /// terminal, or the user opted in with `--force-posters`.
+    fn should_render_posters(&self) -> bool {
+        self.force_posters || std::io::IsTerminal::is_terminal(&std::io::stdout())
+    }
+
     fn get_display_mode(&self) -> bool {
         ConfigManager::new()
             .map(|cm| {
@@ -36,57 +56,133 @@ impl DisplayEngine {
             .unwrap_or(true)
     }
 
-    #[allow(dead_code)]
     fn get_color_mode(&self) -> ColorMode {
         ConfigManager::new()
             .map(|cm| cm.get_color_mode().unwrap_or(ColorMode::Color))
             .unwrap_or(ColorMode::Color)
     }
 
-    #[allow(dead_code)]
+    /// Whether posters should be desaturated before display. See
+    /// `PosterGrayscale` for how this relates to `ColorMode`.
+    fn get_poster_grayscale(&self) -> bool {
+        ConfigManager::new()
+            .and_then(|cm| cm.get_effective_poster_grayscale())
+            .unwrap_or(false)
+    }
+
+    /// Color depth for the pure-Rust ASCII poster fallback. See
+    /// `AsciiColorDepth`.
+    fn get_ascii_color_depth(&self) -> crate::config::AsciiColorDepth {
+        ConfigManager::new()
+            .and_then(|cm| cm.get_effective_ascii_color_depth())
+            .unwrap_or(crate::config::AsciiColorDepth::Color16)
+    }
+
+    /// Which character set the pure-Rust ASCII poster fallback draws with.
+    /// See `PosterStyle`.
+    fn get_poster_style(&self) -> crate::config::PosterStyle {
+        ConfigManager::new()
+            .and_then(|cm| cm.get_poster_style())
+            .unwrap_or_default()
+    }
+
+    /// Luminance cutoff used both by `high_contrast_posters` and, when
+    /// `PosterStyle::Braille` is active, as the dot on/off threshold.
+    fn get_braille_threshold(&self) -> u8 {
+        ConfigManager::new()
+            .and_then(|cm| cm.get_high_contrast_threshold())
+            .unwrap_or(128)
+    }
+
+    fn get_theme(&self) -> Theme {
+        ConfigManager::new()
+            .map(|cm| cm.get_theme().unwrap_or_default())
+            .unwrap_or_default()
+    }
+
+    /// Remaps a named ANSI color to its Solarized accent RGB, if the active
+    /// theme is `Solarized`. Other themes fall through to the plain named colors.
+    fn theme_rgb(&self, theme: &Theme, color: &str) -> Option<(u8, u8, u8)> {
+        if *theme != Theme::Solarized {
+            return None;
+        }
+        match color {
+            "red" | "bright_red" => Some((220, 50, 47)),
+            "green" | "bright_green" => Some((133, 153, 0)),
+            "yellow" | "bright_yellow" => Some((181, 137, 0)),
+            "blue" | "bright_blue" => Some((38, 139, 210)),
+            "magenta" | "bright_magenta" => Some((211, 54, 130)),
+            "cyan" | "bright_cyan" => Some((42, 161, 152)),
+            "white" | "bright_white" => Some((131, 148, 150)),
+            _ => None,
+        }
+    }
+
     fn apply_ansi_color(&self, text: &str, color: &str) -> String {
+        let theme = self.get_theme();
+        if theme == Theme::Mono {
+            return text.normal().to_string();
+        }
+
         match self.get_color_mode() {
-            ColorMode::Color => match color {
-                "red" => text.red().to_string(),
-                "green" => text.green().to_string(),
-                "yellow" => text.yellow().to_string(),
-                "blue" => text.blue().to_string(),
-                "magenta" => text.magenta().to_string(),
-                "cyan" => text.cyan().to_string(),
-                "white" => text.white().to_string(),
-                "bright_red" => text.bright_red().to_string(),
-                "bright_green" => text.bright_green().to_string(),
-                "bright_yellow" => text.bright_yellow().to_string(),
-                "bright_blue" => text.bright_blue().to_string(),
-                "bright_magenta" => text.bright_magenta().to_string(),
-                "bright_cyan" => text.bright_cyan().to_string(),
-                "bright_white" => text.bright_white().to_string(),
-                _ => text.normal().to_string(),
-            },
+            ColorMode::Color => {
+                if let Some((r, g, b)) = self.theme_rgb(&theme, color) {
+                    return text.truecolor(r, g, b).to_string();
+                }
+                match color {
+                    "red" => text.red().to_string(),
+                    "green" => text.green().to_string(),
+                    "yellow" => text.yellow().to_string(),
+                    "blue" => text.blue().to_string(),
+                    "magenta" => text.magenta().to_string(),
+                    "cyan" => text.cyan().to_string(),
+                    "white" => text.white().to_string(),
+                    "bright_red" => text.bright_red().to_string(),
+                    "bright_green" => text.bright_green().to_string(),
+                    "bright_yellow" => text.bright_yellow().to_string(),
+                    "bright_blue" => text.bright_blue().to_string(),
+                    "bright_magenta" => text.bright_magenta().to_string(),
+                    "bright_cyan" => text.bright_cyan().to_string(),
+                    "bright_white" => text.bright_white().to_string(),
+                    _ => text.normal().to_string(),
+                }
+            }
             ColorMode::Grayscale => text.normal().to_string(),
         }
     }
 
-    #[allow(dead_code)]
     fn apply_style_with_ansi_color(&self, text: &str, style: &str, color: &str) -> String {
+        let theme = self.get_theme();
+        if theme == Theme::Mono {
+            return match style {
+                "bold" => text.bold().to_string(),
+                "dimmed" => text.dimmed().to_string(),
+                _ => text.normal().to_string(),
+            };
+        }
+
         match self.get_color_mode() {
             ColorMode::Color => {
-                let colored_text = match color {
-                    "red" => text.red(),
-                    "green" => text.green(),
-                    "yellow" => text.yellow(),
-                    "blue" => text.blue(),
-                    "magenta" => text.magenta(),
-                    "cyan" => text.cyan(),
-                    "white" => text.white(),
-                    "bright_red" => text.bright_red(),
-                    "bright_green" => text.bright_green(),
-                    "bright_yellow" => text.bright_yellow(),
-                    "bright_blue" => text.bright_blue(),
-                    "bright_magenta" => text.bright_magenta(),
-                    "bright_cyan" => text.bright_cyan(),
-                    "bright_white" => text.bright_white(),
-                    _ => text.normal(),
+                let colored_text = if let Some((r, g, b)) = self.theme_rgb(&theme, color) {
+                    text.truecolor(r, g, b)
+                } else {
+                    match color {
+                        "red" => text.red(),
+                        "green" => text.green(),
+                        "yellow" => text.yellow(),
+                        "blue" => text.blue(),
+                        "magenta" => text.magenta(),
+                        "cyan" => text.cyan(),
+                        "white" => text.white(),
+                        "bright_red" => text.bright_red(),
+                        "bright_green" => text.bright_green(),
+                        "bright_yellow" => text.bright_yellow(),
+                        "bright_blue" => text.bright_blue(),
+                        "bright_magenta" => text.bright_magenta(),
+                        "bright_cyan" => text.bright_cyan(),
+                        "bright_white" => text.bright_white(),
+                        _ => text.normal(),
+                    }
                 };
 
                 match style {
@@ -108,7 +204,9 @@ impl DisplayEngine {
         profile: &UserProfile,
         limit: Option<usize>,
         vertical: bool,
+        group_by_date: bool,
         width: u32,
+        poster_width: u32,
     ) {
         // Use the new activity header method
         self.print_activity_header(&profile.username);
@@ -120,11 +218,48 @@ impl DisplayEngine {
         };
 
         if vertical {
-            for entry in entries_to_show.iter() {
-                self.display_entry_with_tmdb_lookup(entry, width).await;
+            if group_by_date {
+                self.display_entries_grouped_by_date(&entries_to_show, poster_width)
+                    .await;
+            } else {
+                for entry in entries_to_show.iter() {
+                    self.display_entry_with_tmdb_lookup(entry, poster_width)
+                        .await;
+                }
             }
         } else {
-            self.display_entries_horizontal_grid_tmdb(&entries_to_show, width)
+            self.display_entries_horizontal_grid_tmdb(&entries_to_show, width, poster_width)
+                .await;
+        }
+    }
+
+    /// Like the plain vertical layout, but inserts a date header ("── March 14, 2024
+    /// ──") before the run of entries watched on that day, matching how Letterboxd's
+    /// own diary groups entries. Entries without a `watched_date` are grouped last
+    /// under "Undated". Assumes `entries` is already date-sorted (as the feed/diary
+    /// provides it) — this only detects runs, it does not re-sort.
+    async fn display_entries_grouped_by_date(&self, entries: &[&UserEntry], poster_width: u32) {
+        let mut current_group: Option<Option<chrono::NaiveDate>> = None;
+
+        for entry in entries {
+            let group = entry.watched_date.map(|d| d.date_naive());
+
+            if current_group != Some(group) {
+                current_group = Some(group);
+
+                let label = match group {
+                    Some(date) => crate::util::format_naive_date(&date),
+                    None => "Undated".to_string(),
+                };
+
+                println!();
+                println!(
+                    "{}",
+                    self.apply_ansi_color(&format!("── {} ──", label), "bright_white")
+                );
+            }
+
+            self.display_entry_with_tmdb_lookup(entry, poster_width)
                 .await;
         }
     }
@@ -153,10 +288,42 @@ impl DisplayEngine {
             );
         }
 
+        if !summary.months_breakdown.is_empty() {
+            println!();
+            println!("{}", "  Monthly Breakdown:".bright_white());
+            self.show_monthly_chart(&summary.months_breakdown);
+        }
+
         println!();
         self.print_footer();
     }
 
+    /// Renders each month as a bar segmented by rating bucket (green for 4★+,
+    /// yellow for 3★-3.5★, red for anything lower or unrated), scaled to a
+    /// 20-character max width. Falls back to a single uncolored style in
+    /// grayscale mode via `apply_ansi_color`.
+    fn show_monthly_chart(&self, months: &[MonthBreakdown]) {
+        const BAR_WIDTH: usize = 20;
+        let max_total = months.iter().map(|m| m.total).max().unwrap_or(0).max(1);
+
+        for month in months {
+            let scale = |n: usize| (n * BAR_WIDTH) / max_total;
+
+            let high = "█".repeat(scale(month.high_rated));
+            let mid = "█".repeat(scale(month.mid_rated));
+            let low = "█".repeat(scale(month.low_rated));
+
+            let bar = format!(
+                "{}{}{}",
+                self.apply_ansi_color(&high, "bright_green"),
+                self.apply_ansi_color(&mid, "bright_yellow"),
+                self.apply_ansi_color(&low, "bright_red"),
+            );
+
+            println!("  {:<12} {} {}", month.month, bar, month.total);
+        }
+    }
+
     pub fn print_header(&self, title: &str) {
         let width = if let Some((Width(w), Height(_))) = terminal_size() {
             w as usize
@@ -165,9 +332,12 @@ impl DisplayEngine {
         };
 
         let border = "═".repeat(width);
-        println!("{}", border.bright_cyan());
-        println!("{}", title.bright_white().bold());
-        println!("{}", border.bright_cyan());
+        println!("{}", self.apply_ansi_color(&border, "bright_cyan"));
+        println!(
+            "{}",
+            self.apply_style_with_ansi_color(title, "bold", "bright_white")
+        );
+        println!("{}", self.apply_ansi_color(&border, "bright_cyan"));
         println!();
     }
 
@@ -179,13 +349,62 @@ impl DisplayEngine {
             80
         };
         let border = "─".repeat(width);
-        println!("{}", border.dimmed());
+        println!(
+            "{}",
+            self.apply_style_with_ansi_color(&border, "dimmed", "")
+        );
     }
 
     pub fn print_activity_header(&self, username: &str) {
         self.print_header(&format!("{} Activity", username));
     }
 
+    /// Renders a merged, time-sorted timeline of entries from multiple users
+    /// (`lbxd feed`), one compact line per entry, e.g.
+    /// "bob rated Dune ★★★★" or "alice reviewed Barbie".
+    pub fn show_feed(&self, items: &[(String, UserEntry)]) {
+        if items.is_empty() {
+            self.print_warning("No activity found.");
+            return;
+        }
+
+        self.print_header("🌐 Friends Feed");
+
+        for (username, entry) in items {
+            let verb = if entry.liked {
+                "liked"
+            } else {
+                match entry.entry_type {
+                    EntryType::Review => "reviewed",
+                    EntryType::Like => "liked",
+                    EntryType::List => "added to a list",
+                    EntryType::Watch => {
+                        if entry.rating.is_some() {
+                            "rated"
+                        } else {
+                            "watched"
+                        }
+                    }
+                }
+            };
+
+            let rating_suffix = entry
+                .rating
+                .map(|r| format!(" {}", self.rating_to_stars(r)))
+                .unwrap_or_default();
+
+            println!(
+                "  {} {} {}{}",
+                self.apply_style_with_ansi_color(username, "bold", "bright_cyan"),
+                verb,
+                entry.movie.title.bold(),
+                rating_suffix
+            );
+        }
+
+        self.print_footer();
+    }
+
     pub fn print_error(&self, message: &str) {
         eprintln!("{} {}", "✗".red().bold(), message.red());
     }
@@ -203,10 +422,20 @@ impl DisplayEngine {
     }
 
     pub fn print_minimal_logo(&self) {
-        println!("{}", "lbxd".bright_cyan().bold());
+        println!(
+            "{}",
+            self.apply_style_with_ansi_color("lbxd", "bold", "bright_cyan")
+        );
     }
 
     pub async fn print_loading_animation(&self, message: &str, duration_ms: u64) {
+        if !crate::util::stdout_is_terminal() {
+            println!("{}...", message.dimmed());
+            tokio::time::sleep(Duration::from_millis(duration_ms)).await;
+            println!("{} done", message.dimmed());
+            return;
+        }
+
         print!("{} ", message.dimmed());
         let frames = vec!["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
         let mut ticker = interval(Duration::from_millis(50));
@@ -225,14 +454,16 @@ impl DisplayEngine {
     }
 
     fn rating_to_stars(&self, rating: f32) -> String {
+        let rating = rating.clamp(0.0, 5.0);
         let full_stars = rating.floor() as usize;
         let has_half = rating - rating.floor() >= 0.5;
-        let mut stars = "★".repeat(full_stars).yellow().to_string();
+        let mut stars = self.apply_ansi_color(&"★".repeat(full_stars), "yellow");
         if has_half {
-            stars.push_str(&"☆".yellow().to_string());
+            stars.push_str(&self.apply_ansi_color("½", "yellow"));
         }
-        let empty_stars = 5 - full_stars - if has_half { 1 } else { 0 };
-        stars.push_str(&"☆".repeat(empty_stars).dimmed().to_string());
+        let filled_stars = full_stars + if has_half { 1 } else { 0 };
+        let empty_stars = 5usize.saturating_sub(filled_stars);
+        stars.push_str(&self.apply_style_with_ansi_color(&"☆".repeat(empty_stars), "dimmed", ""));
         stars
     }
 
@@ -258,19 +489,202 @@ impl DisplayEngine {
             stats.followers_count.to_string().cyan().bold()
         );
 
+        if let Some(per_week) = stats.average_watches_per_week {
+            match stats.projected_year_end_total {
+                Some(projected) => println!(
+                    "  {} films/week (on track for {} films this year)",
+                    format!("{:.1}", per_week).cyan().bold(),
+                    projected.to_string().cyan().bold()
+                ),
+                None => println!("  {} films/week", format!("{:.1}", per_week).cyan().bold()),
+            }
+        }
+
         // Display favorite films if available
         if !stats.favorite_films.is_empty() {
             println!();
             println!("{}", "Favorite Films:".bright_white().bold());
-            for film in stats.favorite_films.iter().take(4) {
-                println!("  • {}", film.title.cyan());
+
+            if crate::util::stdout_is_terminal() && ViuViewer::is_available() {
+                self.show_favorites_poster_strip(&stats.favorite_films)
+                    .await;
+            } else {
+                for film in stats.favorite_films.iter().take(4) {
+                    println!("  • {}", film.title.cyan());
+                }
             }
         }
 
         self.print_footer();
     }
 
-    pub async fn show_tmdb_movie(&self, movie: &TMDBMovie, width: u32) {
+    /// Renders up to four favorite films as a strip of posters (title below each),
+    /// resolving all of them against TMDB concurrently. `viu` has no native
+    /// side-by-side layout, so the strip prints one poster after another rather than
+    /// true side-by-side columns; callers should only use this path when posters are
+    /// actually viewable (TTY + `viu` installed) and fall back to the bullet list
+    /// otherwise.
+    async fn show_favorites_poster_strip(&self, favorites: &[crate::profile::FavoriteFilm]) {
+        let handles: Vec<_> = favorites
+            .iter()
+            .take(4)
+            .cloned()
+            .map(|film| {
+                tokio::spawn(async move {
+                    let tmdb = TMDBClient::new();
+                    let movie = tmdb
+                        .search_movie_with_year(&film.title, film.year.map(|y| y as i32))
+                        .await
+                        .ok()
+                        .flatten();
+                    (film, movie)
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            let (film, movie) = match handle.await {
+                Ok(pair) => pair,
+                Err(_) => continue,
+            };
+
+            let poster_url = movie
+                .as_ref()
+                .and_then(|m| m.get_full_poster_url())
+                .or(film.poster_url);
+
+            if let Some(url) = poster_url {
+                self.print_loading_animation("Loading poster...", 200).await;
+                let use_pixelated = self.get_display_mode();
+                let grayscale = self.get_poster_grayscale();
+                let _ = self
+                    .viu_viewer
+                    .display_image_url(&url, 30, use_pixelated, grayscale)
+                    .await;
+            }
+
+            println!("{}", film.title.cyan().bold());
+        }
+    }
+
+    pub async fn show_stats_diff(
+        &self,
+        username: &str,
+        year_a: i32,
+        stats_a: &EnhancedStatistics,
+        year_b: i32,
+        stats_b: &EnhancedStatistics,
+    ) {
+        self.print_header(&format!("📊 {} - {} vs {}", username, year_a, year_b));
+
+        let films_a: u32 = stats_a.yearly_breakdown.iter().map(|y| y.film_count).sum();
+        let films_b: u32 = stats_b.yearly_breakdown.iter().map(|y| y.film_count).sum();
+        Self::print_delta_row("Films watched", films_a as i64, films_b as i64);
+
+        let rewatches_a: u32 = stats_a
+            .yearly_breakdown
+            .iter()
+            .map(|y| y.rewatch_count)
+            .sum();
+        let rewatches_b: u32 = stats_b
+            .yearly_breakdown
+            .iter()
+            .map(|y| y.rewatch_count)
+            .sum();
+        Self::print_delta_row("Rewatches", rewatches_a as i64, rewatches_b as i64);
+
+        let avg_delta = stats_b.basic_stats.average_rating - stats_a.basic_stats.average_rating;
+        println!(
+            "  Average rating: {:.2} -> {:.2} {}",
+            stats_a.basic_stats.average_rating,
+            stats_b.basic_stats.average_rating,
+            Self::arrow_f32(avg_delta)
+        );
+
+        Self::print_delta_row(
+            "Unique directors",
+            stats_a.basic_stats.unique_directors_count as i64,
+            stats_b.basic_stats.unique_directors_count as i64,
+        );
+
+        Self::print_delta_row(
+            "Unique genres",
+            stats_a.basic_stats.unique_genres_count as i64,
+            stats_b.basic_stats.unique_genres_count as i64,
+        );
+
+        let top_genre_a = stats_a.genre_breakdown.first().map(|g| g.name.as_str());
+        let top_genre_b = stats_b.genre_breakdown.first().map(|g| g.name.as_str());
+        match (top_genre_a, top_genre_b) {
+            (Some(a), Some(b)) if a != b => {
+                println!("  Top genre: {} -> {}", a.yellow(), b.yellow().bold());
+            }
+            (None, Some(b)) => println!("  Top genre: none -> {}", b.yellow().bold()),
+            (Some(a), Some(_)) => println!("  Top genre: {} (unchanged)", a.yellow()),
+            _ => {}
+        }
+
+        if let (Some(longest), Some(shortest)) = (&stats_b.longest_film, &stats_b.shortest_film) {
+            println!(
+                "  Longest film ({}): {} ({})",
+                year_b,
+                longest.title.cyan(),
+                crate::util::format_runtime_minutes(longest.runtime_minutes)
+            );
+            println!(
+                "  Shortest film ({}): {} ({})",
+                year_b,
+                shortest.title.cyan(),
+                crate::util::format_runtime_minutes(shortest.runtime_minutes)
+            );
+        }
+
+        let directors_a: std::collections::HashSet<&str> = stats_a
+            .director_stats
+            .iter()
+            .map(|d| d.name.as_str())
+            .collect();
+        let new_directors: Vec<&str> = stats_b
+            .director_stats
+            .iter()
+            .map(|d| d.name.as_str())
+            .filter(|name| !directors_a.contains(name))
+            .collect();
+
+        if !new_directors.is_empty() {
+            println!();
+            println!("{}", "Directors discovered:".bright_white().bold());
+            for director in new_directors.iter().take(10) {
+                println!("  • {}", director.cyan());
+            }
+        }
+
+        self.print_footer();
+    }
+
+    fn print_delta_row(label: &str, a: i64, b: i64) {
+        println!("  {}: {} -> {} {}", label, a, b, Self::arrow(b - a));
+    }
+
+    fn arrow(delta: i64) -> colored::ColoredString {
+        match delta.cmp(&0) {
+            std::cmp::Ordering::Greater => format!("▲ +{}", delta).green().bold(),
+            std::cmp::Ordering::Less => format!("▼ {}", delta).red().bold(),
+            std::cmp::Ordering::Equal => "– 0".dimmed(),
+        }
+    }
+
+    fn arrow_f32(delta: f32) -> colored::ColoredString {
+        if delta > 0.001 {
+            format!("▲ +{:.2}", delta).green().bold()
+        } else if delta < -0.001 {
+            format!("▼ {:.2}", delta).red().bold()
+        } else {
+            "– 0.00".dimmed()
+        }
+    }
+
+    pub async fn show_tmdb_movie(&self, movie: &TMDBMovie, poster_width: u32) {
         self.print_header(&format!("🎬 {}", movie.title));
 
         // Display movie details with poster
@@ -284,7 +698,7 @@ impl DisplayEngine {
             None,
             None,
             None,
-            width,
+            poster_width,
         )
         .await;
 
@@ -292,6 +706,142 @@ impl DisplayEngine {
         TMDBClient::print_tmdb_attribution();
     }
 
+    /// Prints a "critical consensus" view: TMDB/IMDb/Rotten Tomatoes/Metacritic
+    /// scores normalized to a common 0-10 scale and rendered as bars, with the
+    /// source that deviates most from the others' average flagged as an
+    /// outlier. A source with no data is omitted rather than shown as empty.
+    pub fn show_rating_comparison(
+        &self,
+        title: &str,
+        tmdb: Option<f32>,
+        imdb: Option<f32>,
+        rotten_tomatoes: Option<u8>,
+        metacritic: Option<u8>,
+    ) {
+        self.print_header(&format!("⚖ {} - Critical Consensus", title));
+
+        let sources: Vec<(&str, Option<f32>)> = vec![
+            ("TMDB", tmdb),
+            ("IMDb", imdb),
+            ("Rotten Tomatoes", rotten_tomatoes.map(|v| v as f32 / 10.0)),
+            ("Metacritic", metacritic.map(|v| v as f32 / 10.0)),
+        ];
+
+        let available: Vec<(&str, f32)> = sources
+            .into_iter()
+            .filter_map(|(name, score)| score.map(|s| (name, s)))
+            .collect();
+
+        if available.is_empty() {
+            self.print_warning("No rating sources available for this film.");
+            self.print_footer();
+            return;
+        }
+
+        let outlier = Self::find_outlier(&available);
+
+        for (name, score) in &available {
+            let bar_width = 20;
+            let filled = ((score / 10.0) * bar_width as f32).round() as usize;
+            let bar = "█".repeat(filled) + &"░".repeat(bar_width - filled);
+
+            let is_outlier = outlier == Some(*name);
+            let label = format!("{:<16}", name);
+            let score_str = format!("{:.1}/10", score);
+
+            if is_outlier {
+                println!(
+                    "  {} {} {} ⚠ outlier",
+                    label.yellow().bold(),
+                    bar.yellow(),
+                    score_str.yellow().bold()
+                );
+            } else {
+                println!("  {} {} {}", label.cyan(), bar.cyan(), score_str);
+            }
+        }
+
+        self.print_footer();
+    }
+
+    /// Returns the source whose score deviates most from the average of all
+    /// *other* sources, when there are at least two sources to compare.
+    fn find_outlier<'a>(scores: &[(&'a str, f32)]) -> Option<&'a str> {
+        if scores.len() < 2 {
+            return None;
+        }
+
+        scores
+            .iter()
+            .map(|(name, score)| {
+                let others: Vec<f32> = scores
+                    .iter()
+                    .filter(|(n, _)| n != name)
+                    .map(|(_, s)| *s)
+                    .collect();
+                let others_avg = others.iter().sum::<f32>() / others.len() as f32;
+                (*name, (score - others_avg).abs())
+            })
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+            .map(|(name, _)| name)
+    }
+
+    /// Prints the `compare` command's per-user summary table (films, average
+    /// rating, review count), in whatever order `rows` is already sorted to.
+    pub fn show_compare_summary_table(&self, rows: &[crate::profile::CompareSummaryRow]) {
+        self.print_header("▲ User Summary");
+
+        for row in rows {
+            let rating_str = row
+                .average_rating
+                .map(|r| format!("{:.2}", r))
+                .unwrap_or_else(|| "-".to_string());
+
+            println!(
+                "{}\tFilms: {}\tAvg Rating: {}\tReviews: {}",
+                row.username.bright_white().bold(),
+                row.total_films,
+                rating_str,
+                row.review_count
+            );
+        }
+
+        self.print_footer();
+    }
+
+    pub fn show_compatibility_score(
+        &self,
+        username_a: &str,
+        username_b: &str,
+        score: Option<&crate::compatibility::CompatibilityScore>,
+    ) {
+        self.print_header(&format!(
+            "💞 {} vs {} - Taste Match",
+            username_a, username_b
+        ));
+
+        let Some(score) = score else {
+            self.print_warning("Insufficient data to compute a taste match for these users.");
+            self.print_footer();
+            return;
+        };
+
+        let bar_width = 30;
+        let filled = ((score.percentage / 100.0) * bar_width as f32).round() as usize;
+        let bar = "█".repeat(filled) + &"░".repeat(bar_width - filled);
+
+        let label = format!("{:.0}% match", score.percentage);
+        if score.percentage >= 70.0 {
+            println!("  {} {}", bar.green(), label.green().bold());
+        } else if score.percentage >= 40.0 {
+            println!("  {} {}", bar.yellow(), label.yellow().bold());
+        } else {
+            println!("  {} {}", bar.red(), label.red().bold());
+        }
+
+        self.print_footer();
+    }
+
     // Unified function to display a movie with poster and metadata
     #[allow(clippy::too_many_arguments, clippy::needless_borrow)]
     pub async fn display_movie_with_poster(
@@ -305,24 +855,36 @@ impl DisplayEngine {
         user_rating: Option<f32>,
         review: Option<&String>,
         watched_date: Option<chrono::DateTime<chrono::Utc>>,
-        width: u32,
+        poster_width: u32,
     ) {
-        // Always use viu for image display
+        // Use viu when it's installed, falling back to the pure-Rust ASCII renderer otherwise
         if let Some(ref url) = poster_url {
-            // Check if viu is available
-            if ViuViewer::is_available() {
+            if !self.should_render_posters() {
+                println!("Poster: {}", url.dimmed());
+            } else {
                 self.print_loading_animation("Loading poster...", 300).await;
                 let use_pixelated = self.get_display_mode();
+                let grayscale = self.get_poster_grayscale();
+                let ascii_depth = self.get_ascii_color_depth();
+                let poster_style = self.get_poster_style();
+                let braille_threshold = self.get_braille_threshold();
                 if self
                     .viu_viewer
-                    .display_image_url(&url, width, use_pixelated)
+                    .display_image_url_or_ascii(
+                        &url,
+                        poster_width,
+                        use_pixelated,
+                        grayscale,
+                        ascii_depth,
+                        poster_style,
+                        braille_threshold,
+                    )
                     .await
                     .is_err()
+                    && ViuViewer::should_warn_unavailable()
                 {
-                    self.print_warning("Failed to display image with viu");
+                    self.print_warning(&ViuViewer::get_installation_instructions());
                 }
-            } else {
-                self.print_warning(&ViuViewer::get_installation_instructions());
             }
         }
 
@@ -350,7 +912,10 @@ impl DisplayEngine {
         }
 
         if let Some(date) = watched_date {
-            println!("Watched: {}", date.format("%B %d, %Y").to_string().dimmed());
+            println!(
+                "Watched: {}",
+                crate::util::format_watch_date(&date).dimmed()
+            );
         }
 
         // Display overview
@@ -374,6 +939,81 @@ impl DisplayEngine {
         }
     }
 
+    /// Renders a single diary entry's full detail for `lbxd entry` — the
+    /// precise-lookup counterpart to [`Self::display_movie_with_poster`],
+    /// including the fields (rewatch flag, tags) that only the native
+    /// client's [`crate::profile::UserMovieEntry`] carries.
+    pub async fn show_diary_entry_detail(
+        &self,
+        entry: &crate::profile::UserMovieEntry,
+        poster_width: u32,
+    ) {
+        if let Some(ref url) = entry.movie.poster_url {
+            if !self.should_render_posters() {
+                println!("Poster: {}", url.dimmed());
+            } else {
+                self.print_loading_animation("Loading poster...", 300).await;
+                let use_pixelated = self.get_display_mode();
+                let grayscale = self.get_poster_grayscale();
+                let ascii_depth = self.get_ascii_color_depth();
+                let poster_style = self.get_poster_style();
+                let braille_threshold = self.get_braille_threshold();
+                if self
+                    .viu_viewer
+                    .display_image_url_or_ascii(
+                        url,
+                        poster_width,
+                        use_pixelated,
+                        grayscale,
+                        ascii_depth,
+                        poster_style,
+                        braille_threshold,
+                    )
+                    .await
+                    .is_err()
+                    && ViuViewer::should_warn_unavailable()
+                {
+                    self.print_warning(&ViuViewer::get_installation_instructions());
+                }
+            }
+        }
+
+        println!();
+        let title_line = match entry.movie.year {
+            Some(year) => format!("{} ({})", entry.movie.title, year),
+            None => entry.movie.title.clone(),
+        };
+        println!("{}", title_line.bright_white().bold());
+
+        if let Some(date) = entry.watched_date {
+            let mut watched_line = format!("Watched: {}", crate::util::format_watch_date(&date));
+            if entry.rewatched {
+                watched_line.push_str(&format!(" {}", "(rewatch)".dimmed()));
+            }
+            println!("{}", watched_line);
+        }
+
+        if let Some(rating) = entry.user_rating {
+            println!("Your Rating: {}", self.rating_to_stars(rating));
+        }
+
+        if !entry.tags.is_empty() {
+            println!("Tags: {}", entry.tags.join(", ").cyan());
+        }
+
+        if entry.liked {
+            println!("{}", "♥ Liked".red());
+        }
+
+        if let Some(ref review) = entry.review {
+            println!();
+            println!("{}", "Review:".bright_white());
+            for line in self.wrap_text(review, 80) {
+                println!("  {}", line);
+            }
+        }
+    }
+
     fn wrap_text(&self, text: &str, width: usize) -> Vec<String> {
         let words = text.split_whitespace();
         let mut lines = Vec::new();
@@ -397,7 +1037,7 @@ impl DisplayEngine {
         lines
     }
 
-    async fn display_entry_with_tmdb_lookup(&self, entry: &UserEntry, width: u32) {
+    async fn display_entry_with_tmdb_lookup(&self, entry: &UserEntry, poster_width: u32) {
         // Clean the title for better TMDB search results
         let cleaned_title = self.clean_title_for_search(&entry.movie.title);
 
@@ -419,7 +1059,7 @@ impl DisplayEngine {
                     entry.rating,
                     entry.review.as_ref(),
                     entry.watched_date,
-                    width,
+                    poster_width,
                 )
                 .await;
             }
@@ -442,7 +1082,7 @@ impl DisplayEngine {
                                 entry.rating,
                                 entry.review.as_ref(),
                                 entry.watched_date,
-                                width,
+                                poster_width,
                             )
                             .await;
                         }
@@ -458,7 +1098,7 @@ impl DisplayEngine {
                                 entry.rating,
                                 entry.review.as_ref(),
                                 entry.watched_date,
-                                width,
+                                poster_width,
                             )
                             .await;
                         }
@@ -475,7 +1115,7 @@ impl DisplayEngine {
                         entry.rating,
                         entry.review.as_ref(),
                         entry.watched_date,
-                        width,
+                        poster_width,
                     )
                     .await;
                 }
@@ -492,7 +1132,7 @@ impl DisplayEngine {
                     entry.rating,
                     entry.review.as_ref(),
                     entry.watched_date,
-                    width,
+                    poster_width,
                 )
                 .await;
             }
@@ -500,24 +1140,16 @@ impl DisplayEngine {
     }
 
     fn clean_title_for_search(&self, title: &str) -> String {
-        // Remove common problematic characters and patterns that might interfere with TMDB search
-        let mut cleaned = title.to_string();
-
-        // Remove trailing asterisks (like "Thunderbolts*")
-        cleaned = cleaned.trim_end_matches('*').to_string();
-
-        // Remove extra whitespace and normalize
-        cleaned = cleaned.trim().to_string();
-
-        // Replace multiple spaces with single space
-        let re = Regex::new(r"\s+").unwrap();
-        cleaned = re.replace_all(&cleaned, " ").to_string();
-
-        cleaned
+        crate::util::normalize_title(title)
     }
 
     // Horizontal grid layout with TMDB integration
-    async fn display_entries_horizontal_grid_tmdb(&self, entries: &[&UserEntry], width: u32) {
+    async fn display_entries_horizontal_grid_tmdb(
+        &self,
+        entries: &[&UserEntry],
+        width: u32,
+        poster_width: u32,
+    ) {
         if entries.is_empty() {
             return;
         }
@@ -528,26 +1160,115 @@ impl DisplayEngine {
             80 // fallback width
         };
 
-        // Calculate how many posters can fit horizontally
-        let poster_width = width as usize + 2; // Add padding
-        let posters_per_row = (term_width / poster_width).max(1);
+        // Calculate how many posters can fit horizontally, based on the text
+        // layout width (column spacing), not the poster image width itself.
+        let column_width = width as usize + 2; // Add padding
+        let posters_per_row = (term_width / column_width).max(1);
 
         // Process entries in chunks
         for chunk in entries.chunks(posters_per_row) {
-            self.print_poster_row_tmdb(chunk, width).await;
+            self.print_poster_row_tmdb(chunk, poster_width).await;
             println!(); // Space between rows
         }
     }
 
-    async fn print_poster_row_tmdb(&self, entries: &[&UserEntry], width: u32) {
+    async fn print_poster_row_tmdb(&self, entries: &[&UserEntry], poster_width: u32) {
         // Use viu to display posters side by side if possible
         // For now, display them vertically since viu doesn't support side-by-side easily
         for entry in entries {
-            self.display_entry_with_tmdb_lookup(entry, width).await;
+            self.display_entry_with_tmdb_lookup(entry, poster_width)
+                .await;
             println!();
         }
     }
 
+    /// Prints a user's watchlist. `in_theaters_titles`, when given, is a set
+    /// of normalized titles (via [`crate::util::normalize_title`]) currently
+    /// in theaters; matching films get a "🎟 In theaters now" marker. Films
+    /// with no release data to check simply don't get the marker.
+    /// `seen_titles`, when given, is a set of normalized titles from the
+    /// viewing user's own diary; matching films get a "✓ you've seen this"
+    /// marker.
+    pub fn show_watchlist(
+        &self,
+        movies: &[DetailedMovie],
+        in_theaters_titles: Option<&std::collections::HashSet<String>>,
+        seen_titles: Option<&std::collections::HashSet<String>>,
+    ) {
+        if movies.is_empty() {
+            self.print_warning("Watchlist is empty");
+            return;
+        }
+
+        self.print_header(&format!("🎞 Watchlist ({} films)", movies.len()));
+
+        for movie in movies {
+            print!("{}", movie.title.bright_white().bold());
+            if let Some(year) = movie.year {
+                print!(" ({})", year.to_string().dimmed());
+            }
+
+            let normalized_title = crate::util::normalize_title(&movie.title);
+
+            let in_theaters = in_theaters_titles
+                .map(|titles| titles.contains(&normalized_title))
+                .unwrap_or(false);
+            if in_theaters {
+                print!(" {}", "🎟 In theaters now".green().bold());
+            }
+
+            let seen = seen_titles
+                .map(|titles| titles.contains(&normalized_title))
+                .unwrap_or(false);
+            if seen {
+                print!(" {}", "✓ you've seen this".cyan().bold());
+            }
+
+            println!();
+        }
+
+        self.print_footer();
+    }
+
+    /// Prints every genre seen with its film count, one per line as
+    /// `<count>\t<name>`, sorted by count descending — plain and tab-separated
+    /// so it pipes cleanly into `sort`/`awk`/`cut` rather than a `stats`-style
+    /// decorated table.
+    pub fn show_genre_list(&self, genres: &[GenreStats]) {
+        for genre in genres {
+            println!("{}\t{}", genre.count, genre.name);
+        }
+    }
+
+    /// Prints every director seen with their film count, one per line as
+    /// `<count>\t<name>`, sorted by count descending. See [`Self::show_genre_list`].
+    pub fn show_director_list(&self, directors: &[DirectorStats]) {
+        for director in directors {
+            println!("{}\t{}", director.film_count, director.name);
+        }
+    }
+
+    /// Prints a "Did you mean...?" list of near-miss TMDB matches, shown when
+    /// an exact-title search comes up empty but a broader search finds
+    /// plausible alternatives. See [`crate::tmdb::TMDBClient::search_movies_multi`].
+    pub fn show_tmdb_suggestions(&self, query: &str, suggestions: &[TMDBMovie]) {
+        self.print_warning(&format!("No exact match found for '{}'", query));
+
+        if suggestions.is_empty() {
+            return;
+        }
+
+        self.print_header("Did you mean...?");
+        for movie in suggestions {
+            print!("{}", movie.title.bright_white().bold());
+            if let Some(year) = movie.get_year() {
+                print!(" ({})", year.to_string().dimmed());
+            }
+            println!();
+        }
+        self.print_footer();
+    }
+
     pub fn show_search_results(&self, results: Vec<UserEntry>) {
         if results.is_empty() {
             self.print_warning("No matching movies found");
@@ -567,10 +1288,7 @@ impl DisplayEngine {
             println!();
 
             if let Some(date) = entry.watched_date {
-                println!(
-                    "  Watched: {}",
-                    date.format("%B %d, %Y").to_string().dimmed()
-                );
+                println!("  Watched: {}", crate::util::format_date(&date).dimmed());
             }
 
             if let Some(review) = &entry.review {
@@ -587,7 +1305,7 @@ impl DisplayEngine {
         self.print_footer();
     }
 
-    pub async fn search_with_poster(&self, results: Vec<UserEntry>, width: u32) {
+    pub async fn search_with_poster(&self, results: Vec<UserEntry>, poster_width: u32) {
         if results.is_empty() {
             self.print_warning("No matching movies found");
             return;
@@ -596,10 +1314,55 @@ impl DisplayEngine {
         self.print_header(&format!("🔍 Found {} matches", results.len()));
 
         for result in results.iter() {
-            self.display_entry_with_tmdb_lookup(result, width).await;
+            self.display_entry_with_tmdb_lookup(result, poster_width)
+                .await;
             println!();
         }
 
         self.print_footer();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glyph_counts(rendered: &str) -> (usize, usize, usize) {
+        let full = rendered.matches('★').count();
+        let half = rendered.matches('½').count();
+        let empty = rendered.matches('☆').count();
+        (full, half, empty)
+    }
+
+    #[test]
+    fn rating_to_stars_renders_five_positions_with_a_half_at_half_point_five() {
+        let display = DisplayEngine::new();
+        let (full, half, empty) = glyph_counts(&display.rating_to_stars(0.5));
+        assert_eq!(full + half + empty, 5);
+        assert_eq!((full, half, empty), (0, 1, 4));
+    }
+
+    #[test]
+    fn rating_to_stars_renders_five_positions_with_a_half_at_two_point_five() {
+        let display = DisplayEngine::new();
+        let (full, half, empty) = glyph_counts(&display.rating_to_stars(2.5));
+        assert_eq!(full + half + empty, 5);
+        assert_eq!((full, half, empty), (2, 1, 2));
+    }
+
+    #[test]
+    fn rating_to_stars_renders_five_positions_with_a_half_at_four_point_five() {
+        let display = DisplayEngine::new();
+        let (full, half, empty) = glyph_counts(&display.rating_to_stars(4.5));
+        assert_eq!(full + half + empty, 5);
+        assert_eq!((full, half, empty), (4, 1, 0));
+    }
+
+    #[test]
+    fn rating_to_stars_renders_five_full_stars_with_no_half_at_five_point_zero() {
+        let display = DisplayEngine::new();
+        let (full, half, empty) = glyph_counts(&display.rating_to_stars(5.0));
+        assert_eq!(full + half + empty, 5);
+        assert_eq!((full, half, empty), (5, 0, 0));
+    }
+}
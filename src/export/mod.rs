@@ -1,8 +1,21 @@
 use crate::cli::ExportFormat;
 use crate::models::{UserProfile, ViewingSummary};
+use crate::profile::EnhancedStatistics;
 use anyhow::Result;
 use serde_json;
 use std::fs;
+use std::io::{self, Write};
+
+/// Reviews longer than this (in characters) are wrapped in a collapsible
+/// `<details>` block rather than dumped inline, so one long review doesn't
+/// dominate the rendered markdown. GFM renderers (GitHub, GitLab, etc.)
+/// support raw HTML inside markdown, so this also renders collapsed in HTML
+/// previews of the same file.
+const LONG_REVIEW_THRESHOLD: usize = 500;
+
+/// Width (in characters) of the ASCII bar charts used in the enhanced-stats
+/// report, e.g. for the genre and rating distribution sections.
+const REPORT_BAR_WIDTH: usize = 30;
 
 pub struct ExportManager;
 
@@ -23,10 +36,133 @@ impl ExportManager {
         format: &ExportFormat,
         output_path: &str,
     ) -> Result<()> {
+        // Guard against duplicate diary entries (e.g. from paginated or merged
+        // sources) slipping into the exported file.
+        let mut profile = profile.clone();
+        profile.entries = crate::util::dedupe_user_entries(profile.entries);
+
         match format {
-            ExportFormat::Json => self.export_json(profile, output_path),
-            ExportFormat::Markdown => self.export_markdown(profile, output_path),
+            ExportFormat::Json => self.export_json(&profile, output_path),
+            ExportFormat::Markdown => self.export_markdown(&profile, output_path),
+            ExportFormat::JsonLines => self.export_jsonl(&profile, output_path),
+            ExportFormat::Xml => self.export_xml(&profile, output_path),
+            ExportFormat::HeatmapCsv => self.export_heatmap_csv(&profile, output_path),
+        }
+    }
+
+    /// Writes one `date,count` row per day across the diary's full date
+    /// range (earliest to latest watched date), including zero-count days,
+    /// so the output plots as a continuous timeline in ggplot/matplotlib or
+    /// a GitHub-style calendar heatmap.
+    fn export_heatmap_csv(&self, profile: &UserProfile, output_path: &str) -> Result<()> {
+        use std::collections::BTreeMap;
+
+        let mut counts: BTreeMap<chrono::NaiveDate, u32> = BTreeMap::new();
+        for entry in &profile.entries {
+            if let Some(watched_date) = entry.watched_date {
+                *counts.entry(watched_date.date_naive()).or_insert(0) += 1;
+            }
+        }
+
+        let mut writer = open_writer(output_path)?;
+        writeln!(writer, "date,count")?;
+
+        if let (Some(&first), Some(&last)) = (counts.keys().next(), counts.keys().next_back()) {
+            let mut day = first;
+            while day <= last {
+                let count = counts.get(&day).copied().unwrap_or(0);
+                writeln!(writer, "{},{}", day.format("%Y-%m-%d"), count)?;
+                day = day
+                    .succ_opt()
+                    .expect("date overflow iterating heatmap range");
+            }
+        }
+
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Streams one `UserEntry` per line as a standalone JSON object, rather
+    /// than building the whole profile into one in-memory `String` like
+    /// [`Self::export_json`] does. Keeps memory flat for huge diaries and
+    /// plays nicely with `jq`. Pass `-` as `output_path` to stream to stdout.
+    fn export_jsonl(&self, profile: &UserProfile, output_path: &str) -> Result<()> {
+        let mut writer = open_writer(output_path)?;
+
+        for entry in &profile.entries {
+            serde_json::to_writer(&mut writer, entry)?;
+            writer.write_all(b"\n")?;
+        }
+
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Emits a well-formed XML document mirroring the JSON structure, for
+    /// third-party tools that ingest XML. Uses `quick_xml`'s writer so text
+    /// content (titles, reviews, etc.) is escaped correctly.
+    fn export_xml(&self, profile: &UserProfile, output_path: &str) -> Result<()> {
+        use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
+        use quick_xml::writer::Writer;
+        use std::io::Cursor;
+
+        let mut writer = Writer::new_with_indent(Cursor::new(Vec::new()), b' ', 2);
+
+        let mut profile_start = BytesStart::new("profile");
+        profile_start.push_attribute(("username", profile.username.as_str()));
+        if let Some(ref display_name) = profile.display_name {
+            profile_start.push_attribute(("display_name", display_name.as_str()));
+        }
+        writer.write_event(Event::Start(profile_start))?;
+
+        writer.write_event(Event::Start(BytesStart::new("entries")))?;
+        for entry in &profile.entries {
+            let mut film = BytesStart::new("film");
+            if let Some(year) = entry.movie.year {
+                film.push_attribute(("year", year.to_string().as_str()));
+            }
+            if let Some(rating) = entry.rating {
+                film.push_attribute(("rating", rating.to_string().as_str()));
+            }
+            film.push_attribute(("liked", entry.liked.to_string().as_str()));
+            writer.write_event(Event::Start(film))?;
+
+            writer.write_event(Event::Start(BytesStart::new("title")))?;
+            writer.write_event(Event::Text(BytesText::new(&entry.movie.title)))?;
+            writer.write_event(Event::End(BytesEnd::new("title")))?;
+
+            writer.write_event(Event::Start(BytesStart::new("letterboxd_url")))?;
+            writer.write_event(Event::Text(BytesText::new(&entry.movie.letterboxd_url)))?;
+            writer.write_event(Event::End(BytesEnd::new("letterboxd_url")))?;
+
+            if let Some(ref director) = entry.movie.director {
+                writer.write_event(Event::Start(BytesStart::new("director")))?;
+                writer.write_event(Event::Text(BytesText::new(director)))?;
+                writer.write_event(Event::End(BytesEnd::new("director")))?;
+            }
+
+            if let Some(date) = entry.watched_date {
+                writer.write_event(Event::Start(BytesStart::new("watched_date")))?;
+                writer.write_event(Event::Text(BytesText::new(&date.to_rfc3339())))?;
+                writer.write_event(Event::End(BytesEnd::new("watched_date")))?;
+            }
+
+            if let Some(ref review) = entry.review {
+                writer.write_event(Event::Start(BytesStart::new("review")))?;
+                writer.write_event(Event::Text(BytesText::new(review)))?;
+                writer.write_event(Event::End(BytesEnd::new("review")))?;
+            }
+
+            writer.write_event(Event::End(BytesEnd::new("film")))?;
         }
+        writer.write_event(Event::End(BytesEnd::new("entries")))?;
+
+        writer.write_event(Event::End(BytesEnd::new("profile")))?;
+
+        let xml_body = String::from_utf8(writer.into_inner().into_inner())?;
+        let content = format!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n{}\n", xml_body);
+        fs::write(output_path, content)?;
+        Ok(())
     }
 
     pub fn export_summary(
@@ -36,7 +172,17 @@ impl ExportManager {
         output_path: &str,
     ) -> Result<()> {
         match format {
-            ExportFormat::Json => {
+            // A summary is a single object, not a list of entries, so there's
+            // nothing to stream line-by-line — fall back to plain JSON.
+            // A summary is a single object, not a list of entries, so there's
+            // nothing to stream line-by-line — fall back to plain JSON. The
+            // XML and heatmap-CSV formats are scoped to per-entry profile
+            // exports for now, so they fall back here too rather than
+            // inventing an untested shape.
+            ExportFormat::Json
+            | ExportFormat::JsonLines
+            | ExportFormat::Xml
+            | ExportFormat::HeatmapCsv => {
                 let content = serde_json::to_string_pretty(summary)?;
                 fs::write(output_path, content)?;
                 Ok(())
@@ -45,6 +191,196 @@ impl ExportManager {
         }
     }
 
+    /// Writes a standalone report of a user's full `EnhancedStatistics` —
+    /// genre breakdown, director stats, yearly breakdown, rating
+    /// distribution and viewing patterns — distinct from [`Self::export_profile`],
+    /// which exports the diary itself. `Markdown` renders a year-in-review
+    /// style report with ASCII bar charts; other formats fall back to a
+    /// plain JSON dump of the computed stats.
+    pub fn export_enhanced_stats(
+        &self,
+        username: &str,
+        stats: &EnhancedStatistics,
+        format: &ExportFormat,
+        output_path: &str,
+    ) -> Result<()> {
+        match format {
+            ExportFormat::Markdown => {
+                self.export_enhanced_stats_markdown(username, stats, output_path)
+            }
+            ExportFormat::Json
+            | ExportFormat::JsonLines
+            | ExportFormat::Xml
+            | ExportFormat::HeatmapCsv => {
+                let content = serde_json::to_string_pretty(stats)?;
+                fs::write(output_path, content)?;
+                Ok(())
+            }
+        }
+    }
+
+    fn export_enhanced_stats_markdown(
+        &self,
+        username: &str,
+        stats: &EnhancedStatistics,
+        output_path: &str,
+    ) -> Result<()> {
+        let mut content = String::new();
+
+        content.push_str(&format!("# {} - Year in Review\n\n", username));
+
+        let total_films: u32 = stats.yearly_breakdown.iter().map(|y| y.film_count).sum();
+
+        content.push_str("## Overview\n\n");
+        content.push_str(&format!("- **Total films:** {}\n", total_films));
+        content.push_str(&format!(
+            "- **Average rating:** {:.2}/5\n",
+            stats.basic_stats.average_rating
+        ));
+        content.push_str(&format!(
+            "- **Unique directors:** {}\n",
+            stats.basic_stats.unique_directors_count
+        ));
+        content.push_str(&format!(
+            "- **Unique genres:** {}\n",
+            stats.basic_stats.unique_genres_count
+        ));
+        content.push_str(&format!(
+            "- **Unique countries:** {}\n",
+            stats.basic_stats.unique_countries_count
+        ));
+        if let (Some(longest), Some(shortest)) = (&stats.longest_film, &stats.shortest_film) {
+            content.push_str(&format!(
+                "- **Longest film:** {} ({})\n",
+                longest.title,
+                crate::util::format_runtime_minutes(longest.runtime_minutes)
+            ));
+            content.push_str(&format!(
+                "- **Shortest film:** {} ({})\n",
+                shortest.title,
+                crate::util::format_runtime_minutes(shortest.runtime_minutes)
+            ));
+            content.push_str(&format!(
+                "  (of {} films with a known runtime)\n",
+                stats.runtime_sample_size
+            ));
+        }
+        if let Some(contrarianness) = stats.average_contrarianness {
+            content.push_str(&format!(
+                "- **Average contrarianness:** {:+.2} (personal rating vs. Letterboxd average)\n",
+                contrarianness
+            ));
+        }
+        content.push('\n');
+
+        if let Some(ref comparison) = stats.community_comparison {
+            content.push_str("## You vs. the Average Letterboxd User\n\n");
+            content.push_str(&format!(
+                "- **Rating:** {:+.1}★ vs. the community average\n",
+                comparison.rating_diff
+            ));
+            content.push_str(&format!(
+                "- **Films per year:** {:+.0} vs. the community average\n",
+                comparison.films_per_year_diff
+            ));
+            content.push_str(&format!(
+                "- **Top genre:** more into {} than most users ({:.0}% of the community)\n",
+                comparison.top_genre,
+                comparison.top_genre_community_share * 100.0
+            ));
+            content.push_str(&format!("\n_{}_\n\n", comparison.note));
+        }
+
+        if !stats.genre_breakdown.is_empty() {
+            content.push_str("## Genre Breakdown\n\n```\n");
+            for genre in &stats.genre_breakdown {
+                content.push_str(&format!(
+                    "{:<20} {} {:>5.1}% ({} films, avg {:.1}★)\n",
+                    genre.name,
+                    Self::ascii_bar(genre.percentage),
+                    genre.percentage,
+                    genre.count,
+                    genre.average_rating
+                ));
+            }
+            content.push_str("```\n\n");
+        }
+
+        if !stats.director_stats.is_empty() {
+            content.push_str("## Top Directors\n\n");
+            content.push_str("| Director | Films | Avg Rating | Favorite Film |\n");
+            content.push_str("|---|---|---|---|\n");
+            for director in stats.director_stats.iter().take(15) {
+                content.push_str(&format!(
+                    "| {} | {} | {:.1}★ | {} |\n",
+                    director.name,
+                    director.film_count,
+                    director.average_rating,
+                    director.favorite_film.as_deref().unwrap_or("-")
+                ));
+            }
+            content.push('\n');
+        }
+
+        if !stats.yearly_breakdown.is_empty() {
+            content.push_str("## Yearly Breakdown\n\n");
+            content.push_str("| Year | Films | Avg Rating | Top Genre | Rewatches |\n");
+            content.push_str("|---|---|---|---|---|\n");
+            for year in &stats.yearly_breakdown {
+                content.push_str(&format!(
+                    "| {} | {} | {:.1}★ | {} | {} |\n",
+                    year.year,
+                    year.film_count,
+                    year.average_rating,
+                    year.top_genre.as_deref().unwrap_or("-"),
+                    year.rewatch_count
+                ));
+            }
+            content.push('\n');
+        }
+
+        if !stats.rating_distribution.is_empty() {
+            content.push_str("## Rating Distribution\n\n```\n");
+            for bucket in &stats.rating_distribution {
+                content.push_str(&format!(
+                    "{:>3.1}★ {} {:>5.1}% ({})\n",
+                    bucket.rating,
+                    Self::ascii_bar(bucket.percentage),
+                    bucket.percentage,
+                    bucket.count
+                ));
+            }
+            content.push_str("```\n\n");
+        }
+
+        if !stats.viewing_patterns.is_empty() {
+            content.push_str("## Viewing Patterns by Month\n\n");
+            content.push_str("| Month | Films Watched | Busiest Day |\n");
+            content.push_str("|---|---|---|\n");
+            for pattern in &stats.viewing_patterns {
+                content.push_str(&format!(
+                    "| {} | {} | {} |\n",
+                    pattern.month,
+                    pattern.films_watched,
+                    pattern.busiest_day.as_deref().unwrap_or("-")
+                ));
+            }
+            content.push('\n');
+        }
+
+        fs::write(output_path, content)?;
+        Ok(())
+    }
+
+    /// Renders a percentage (0-100) as a fixed-width ASCII bar for the
+    /// enhanced-stats report, which is a plain text/markdown file and so
+    /// can't use the terminal-only block characters with ANSI color codes.
+    fn ascii_bar(percentage: f32) -> String {
+        let filled = ((percentage / 100.0) * REPORT_BAR_WIDTH as f32).round() as usize;
+        let filled = filled.min(REPORT_BAR_WIDTH);
+        "#".repeat(filled) + &"-".repeat(REPORT_BAR_WIDTH - filled)
+    }
+
     fn export_json(&self, profile: &UserProfile, output_path: &str) -> Result<()> {
         let content = serde_json::to_string_pretty(profile)?;
         fs::write(output_path, content)?;
@@ -85,11 +421,16 @@ impl ExportManager {
             }
 
             if let Some(review) = &entry.review {
-                content.push_str(&format!("**Review:**\n{}\n\n", review));
+                content.push_str("**Review:**\n");
+                content.push_str(&Self::format_review_markdown(review));
+                content.push_str("\n\n");
             }
 
             if let Some(date) = entry.watched_date {
-                content.push_str(&format!("**Date:** {}\n\n", date.format("%B %d, %Y")));
+                content.push_str(&format!(
+                    "**Date:** {}\n\n",
+                    crate::util::format_date(&date)
+                ));
             }
 
             content.push_str(&format!(
@@ -103,6 +444,19 @@ impl ExportManager {
         Ok(())
     }
 
+    /// Renders a review as plain markdown, or as a collapsed `<details>` block
+    /// when it's longer than [`LONG_REVIEW_THRESHOLD`].
+    fn format_review_markdown(review: &str) -> String {
+        if review.chars().count() <= LONG_REVIEW_THRESHOLD {
+            review.to_string()
+        } else {
+            format!(
+                "<details>\n<summary>Review (click to expand)</summary>\n\n{}\n\n</details>",
+                review
+            )
+        }
+    }
+
     fn export_summary_markdown(&self, summary: &ViewingSummary, output_path: &str) -> Result<()> {
         let mut content = String::new();
 
@@ -120,7 +474,7 @@ impl ExportManager {
         }
 
         content.push_str("\n## Top Rated Movies\n\n");
-        for (i, (movie, rating)) in summary.top_movies.iter().enumerate() {
+        for (i, (movie, rating, review_excerpt)) in summary.top_movies.iter().enumerate() {
             let title_with_year = if let Some(year) = movie.year {
                 format!("{} ({})", movie.title, year)
             } else {
@@ -132,6 +486,9 @@ impl ExportManager {
                 title_with_year,
                 rating
             ));
+            if let Some(excerpt) = review_excerpt {
+                content.push_str(&format!("   > {}\n", excerpt));
+            }
         }
 
         if !summary.favorite_directors.is_empty() {
@@ -145,3 +502,97 @@ impl ExportManager {
         Ok(())
     }
 }
+
+/// Opens a writer for `output_path`, treating `-` as a request to stream to
+/// stdout instead of a file.
+fn open_writer(output_path: &str) -> Result<Box<dyn Write>> {
+    if output_path == "-" {
+        Ok(Box::new(io::stdout()))
+    } else {
+        Ok(Box::new(fs::File::create(output_path)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{EntryType, Movie, UserEntry, UserProfile};
+
+    #[test]
+    fn export_xml_round_trips_fields_needing_escaping() {
+        use quick_xml::events::Event;
+        use quick_xml::reader::Reader;
+
+        let profile = UserProfile {
+            username: "filmfan".to_string(),
+            display_name: None,
+            avatar_url: None,
+            rss_url: "https://letterboxd.com/filmfan/rss/".to_string(),
+            entries: vec![UserEntry {
+                movie: Movie {
+                    title: "Tom & Jerry: \"The Movie\" <Remastered>".to_string(),
+                    year: Some(2021),
+                    director: None,
+                    letterboxd_url: "https://letterboxd.com/film/tom-and-jerry/".to_string(),
+                    poster_url: None,
+                    tmdb_id: None,
+                },
+                rating: Some(3.5),
+                review: Some("Better than I expected & <surprisingly> fun".to_string()),
+                watched_date: None,
+                entry_type: EntryType::Review,
+                liked: true,
+            }],
+        };
+
+        let dir = std::env::temp_dir().join(format!("lbxd_export_xml_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let output_path = dir.join("profile.xml");
+
+        let manager = ExportManager::new();
+        manager
+            .export_xml(&profile, output_path.to_str().unwrap())
+            .unwrap();
+
+        let xml = std::fs::read_to_string(&output_path).unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+
+        let mut reader = Reader::from_str(&xml);
+
+        let mut title = String::new();
+        let mut review = String::new();
+        let mut current_tag = String::new();
+
+        loop {
+            match reader.read_event().unwrap() {
+                Event::Start(e) => {
+                    current_tag = String::from_utf8(e.name().as_ref().to_vec()).unwrap();
+                }
+                Event::Text(t) => {
+                    let text = t.decode().unwrap().into_owned();
+                    match current_tag.as_str() {
+                        "title" => title.push_str(&text),
+                        "review" => review.push_str(&text),
+                        _ => {}
+                    }
+                }
+                Event::GeneralRef(r) => {
+                    let name = r.decode().unwrap();
+                    let resolved = quick_xml::escape::unescape(&format!("&{};", name))
+                        .unwrap()
+                        .into_owned();
+                    match current_tag.as_str() {
+                        "title" => title.push_str(&resolved),
+                        "review" => review.push_str(&resolved),
+                        _ => {}
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        assert_eq!(title.trim(), "Tom & Jerry: \"The Movie\" <Remastered>");
+        assert_eq!(review.trim(), "Better than I expected & <surprisingly> fun");
+    }
+}
@@ -3,8 +3,45 @@ use anyhow::{anyhow, Result};
 use feed_rs::parser;
 use regex::Regex;
 use reqwest;
+use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
+/// Conditional-request headers captured from a previous RSS fetch, stored alongside
+/// the cached `UserProfile` so later refreshes can ask Letterboxd "has this changed?"
+/// instead of re-downloading and re-parsing the whole feed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FeedCacheMeta {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+/// Result of a conditional feed fetch.
+pub enum FeedFetchOutcome {
+    /// The server confirmed (via 304) that the cached profile is still current.
+    NotModified,
+    /// A fresh profile was fetched, along with headers to use for the next conditional
+    /// fetch and, if the request was redirected to a different username (the user
+    /// renamed their account), that new canonical username.
+    Fetched(UserProfile, FeedCacheMeta, Option<String>),
+}
+
+/// Detects a Letterboxd maintenance page or Cloudflare challenge served with
+/// a `200 OK` in place of the expected RSS XML. Without this, such a
+/// response reaches `parser::parse` and fails with an opaque XML-parsing
+/// error that gives the user no indication Letterboxd itself is the problem.
+pub(crate) fn is_challenge_page(content_type: &str, body: &str) -> bool {
+    if content_type.contains("xml") {
+        return false;
+    }
+
+    let lower = body.to_lowercase();
+    lower.contains("cf-browser-verification")
+        || lower.contains("cf-challenge")
+        || lower.contains("checking your browser before accessing")
+        || lower.contains("just a moment...")
+        || (lower.contains("<html") && lower.contains("cloudflare"))
+}
+
 pub struct FeedParser {
     client: reqwest::Client,
 }
@@ -17,9 +54,15 @@ impl Default for FeedParser {
 
 impl FeedParser {
     pub fn new() -> Self {
+        let contact = crate::config::ConfigManager::new()
+            .and_then(|cm| cm.get_rss_contact())
+            .unwrap_or(None)
+            .unwrap_or_else(|| "https://pranavkarra.me".to_string());
+        let user_agent = format!("lbxd/{} ({})", env!("CARGO_PKG_VERSION"), contact);
+
         let client = reqwest::Client::builder()
             .timeout(Duration::from_secs(10))
-            .user_agent("lbxd/1.2.1 (https://pranavkarra.me)")
+            .user_agent(user_agent)
             .build()
             .unwrap_or_default();
 
@@ -27,20 +70,83 @@ impl FeedParser {
     }
 
     pub async fn fetch_user_feed(&self, username: &str) -> Result<UserProfile> {
+        match self.fetch_user_feed_conditional(username, None).await? {
+            FeedFetchOutcome::Fetched(profile, _meta, _renamed_to) => Ok(profile),
+            // Unreachable without conditional headers: the server only returns 304
+            // in response to If-None-Match/If-Modified-Since, which we didn't send.
+            FeedFetchOutcome::NotModified => {
+                Err(anyhow!("Unexpected 304 response for unconditional fetch"))
+            }
+        }
+    }
+
+    /// Fetches a user's RSS feed, sending `If-None-Match`/`If-Modified-Since` when
+    /// `cached_meta` carries headers from a previous fetch. Falls back to a normal
+    /// full fetch when `cached_meta` is `None` or carries no headers. A `304`
+    /// response is surfaced as `FeedFetchOutcome::NotModified` so the caller can
+    /// keep using its existing cached profile without re-parsing anything.
+    pub async fn fetch_user_feed_conditional(
+        &self,
+        username: &str,
+        cached_meta: Option<&FeedCacheMeta>,
+    ) -> Result<FeedFetchOutcome> {
         let rss_url = format!("https://letterboxd.com/{}/rss/", username);
 
-        let response = self
-            .client
-            .get(&rss_url)
-            .header("User-Agent", "lbxd/1.0.0")
-            .send()
-            .await?;
+        let mut request = self.client.get(&rss_url);
+
+        if let Some(meta) = cached_meta {
+            if let Some(ref etag) = meta.etag {
+                request = request.header("If-None-Match", etag);
+            }
+            if let Some(ref last_modified) = meta.last_modified {
+                request = request.header("If-Modified-Since", last_modified);
+            }
+        }
+
+        let response = request.send().await?;
+
+        // If Letterboxd 301-redirected us (the user renamed their account),
+        // reqwest's default redirect policy already followed it — we just need
+        // to notice the final URL landed on a different username than we asked for.
+        let renamed_to = Self::extract_username(response.url().as_str())
+            .filter(|canonical| canonical != username);
+
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            return Ok(FeedFetchOutcome::NotModified);
+        }
 
         if !response.status().is_success() {
             return Err(anyhow!("Failed to fetch RSS feed for user: {}", username));
         }
 
+        let new_meta = FeedCacheMeta {
+            etag: response
+                .headers()
+                .get(reqwest::header::ETAG)
+                .and_then(|v| v.to_str().ok())
+                .map(String::from),
+            last_modified: response
+                .headers()
+                .get(reqwest::header::LAST_MODIFIED)
+                .and_then(|v| v.to_str().ok())
+                .map(String::from),
+        };
+
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("")
+            .to_string();
+
         let content = response.text().await?;
+
+        if is_challenge_page(&content_type, &content) {
+            return Err(anyhow!(
+                "Letterboxd appears to be unavailable or is blocking automated requests. Please try again later."
+            ));
+        }
+
         let feed = parser::parse(content.as_bytes())?;
 
         let mut entries = Vec::new();
@@ -51,24 +157,43 @@ impl FeedParser {
             }
         }
 
-        Ok(UserProfile {
-            username: username.to_string(),
+        let entries = crate::util::dedupe_user_entries(entries);
+
+        let profile = UserProfile {
+            username: renamed_to.clone().unwrap_or_else(|| username.to_string()),
             display_name: feed.title.map(|t| t.content),
             avatar_url: None,
             rss_url,
             entries,
-        })
+        };
+
+        Ok(FeedFetchOutcome::Fetched(profile, new_meta, renamed_to))
+    }
+
+    /// Extracts the username from a Letterboxd RSS URL, e.g.
+    /// `https://letterboxd.com/newname/rss/` -> `Some("newname")`.
+    fn extract_username(url: &str) -> Option<String> {
+        url.strip_prefix("https://letterboxd.com/")
+            .or_else(|| url.strip_prefix("http://letterboxd.com/"))?
+            .split('/')
+            .next()
+            .filter(|s| !s.is_empty())
+            .map(String::from)
     }
 
     async fn parse_entry(&self, item: feed_rs::model::Entry) -> Option<UserEntry> {
         let title = item.title?.content;
         let link = item.links.first()?.href.clone();
+        let description = item.summary.as_ref().map(|s| s.content.as_str());
 
-        let movie = self.extract_movie_info(&title, &link).await?;
-        let entry_type = self.determine_entry_type(&title);
+        let movie = self.extract_movie_info(&title, &link, description).await?;
         let rating = self.extract_rating(&title);
-        let review = item.summary.map(|s| s.content);
+        let review = item
+            .summary
+            .map(|s| crate::util::sanitize_display_text(&s.content));
         let watched_date = item.published.or(item.updated);
+        let liked = title.contains("♥");
+        let entry_type = self.determine_entry_type(review.is_some(), liked);
 
         Some(UserEntry {
             movie,
@@ -76,11 +201,16 @@ impl FeedParser {
             review,
             watched_date,
             entry_type,
-            liked: title.contains("♥"),
+            liked,
         })
     }
 
-    async fn extract_movie_info(&self, title: &str, url: &str) -> Option<Movie> {
+    async fn extract_movie_info(
+        &self,
+        title: &str,
+        url: &str,
+        description: Option<&str>,
+    ) -> Option<Movie> {
         let re = Regex::new(r"(.+?)\s*(\d{4})").ok()?;
 
         let (movie_title, year) = if let Some(caps) = re.captures(title) {
@@ -91,21 +221,51 @@ impl FeedParser {
             (title.to_string(), None)
         };
 
+        let director = description.and_then(Self::extract_director);
+
         // Don't fetch poster URL here - let the display handle TMDB lookup
         Some(Movie {
-            title: movie_title,
+            title: crate::util::sanitize_display_text(&movie_title),
             year,
-            director: None,
+            director,
             letterboxd_url: url.to_string(),
             poster_url: None, // Will be fetched by display layer using TMDB
             tmdb_id: None,
         })
     }
 
-    fn determine_entry_type(&self, title: &str) -> EntryType {
-        if title.contains("★") {
+    /// Opportunistically pulls a director credit out of an item's
+    /// description HTML, e.g. "Directed by Denis Villeneuve.". Letterboxd's
+    /// own diary RSS doesn't normally include this, but some mirrors and
+    /// list feeds do — when it's absent this just returns `None`, same as
+    /// before, so the RSS-based `recent`/`search`/`export` paths don't
+    /// regress for feeds that lack it.
+    fn extract_director(description: &str) -> Option<String> {
+        let plain_text = Self::strip_html_tags(description);
+        let re = Regex::new(r"(?i)directed by[:\s]+([^.\n]+)").ok()?;
+        let captured = re.captures(&plain_text)?.get(1)?.as_str().trim();
+        if captured.is_empty() {
+            return None;
+        }
+        Some(crate::util::sanitize_display_text(captured))
+    }
+
+    /// Strips HTML tags from RSS description content, leaving plain text.
+    fn strip_html_tags(html: &str) -> String {
+        let re = Regex::new(r"<[^>]*>").unwrap();
+        re.replace_all(html, " ").to_string()
+    }
+
+    /// A written review makes an entry a `Review` regardless of whether a
+    /// rating (stars) was also given, since a rating alone doesn't mean a
+    /// review was written — and a reviewed-but-unrated film is a common
+    /// Letterboxd pattern that star-presence alone would miss. `Like` only
+    /// applies when there's no review text; an unrated, unreviewed, liked
+    /// film is otherwise indistinguishable from a plain watch.
+    fn determine_entry_type(&self, has_review: bool, liked: bool) -> EntryType {
+        if has_review {
             EntryType::Review
-        } else if title.contains("♥") {
+        } else if liked {
             EntryType::Like
         } else {
             EntryType::Watch
@@ -123,3 +283,87 @@ impl FeedParser {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn extract_movie_info_captures_director_when_present() {
+        let parser = FeedParser::new();
+        let description = "<p>Watched on Letterboxd. Directed by Denis Villeneuve.</p>";
+
+        let movie = parser
+            .extract_movie_info(
+                "Dune, 2021",
+                "https://letterboxd.com/film/dune-2021/",
+                Some(description),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(movie.title, "Dune,");
+        assert_eq!(movie.year, Some(2021));
+        assert_eq!(movie.director.as_deref(), Some("Denis Villeneuve"));
+    }
+
+    #[tokio::test]
+    async fn extract_movie_info_omits_director_when_absent() {
+        let parser = FeedParser::new();
+        let description = "<p>Watched on Letterboxd.</p>";
+
+        let movie = parser
+            .extract_movie_info(
+                "Dune, 2021",
+                "https://letterboxd.com/film/dune-2021/",
+                Some(description),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(movie.director, None);
+    }
+
+    #[tokio::test]
+    async fn extract_movie_info_omits_director_when_no_description() {
+        let parser = FeedParser::new();
+
+        let movie = parser
+            .extract_movie_info("Dune, 2021", "https://letterboxd.com/film/dune-2021/", None)
+            .await
+            .unwrap();
+
+        assert_eq!(movie.director, None);
+    }
+
+    #[test]
+    fn determine_entry_type_reviewed_unrated_is_a_review() {
+        let parser = FeedParser::new();
+        // A review with no rating stars in the title and not liked.
+        assert!(matches!(
+            parser.determine_entry_type(true, false),
+            EntryType::Review
+        ));
+    }
+
+    #[test]
+    fn determine_entry_type_rated_no_review_is_a_plain_watch() {
+        let parser = FeedParser::new();
+        // A star rating alone (no review text, not liked) doesn't make an
+        // entry a "Review" — only written review text does.
+        assert!(parser.extract_rating("The Matrix ★★★★").is_some());
+        assert!(matches!(
+            parser.determine_entry_type(false, false),
+            EntryType::Watch
+        ));
+    }
+
+    #[test]
+    fn determine_entry_type_liked_only_is_a_like() {
+        let parser = FeedParser::new();
+        assert!(matches!(
+            parser.determine_entry_type(false, true),
+            EntryType::Like
+        ));
+    }
+}
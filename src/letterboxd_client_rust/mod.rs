@@ -6,11 +6,34 @@ use tokio::sync::mpsc;
 
 use crate::omdb::OMDBClient;
 use crate::profile::{
-    ComprehensiveProfile, DetailedMovie, DirectorStats, EnhancedStatistics, FavoriteFilm,
-    GenreStats, LoadingProgress, LoadingStage, RatingDistribution, UserMovieEntry, UserStatistics,
-    ViewingPattern, YearlyBreakdown,
+    CommunityComparison, ComprehensiveProfile, DetailedMovie, DirectorStats, EnhancedStatistics,
+    EnrichmentUpdate, FavoriteFilm, GenreStats, LoadingProgress, LoadingStage, RatingDistribution,
+    RuntimeSuperlative, UserMovieEntry, UserStatistics, ViewingPattern, YearlyBreakdown,
 };
 
+/// Rough, hand-maintained Letterboxd community baselines for the "compare to
+/// the average user" stat (see `LetterboxdClient::calculate_community_comparison`).
+/// Letterboxd doesn't expose live aggregate stats via RSS or rustboxd, so
+/// these are approximate figures — update them here if they drift noticeably
+/// from community vibes, but don't treat them as measured data.
+const COMMUNITY_AVERAGE_RATING: f32 = 3.2;
+const COMMUNITY_FILMS_PER_YEAR: f32 = 120.0;
+const COMMUNITY_GENRE_SHARE: &[(&str, f32)] = &[
+    ("Drama", 0.35),
+    ("Comedy", 0.28),
+    ("Action", 0.22),
+    ("Thriller", 0.20),
+    ("Animation", 0.15),
+    ("Science Fiction", 0.15),
+    ("Romance", 0.15),
+    ("Fantasy", 0.12),
+    ("Horror", 0.12),
+    ("Documentary", 0.10),
+];
+/// Fallback share for a genre not present in `COMMUNITY_GENRE_SHARE`, so an
+/// unlisted genre still produces a plausible "more into X than most" claim.
+const COMMUNITY_GENRE_SHARE_DEFAULT: f32 = 0.15;
+
 pub struct LetterboxdClient {}
 
 impl LetterboxdClient {
@@ -18,10 +41,51 @@ impl LetterboxdClient {
         Ok(Self {})
     }
 
+    /// Dedupes, flags rewatches on, and (when `merge_same_day_rewatches` is
+    /// enabled in config) collapses same-film same-day duplicates in a batch
+    /// of converted diary entries. The common tail of every diary-loading
+    /// path, so the three don't drift.
+    fn finalize_diary_movies(&self, movies: Vec<UserMovieEntry>) -> Vec<UserMovieEntry> {
+        let mut movies = crate::util::dedupe_movie_entries(movies);
+        crate::util::mark_rewatches(&mut movies);
+
+        let merge_enabled = crate::config::ConfigManager::new()
+            .and_then(|cm| cm.get_merge_same_day_rewatches())
+            .unwrap_or(true);
+        if merge_enabled {
+            movies = crate::util::merge_same_day_rewatches(movies);
+        }
+
+        movies
+    }
+
     pub async fn get_comprehensive_profile(
         &self,
         username: &str,
         progress_tx: Option<mpsc::UnboundedSender<LoadingProgress>>,
+    ) -> Result<ComprehensiveProfile> {
+        self.get_comprehensive_profile_with_options(username, progress_tx, false, None, false)
+            .await
+    }
+
+    /// Like [`get_comprehensive_profile`](Self::get_comprehensive_profile), but allows
+    /// skipping the OMDB enrichment pass for a faster, Letterboxd/TMDB-only load, and
+    /// capping how many of the most recent diary entries are kept and have stats
+    /// computed over them. `max_diary_entries` overrides the configured
+    /// `max_diary_entries` default for this call only; `None` falls back to config.
+    /// `verbose` prints a warning to stderr for each diary entry discarded for having a
+    /// future `watched_date`, which would otherwise silently skew streak/pace stats.
+    ///
+    /// Note: `rustboxd::User::get_diary_entries` has no pagination/early-stop support,
+    /// so the whole diary is still fetched over the network — the cap only bounds how
+    /// much of it we keep and process afterwards.
+    pub async fn get_comprehensive_profile_with_options(
+        &self,
+        username: &str,
+        progress_tx: Option<mpsc::UnboundedSender<LoadingProgress>>,
+        skip_enrichment: bool,
+        max_diary_entries: Option<u32>,
+        verbose: bool,
     ) -> Result<ComprehensiveProfile> {
         // Send initial progress
         if let Some(ref tx) = progress_tx {
@@ -71,7 +135,7 @@ impl LetterboxdClient {
 
         // Convert the data to our Rust structures
         let mut comprehensive_profile = self
-            .convert_user_data_to_profile(user, diary_entries, username)
+            .convert_user_data_to_profile(user, diary_entries, username, max_diary_entries, verbose)
             .await?;
 
         // Add watchlist data
@@ -82,17 +146,21 @@ impl LetterboxdClient {
         comprehensive_profile.watchlist_loaded = comprehensive_profile.watchlist.len();
         comprehensive_profile.total_watchlist_available = total_watchlist_available;
 
-        if let Some(ref tx) = progress_tx {
-            let _ = tx.send(LoadingProgress {
-                stage: LoadingStage::Complete,
-                current: 5,
-                total: 5,
-                message: "Enriching with OMDB data...".to_string(),
-            });
-        }
+        let comprehensive_profile = if skip_enrichment {
+            comprehensive_profile
+        } else {
+            if let Some(ref tx) = progress_tx {
+                let _ = tx.send(LoadingProgress {
+                    stage: LoadingStage::Complete,
+                    current: 5,
+                    total: 5,
+                    message: "Enriching with OMDB data...".to_string(),
+                });
+            }
 
-        // Enrich with OMDB data
-        let comprehensive_profile = self.enrich_with_omdb(comprehensive_profile).await?;
+            // Enrich with OMDB data
+            self.enrich_with_omdb(comprehensive_profile).await?
+        };
 
         if let Some(ref tx) = progress_tx {
             let _ = tx.send(LoadingProgress {
@@ -106,11 +174,63 @@ impl LetterboxdClient {
         Ok(comprehensive_profile)
     }
 
+    /// Computes `EnhancedStatistics` scoped to each of two calendar years, for a
+    /// personal year-over-year retrospective (`lbxd stats <user> --compare-years`).
+    /// A year with no diary entries reports zeroed-out statistics rather than erroring.
+    pub async fn get_yearly_stats_comparison(
+        &self,
+        username: &str,
+        year_a: i32,
+        year_b: i32,
+        verbose: bool,
+    ) -> Result<(EnhancedStatistics, EnhancedStatistics)> {
+        let user = User::new(username).await?;
+        let diary_entries = user.get_diary_entries().await?;
+        let all_movies = self.finalize_diary_movies(self.convert_diary_entries(diary_entries)?);
+
+        let movies_for_year = |year: i32| -> Vec<UserMovieEntry> {
+            all_movies
+                .iter()
+                .filter(|m| m.watched_date.is_some_and(|d| d.year() == year))
+                .cloned()
+                .collect()
+        };
+
+        let stats_a = self.calculate_enhanced_stats(&movies_for_year(year_a), verbose)?;
+        let stats_b = self.calculate_enhanced_stats(&movies_for_year(year_b), verbose)?;
+
+        Ok((stats_a, stats_b))
+    }
+
+    /// Like [`Self::get_yearly_stats_comparison`], but scoped to a single
+    /// calendar year, also returning the films watched that year (needed by
+    /// `lbxd wrapped` to pick which posters to feature).
+    pub async fn get_yearly_stats(
+        &self,
+        username: &str,
+        year: i32,
+        verbose: bool,
+    ) -> Result<(EnhancedStatistics, Vec<UserMovieEntry>)> {
+        let user = User::new(username).await?;
+        let diary_entries = user.get_diary_entries().await?;
+        let all_movies = self.finalize_diary_movies(self.convert_diary_entries(diary_entries)?);
+
+        let movies_for_year: Vec<UserMovieEntry> = all_movies
+            .into_iter()
+            .filter(|m| m.watched_date.is_some_and(|d| d.year() == year))
+            .collect();
+
+        let stats = self.calculate_enhanced_stats(&movies_for_year, verbose)?;
+        Ok((stats, movies_for_year))
+    }
+
     async fn convert_user_data_to_profile(
         &self,
         user: User,
         diary_entries: Vec<DiaryMovieEntry>,
         username: &str,
+        max_diary_entries: Option<u32>,
+        verbose: bool,
     ) -> Result<ComprehensiveProfile> {
         // Extract basic profile information
         let display_name = user.display_name.clone();
@@ -125,16 +245,45 @@ impl LetterboxdClient {
         // Extract favorites
         let favorite_films = self.extract_favorites(&user)?;
 
-        // Convert diary entries to UserMovieEntry
-        let all_movies = self.convert_diary_entries(diary_entries)?;
+        // Convert diary entries to UserMovieEntry, then dedupe by canonical
+        // letterboxd_url in case the same film appeared twice across diary pages.
+        let mut all_movies = self.finalize_diary_movies(self.convert_diary_entries(diary_entries)?);
         let total_movies_available = all_movies.len();
-        let recent_activity = all_movies.iter().take(10).cloned().collect();
+
+        // `rustboxd` has no way to stop the diary fetch early, so the cap only bounds
+        // how many of the most recent entries we keep and compute stats over.
+        let diary_cap = max_diary_entries
+            .or_else(|| {
+                crate::config::ConfigManager::new()
+                    .and_then(|cm| cm.get_max_diary_entries())
+                    .unwrap_or(None)
+            })
+            .filter(|&cap| cap > 0 && (cap as usize) < total_movies_available);
+
+        if let Some(cap) = diary_cap {
+            all_movies.sort_by_key(|m| std::cmp::Reverse(m.watched_date));
+            all_movies.truncate(cap as usize);
+        }
+
+        let recent_activity_count = crate::config::ConfigManager::new()
+            .and_then(|cm| cm.get_recent_activity_count())
+            .unwrap_or(10) as usize;
+
+        let mut recent_candidates: Vec<&UserMovieEntry> = all_movies.iter().collect();
+        recent_candidates.sort_by_key(|m| std::cmp::Reverse(m.watched_date));
+        let recent_activity = recent_candidates
+            .into_iter()
+            .take(recent_activity_count)
+            .cloned()
+            .collect();
 
         // No lists support for now
         let lists = Vec::new();
 
         // Calculate enhanced statistics from the movie data
-        let enhanced_stats = self.calculate_enhanced_stats(&all_movies)?;
+        let mut enhanced_stats = self.calculate_enhanced_stats(&all_movies, verbose)?;
+        enhanced_stats.capped_at = diary_cap;
+        let movies_loaded = all_movies.len();
 
         Ok(ComprehensiveProfile {
             name: display_name,
@@ -156,7 +305,7 @@ impl LetterboxdClient {
             member_since: None,
             enhanced_stats: Some(enhanced_stats),
             // Pagination fields
-            movies_loaded: 10.min(total_movies_available),
+            movies_loaded,
             total_movies_available,
             watchlist_loaded: 0, // Will be updated when watchlist is loaded
             total_watchlist_available: 0, // Will be updated when watchlist is loaded
@@ -191,8 +340,11 @@ impl LetterboxdClient {
         let mut movies = Vec::new();
 
         for entry in diary_entries {
+            let description = entry
+                .description
+                .map(|d| crate::util::sanitize_display_text(&d));
             let movie = DetailedMovie {
-                title: entry.title.clone(),
+                title: crate::util::sanitize_display_text(&entry.title),
                 year: entry.year,
                 director: entry.director,
                 genres: entry.genres,
@@ -201,7 +353,7 @@ impl LetterboxdClient {
                 letterboxd_url: format!("https://letterboxd.com/film/{}", entry.slug),
                 tmdb_url: None,
                 cast: Vec::new(),
-                synopsis: entry.description.clone(),
+                synopsis: description.clone(),
                 letterboxd_rating: entry.rating,
                 // OMDB fields - will be filled later
                 imdb_rating: None,
@@ -209,7 +361,7 @@ impl LetterboxdClient {
                 metacritic_rating: None,
                 imdb_id: None,
                 release_date: None,
-                plot: entry.description,
+                plot: description,
                 awards: None,
             };
 
@@ -227,6 +379,7 @@ impl LetterboxdClient {
                 liked: false,
                 rewatched: false,
                 tags: Vec::new(),
+                same_day_rewatch_count: 1,
             });
         }
 
@@ -239,10 +392,16 @@ impl LetterboxdClient {
     ) -> Result<Vec<DetailedMovie>> {
         let mut movies = Vec::new();
 
+        // rustboxd hands back a HashMap, whose iteration order is arbitrary and
+        // varies run to run; sort by slug first so the 10 entries we keep (and
+        // the order they're shown in) are stable across loads.
+        let mut watchlist_vec: Vec<_> = watchlist.into_iter().collect();
+        watchlist_vec.sort_by(|(slug_a, _), (slug_b, _)| slug_a.cmp(slug_b));
+
         // Limit to first 10 entries for performance
-        for (_slug, movie_data) in watchlist.into_iter().take(10) {
+        for (_slug, movie_data) in watchlist_vec.into_iter().take(10) {
             let movie = DetailedMovie {
-                title: movie_data.name.clone(),
+                title: crate::util::sanitize_display_text(&movie_data.name),
                 year: None,         // Will be filled by OMDB
                 director: None,     // Will be filled by OMDB
                 genres: Vec::new(), // Will be filled by OMDB
@@ -269,7 +428,41 @@ impl LetterboxdClient {
         Ok(movies)
     }
 
-    fn calculate_enhanced_stats(&self, movies: &[UserMovieEntry]) -> Result<EnhancedStatistics> {
+    /// Collapses logged TV episodes into their parent series before stats are
+    /// computed, per the user's `tv_aggregation` setting. The diary only ever
+    /// contains films today (no TV-series ingestion exists yet), so this is a
+    /// no-op pass-through until episode entries are modeled.
+    fn apply_tv_aggregation(
+        &self,
+        movies: Vec<UserMovieEntry>,
+        _mode: crate::config::TvAggregationMode,
+    ) -> Vec<UserMovieEntry> {
+        movies
+    }
+
+    /// Exposes [`Self::calculate_enhanced_stats`] to `benches/stats.rs`, which lives
+    /// in a separate crate and so can't reach a private method. Not meant for
+    /// general use outside the benchmark harness.
+    #[doc(hidden)]
+    pub fn calculate_enhanced_stats_for_bench(
+        &self,
+        movies: &[UserMovieEntry],
+        verbose: bool,
+    ) -> Result<EnhancedStatistics> {
+        self.calculate_enhanced_stats(movies, verbose)
+    }
+
+    fn calculate_enhanced_stats(
+        &self,
+        movies: &[UserMovieEntry],
+        verbose: bool,
+    ) -> Result<EnhancedStatistics> {
+        let tv_aggregation = crate::config::ConfigManager::new()
+            .and_then(|cm| cm.get_tv_aggregation())
+            .unwrap_or_default();
+        let movies = self.apply_tv_aggregation(movies.to_vec(), tv_aggregation);
+        let movies = &movies[..];
+
         let total_films = movies.len() as u32;
 
         // Calculate basic statistics
@@ -295,10 +488,10 @@ impl LetterboxdClient {
         };
 
         // Genre analysis
-        let genre_breakdown = self.calculate_real_genre_stats(movies);
+        let genre_breakdown = self.calculate_real_genre_stats(movies, Some(10));
 
         // Director analysis
-        let director_stats = self.calculate_real_director_stats(movies);
+        let director_stats = self.calculate_real_director_stats(movies, Some(10));
 
         // Rating distribution
         let rating_distribution = self.calculate_rating_distribution(&ratings);
@@ -309,6 +502,57 @@ impl LetterboxdClient {
         // Viewing patterns
         let viewing_patterns = self.calculate_viewing_patterns(movies);
 
+        // Pace: average watches per week and a naive year-end projection
+        let (average_watches_per_week, projected_year_end_total) =
+            self.calculate_pace_projection(movies, verbose);
+
+        // Longest/shortest film superlatives, over whichever films happen to
+        // have a known runtime (post-OMDB-enrichment, so often a subset).
+        let runtimes: Vec<(&str, u16)> = movies
+            .iter()
+            .filter_map(|m| m.movie.runtime.map(|r| (m.movie.title.as_str(), r)))
+            .collect();
+        let runtime_sample_size = runtimes.len() as u32;
+        let longest_film =
+            runtimes
+                .iter()
+                .max_by_key(|(_, runtime)| *runtime)
+                .map(|(title, runtime)| RuntimeSuperlative {
+                    title: title.to_string(),
+                    runtime_minutes: *runtime,
+                });
+        let shortest_film =
+            runtimes
+                .iter()
+                .min_by_key(|(_, runtime)| *runtime)
+                .map(|(title, runtime)| RuntimeSuperlative {
+                    title: title.to_string(),
+                    runtime_minutes: *runtime,
+                });
+
+        // Average personal-rating-vs-community-average delta, over films
+        // where both are known.
+        let deltas: Vec<f32> = movies
+            .iter()
+            .filter_map(|m| match (m.user_rating, m.movie.letterboxd_rating) {
+                (Some(user_rating), Some(letterboxd_rating)) => {
+                    Some(user_rating - letterboxd_rating)
+                }
+                _ => None,
+            })
+            .collect();
+        let average_contrarianness = if deltas.is_empty() {
+            None
+        } else {
+            Some(deltas.iter().sum::<f32>() / deltas.len() as f32)
+        };
+
+        let community_comparison = Self::calculate_community_comparison(
+            average_rating,
+            &yearly_breakdown,
+            &genre_breakdown,
+        );
+
         Ok(EnhancedStatistics {
             basic_stats: UserStatistics {
                 total_viewing_time_hours,
@@ -322,6 +566,8 @@ impl LetterboxdClient {
                 average_rating,
                 most_watched_year: yearly_breakdown.first().map(|y| y.year),
                 most_watched_decade: None,
+                average_watches_per_week,
+                projected_year_end_total,
             },
             genre_breakdown,
             country_breakdown: Vec::new(),
@@ -330,14 +576,147 @@ impl LetterboxdClient {
             rating_distribution,
             viewing_patterns,
             data_source: "rustboxd".to_string(),
+            capped_at: None,
+            longest_film,
+            shortest_film,
+            runtime_sample_size,
+            average_contrarianness,
+            community_comparison,
+        })
+    }
+
+    /// Computes the playful "you vs. the average Letterboxd user" comparison
+    /// against the hardcoded `COMMUNITY_*` baselines above. Labeled
+    /// approximate wherever it's shown, since there's no live aggregate
+    /// data source for it. Returns `None` if there's no top genre to anchor
+    /// the comparison on (an empty diary).
+    fn calculate_community_comparison(
+        average_rating: f32,
+        yearly_breakdown: &[YearlyBreakdown],
+        genre_breakdown: &[GenreStats],
+    ) -> Option<CommunityComparison> {
+        let top_genre = genre_breakdown.first()?;
+
+        let films_per_year = if yearly_breakdown.is_empty() {
+            0.0
+        } else {
+            yearly_breakdown
+                .iter()
+                .map(|y| y.film_count as f32)
+                .sum::<f32>()
+                / yearly_breakdown.len() as f32
+        };
+
+        let top_genre_community_share = COMMUNITY_GENRE_SHARE
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(&top_genre.name))
+            .map(|(_, share)| *share)
+            .unwrap_or(COMMUNITY_GENRE_SHARE_DEFAULT);
+
+        Some(CommunityComparison {
+            rating_diff: average_rating - COMMUNITY_AVERAGE_RATING,
+            films_per_year_diff: films_per_year - COMMUNITY_FILMS_PER_YEAR,
+            top_genre: top_genre.name.clone(),
+            top_genre_community_share,
+            note: "Approximate — based on rough, hand-maintained community baselines, not live Letterboxd data.".to_string(),
         })
     }
 
-    fn calculate_real_genre_stats(&self, movies: &[UserMovieEntry]) -> Vec<GenreStats> {
+    /// Computes the average films watched per week over the diary's date span, and
+    /// projects a year-end total at that pace for films watched in the current year.
+    /// Returns `(None, None)` for sparse diaries (fewer than two dated entries), since
+    /// a rate requires a span to divide across.
+    ///
+    /// Entries with a `watched_date` after "now" are excluded — a parsing bug or bad
+    /// diary data can otherwise produce a future date that sorts to the top and skews
+    /// the pace/projection math. With `verbose`, each excluded entry is reported to
+    /// stderr.
+    fn calculate_pace_projection(
+        &self,
+        movies: &[UserMovieEntry],
+        verbose: bool,
+    ) -> (Option<f32>, Option<u32>) {
+        let now = chrono::Utc::now();
+
+        let mut dates: Vec<chrono::DateTime<chrono::Utc>> = movies
+            .iter()
+            .filter_map(|m| m.watched_date)
+            .filter(|d| {
+                let is_future = *d > now;
+                if is_future && verbose {
+                    eprintln!(
+                        "Warning: ignoring diary entry with future watched_date {} (pace/streak math excludes it)",
+                        d.format("%Y-%m-%d")
+                    );
+                }
+                !is_future
+            })
+            .collect();
+        dates.sort();
+
+        if dates.len() < 2 {
+            return (None, None);
+        }
+
+        let span_days = (*dates.last().unwrap() - *dates.first().unwrap()).num_days();
+        if span_days <= 0 {
+            return (None, None);
+        }
+
+        let average_watches_per_week = dates.len() as f32 / (span_days as f32 / 7.0);
+
+        let current_year = chrono::Utc::now().year();
+        let this_year_dates: Vec<_> = dates.iter().filter(|d| d.year() == current_year).collect();
+
+        let projected_year_end_total = if this_year_dates.len() >= 2 {
+            let year_start = **this_year_dates.first().unwrap();
+            let elapsed_days = (chrono::Utc::now() - year_start).num_days().max(1);
+            let pace_per_day = this_year_dates.len() as f64 / elapsed_days as f64;
+            Some((pace_per_day * 365.0).round() as u32)
+        } else {
+            None
+        };
+
+        (Some(average_watches_per_week), projected_year_end_total)
+    }
+
+    /// Lists every genre seen across `movies` with its film count, sorted by
+    /// count descending, optionally filtered to genres with at least
+    /// `min_count` films. Unlike `EnhancedStatistics::genre_breakdown`, this
+    /// isn't truncated to the top 10 — it's meant for "show me everything"
+    /// discovery use cases like `stats --list-genres`.
+    pub fn list_genre_stats(&self, movies: &[UserMovieEntry], min_count: u32) -> Vec<GenreStats> {
+        self.calculate_real_genre_stats(movies, None)
+            .into_iter()
+            .filter(|g| g.count >= min_count)
+            .collect()
+    }
+
+    /// Lists every director seen across `movies` with their film count, sorted
+    /// by count descending, optionally filtered to directors with at least
+    /// `min_count` films. See [`Self::list_genre_stats`] for why this isn't
+    /// the same as `EnhancedStatistics::director_stats`.
+    pub fn list_director_stats(
+        &self,
+        movies: &[UserMovieEntry],
+        min_count: u32,
+    ) -> Vec<DirectorStats> {
+        self.calculate_real_director_stats(movies, None)
+            .into_iter()
+            .filter(|d| d.film_count >= min_count)
+            .collect()
+    }
+
+    fn calculate_real_genre_stats(
+        &self,
+        movies: &[UserMovieEntry],
+        limit: Option<usize>,
+    ) -> Vec<GenreStats> {
         use std::collections::HashMap;
 
         let mut genre_counts = HashMap::new();
         let mut genre_ratings = HashMap::new();
+        let mut genre_top_film: HashMap<String, (f32, String)> = HashMap::new();
 
         for movie in movies {
             for genre in &movie.movie.genres {
@@ -347,6 +726,13 @@ impl LetterboxdClient {
                         .entry(genre.clone())
                         .or_insert(Vec::new())
                         .push(rating);
+
+                    let top = genre_top_film
+                        .entry(genre.clone())
+                        .or_insert((rating, movie.movie.title.clone()));
+                    if rating > top.0 {
+                        *top = (rating, movie.movie.title.clone());
+                    }
                 }
             }
         }
@@ -365,6 +751,7 @@ impl LetterboxdClient {
                 };
 
                 let emoji = self.get_genre_emoji(&name);
+                let top_film = genre_top_film.get(&name).map(|(_, title)| title.clone());
 
                 GenreStats {
                     name,
@@ -372,57 +759,82 @@ impl LetterboxdClient {
                     percentage,
                     average_rating,
                     emoji,
+                    top_film,
                 }
             })
             .collect();
 
         genre_stats.sort_by(|a, b| b.count.cmp(&a.count));
-        genre_stats.truncate(10);
+        if let Some(limit) = limit {
+            genre_stats.truncate(limit);
+        }
         genre_stats
     }
 
-    fn calculate_real_director_stats(&self, movies: &[UserMovieEntry]) -> Vec<DirectorStats> {
+    fn calculate_real_director_stats(
+        &self,
+        movies: &[UserMovieEntry],
+        limit: Option<usize>,
+    ) -> Vec<DirectorStats> {
         use std::collections::HashMap;
 
-        let mut director_data: HashMap<String, (u32, Vec<f32>, Vec<String>)> = HashMap::new();
+        // Running rating sum/count plus the best-rated title seen so far, instead of
+        // collecting every title into a per-director `Vec<String>`: with a 5,000+
+        // film diary this avoided one `String` clone per movie that was only ever
+        // used to look up a single favorite film at the end.
+        struct DirectorAgg {
+            film_count: u32,
+            rating_sum: f32,
+            rating_count: u32,
+            best_rated: Option<(f32, String)>,
+            first_title: Option<String>,
+        }
+
+        let mut director_data: HashMap<String, DirectorAgg> = HashMap::new();
 
         for movie in movies {
             if let Some(ref director) = movie.movie.director {
-                let entry =
-                    director_data
-                        .entry(director.clone())
-                        .or_insert((0, Vec::new(), Vec::new()));
-                entry.0 += 1;
+                let entry = director_data
+                    .entry(director.clone())
+                    .or_insert(DirectorAgg {
+                        film_count: 0,
+                        rating_sum: 0.0,
+                        rating_count: 0,
+                        best_rated: None,
+                        first_title: None,
+                    });
+                entry.film_count += 1;
+                if entry.first_title.is_none() {
+                    entry.first_title = Some(movie.movie.title.clone());
+                }
                 if let Some(rating) = movie.user_rating {
-                    entry.1.push(rating);
+                    entry.rating_sum += rating;
+                    entry.rating_count += 1;
+                    let is_better = match &entry.best_rated {
+                        Some((best, _)) => rating > *best,
+                        None => true,
+                    };
+                    if is_better {
+                        entry.best_rated = Some((rating, movie.movie.title.clone()));
+                    }
                 }
-                entry.2.push(movie.movie.title.clone());
             }
         }
 
         let mut director_stats: Vec<DirectorStats> = director_data
             .into_iter()
-            .map(|(name, (film_count, ratings, titles))| {
-                let average_rating = if !ratings.is_empty() {
-                    ratings.iter().sum::<f32>() / ratings.len() as f32
+            .map(|(name, agg)| {
+                let average_rating = if agg.rating_count > 0 {
+                    agg.rating_sum / agg.rating_count as f32
                 } else {
                     0.0
                 };
 
-                let favorite_film = if !ratings.is_empty() {
-                    let max_rating = ratings.iter().fold(0.0f32, |a, &b| a.max(b));
-                    titles
-                        .iter()
-                        .zip(ratings.iter())
-                        .find(|(_, &rating)| rating == max_rating)
-                        .map(|(title, _)| title.clone())
-                } else {
-                    titles.first().cloned()
-                };
+                let favorite_film = agg.best_rated.map(|(_, title)| title).or(agg.first_title);
 
                 DirectorStats {
                     name,
-                    film_count,
+                    film_count: agg.film_count,
                     average_rating,
                     favorite_film,
                 }
@@ -430,7 +842,9 @@ impl LetterboxdClient {
             .collect();
 
         director_stats.sort_by(|a, b| b.film_count.cmp(&a.film_count));
-        director_stats.truncate(10);
+        if let Some(limit) = limit {
+            director_stats.truncate(limit);
+        }
         director_stats
     }
 
@@ -462,52 +876,61 @@ impl LetterboxdClient {
         use chrono::Datelike;
         use std::collections::HashMap;
 
-        let mut yearly_data: HashMap<u16, (u32, u32, Vec<f32>, Vec<String>)> = HashMap::new();
+        // (film_count, total_runtime, ratings, titles, rewatch_count)
+        type YearlyAccumulator = (u32, u32, Vec<f32>, Vec<String>, u32);
+        let mut yearly_data: HashMap<u16, YearlyAccumulator> = HashMap::new();
 
         for movie in movies {
             if let Some(date) = movie.watched_date {
                 let watch_year = date.year() as u16;
-                let entry = yearly_data
-                    .entry(watch_year)
-                    .or_insert((0, 0, Vec::new(), Vec::new()));
+                let entry =
+                    yearly_data
+                        .entry(watch_year)
+                        .or_insert((0, 0, Vec::new(), Vec::new(), 0));
                 entry.0 += 1;
                 entry.1 += movie.movie.runtime.unwrap_or(0) as u32;
                 if let Some(rating) = movie.user_rating {
                     entry.2.push(rating);
                 }
                 entry.3.push(movie.movie.title.clone());
+                if movie.rewatched {
+                    entry.4 += 1;
+                }
             }
         }
 
         let mut yearly_breakdown: Vec<YearlyBreakdown> = yearly_data
             .into_iter()
-            .map(|(year, (film_count, total_runtime, ratings, titles))| {
-                let average_rating = if !ratings.is_empty() {
-                    ratings.iter().sum::<f32>() / ratings.len() as f32
-                } else {
-                    0.0
-                };
-
-                let favorite_film = if !ratings.is_empty() {
-                    let max_rating = ratings.iter().fold(0.0f32, |a, &b| a.max(b));
-                    titles
-                        .iter()
-                        .zip(ratings.iter())
-                        .find(|(_, &rating)| rating == max_rating)
-                        .map(|(title, _)| title.clone())
-                } else {
-                    titles.first().cloned()
-                };
-
-                YearlyBreakdown {
-                    year,
-                    film_count,
-                    total_runtime,
-                    average_rating,
-                    top_genre: None,
-                    favorite_film,
-                }
-            })
+            .map(
+                |(year, (film_count, total_runtime, ratings, titles, rewatch_count))| {
+                    let average_rating = if !ratings.is_empty() {
+                        ratings.iter().sum::<f32>() / ratings.len() as f32
+                    } else {
+                        0.0
+                    };
+
+                    let favorite_film = if !ratings.is_empty() {
+                        let max_rating = ratings.iter().fold(0.0f32, |a, &b| a.max(b));
+                        titles
+                            .iter()
+                            .zip(ratings.iter())
+                            .find(|(_, &rating)| rating == max_rating)
+                            .map(|(title, _)| title.clone())
+                    } else {
+                        titles.first().cloned()
+                    };
+
+                    YearlyBreakdown {
+                        year,
+                        film_count,
+                        total_runtime,
+                        average_rating,
+                        top_genre: None,
+                        favorite_film,
+                        rewatch_count,
+                    }
+                },
+            )
             .collect();
 
         yearly_breakdown.sort_by(|a, b| b.year.cmp(&a.year));
@@ -572,8 +995,9 @@ impl LetterboxdClient {
 
         // Enrich recent activity movies (limit to 10 to avoid rate limits)
         for entry in profile.recent_activity.iter_mut().take(10) {
+            let search_title = crate::util::normalize_title(&entry.movie.title);
             if let Ok(Some(omdb_movie)) = omdb_client
-                .get_movie_by_title(&entry.movie.title, entry.movie.year)
+                .get_movie_by_title(&search_title, entry.movie.year)
                 .await
             {
                 entry.movie.imdb_rating = omdb_client.get_imdb_rating(&omdb_movie);
@@ -581,9 +1005,9 @@ impl LetterboxdClient {
                     omdb_client.get_rotten_tomatoes_rating(&omdb_movie);
                 entry.movie.metacritic_rating = omdb_client.get_metacritic_rating(&omdb_movie);
                 entry.movie.imdb_id = omdb_movie.imdb_id.clone();
-                entry.movie.release_date = omdb_movie.released.clone();
-                entry.movie.plot = omdb_movie.plot.clone();
-                entry.movie.awards = omdb_movie.awards.clone();
+                entry.movie.release_date = omdb_client.get_release_date(&omdb_movie);
+                entry.movie.plot = omdb_client.get_plot(&omdb_movie);
+                entry.movie.awards = omdb_client.get_awards(&omdb_movie);
             }
 
             // Small delay to respect rate limits
@@ -596,25 +1020,18 @@ impl LetterboxdClient {
                 .get_movie_by_title(&movie.title, movie.year)
                 .await
             {
-                movie.year = omdb_movie.year.parse().ok();
-                movie.director = omdb_movie.director.clone();
-                movie.runtime = omdb_movie
-                    .runtime
-                    .as_ref()
-                    .and_then(|r| r.trim_end_matches(" min").parse().ok());
-                movie.genres = omdb_movie
-                    .genre
-                    .as_ref()
-                    .map(|g| g.split(", ").map(String::from).collect())
-                    .unwrap_or_default();
+                movie.year = omdb_client.get_year(&omdb_movie);
+                movie.director = omdb_client.get_director(&omdb_movie);
+                movie.runtime = omdb_client.get_runtime_minutes(&omdb_movie);
+                movie.genres = omdb_client.get_genres(&omdb_movie);
                 movie.imdb_rating = omdb_client.get_imdb_rating(&omdb_movie);
                 movie.rotten_tomatoes_rating = omdb_client.get_rotten_tomatoes_rating(&omdb_movie);
                 movie.metacritic_rating = omdb_client.get_metacritic_rating(&omdb_movie);
                 movie.imdb_id = omdb_movie.imdb_id.clone();
-                movie.release_date = omdb_movie.released.clone();
-                movie.plot = omdb_movie.plot.clone();
-                movie.awards = omdb_movie.awards.clone();
-                movie.synopsis = omdb_movie.plot.clone();
+                movie.release_date = omdb_client.get_release_date(&omdb_movie);
+                movie.plot = omdb_client.get_plot(&omdb_movie);
+                movie.awards = omdb_client.get_awards(&omdb_movie);
+                movie.synopsis = omdb_client.get_plot(&omdb_movie);
             }
 
             // Small delay to respect rate limits
@@ -624,6 +1041,59 @@ impl LetterboxdClient {
         Ok(profile)
     }
 
+    /// Fetches OMDB data for `movies` that don't already have it (i.e. weren't
+    /// among the first 10 the initial `enrich_with_omdb` pass covers) and
+    /// streams each result back over `update_tx` as it arrives, so the TUI can
+    /// patch `MovieGrid` row by row instead of waiting for the whole diary to
+    /// finish.
+    ///
+    /// Calls are made one at a time with the same rate-limit delay
+    /// `enrich_with_omdb` uses — OMDB is a single shared, rate-limited API, so
+    /// this background pass stays bounded to that one in-flight request
+    /// rather than racing ahead of it. The channel decouples this from the
+    /// render loop: a dropped receiver (the user quit) just ends the task
+    /// early via `send`'s `Err`.
+    pub async fn enrich_movies_in_background(
+        &self,
+        movies: Vec<UserMovieEntry>,
+        update_tx: mpsc::UnboundedSender<EnrichmentUpdate>,
+    ) {
+        let omdb_client = OMDBClient::new();
+
+        for entry in movies {
+            if entry.movie.imdb_rating.is_some() {
+                continue;
+            }
+
+            let search_title = crate::util::normalize_title(&entry.movie.title);
+            if let Ok(Some(omdb_movie)) = omdb_client
+                .get_movie_by_title(&search_title, entry.movie.year)
+                .await
+            {
+                let mut movie = entry.movie.clone();
+                movie.imdb_rating = omdb_client.get_imdb_rating(&omdb_movie);
+                movie.rotten_tomatoes_rating = omdb_client.get_rotten_tomatoes_rating(&omdb_movie);
+                movie.metacritic_rating = omdb_client.get_metacritic_rating(&omdb_movie);
+                movie.imdb_id = omdb_movie.imdb_id.clone();
+                movie.release_date = omdb_client.get_release_date(&omdb_movie);
+                movie.plot = omdb_client.get_plot(&omdb_movie);
+                movie.awards = omdb_client.get_awards(&omdb_movie);
+
+                if update_tx
+                    .send(EnrichmentUpdate {
+                        letterboxd_url: entry.movie.letterboxd_url.clone(),
+                        movie,
+                    })
+                    .is_err()
+                {
+                    return;
+                }
+            }
+
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+    }
+
     pub async fn load_more_movies(
         &self,
         username: &str,
@@ -657,9 +1127,9 @@ impl LetterboxdClient {
                     omdb_client.get_rotten_tomatoes_rating(&omdb_movie);
                 entry.movie.metacritic_rating = omdb_client.get_metacritic_rating(&omdb_movie);
                 entry.movie.imdb_id = omdb_movie.imdb_id.clone();
-                entry.movie.release_date = omdb_movie.released.clone();
-                entry.movie.plot = omdb_movie.plot.clone();
-                entry.movie.awards = omdb_movie.awards.clone();
+                entry.movie.release_date = omdb_client.get_release_date(&omdb_movie);
+                entry.movie.plot = omdb_client.get_plot(&omdb_movie);
+                entry.movie.awards = omdb_client.get_awards(&omdb_movie);
             }
 
             // Small delay to respect rate limits
@@ -679,7 +1149,11 @@ impl LetterboxdClient {
         let user = User::new(username).await?;
         let watchlist_data = user.get_watchlist_movies().await?;
 
-        let watchlist_vec: Vec<_> = watchlist_data.into_iter().collect();
+        // rustboxd hands back a HashMap, whose iteration order is arbitrary and
+        // varies run to run. Sort by slug so offset/limit paging is stable and
+        // `watchlist-sort-by added` has a deterministic order to start from.
+        let mut watchlist_vec: Vec<_> = watchlist_data.into_iter().collect();
+        watchlist_vec.sort_by(|(slug_a, _), (slug_b, _)| slug_a.cmp(slug_b));
         if offset >= watchlist_vec.len() {
             return Ok(Vec::new());
         }
@@ -687,7 +1161,7 @@ impl LetterboxdClient {
         let mut movies = Vec::new();
         for (_slug, movie_data) in watchlist_vec.into_iter().skip(offset).take(limit) {
             let movie = crate::profile::DetailedMovie {
-                title: movie_data.name.clone(),
+                title: crate::util::sanitize_display_text(&movie_data.name),
                 year: None,
                 director: None,
                 genres: Vec::new(),
@@ -718,25 +1192,18 @@ impl LetterboxdClient {
                 .get_movie_by_title(&movie.title, movie.year)
                 .await
             {
-                movie.year = omdb_movie.year.parse().ok();
-                movie.director = omdb_movie.director.clone();
-                movie.runtime = omdb_movie
-                    .runtime
-                    .as_ref()
-                    .and_then(|r| r.trim_end_matches(" min").parse().ok());
-                movie.genres = omdb_movie
-                    .genre
-                    .as_ref()
-                    .map(|g| g.split(", ").map(String::from).collect())
-                    .unwrap_or_default();
+                movie.year = omdb_client.get_year(&omdb_movie);
+                movie.director = omdb_client.get_director(&omdb_movie);
+                movie.runtime = omdb_client.get_runtime_minutes(&omdb_movie);
+                movie.genres = omdb_client.get_genres(&omdb_movie);
                 movie.imdb_rating = omdb_client.get_imdb_rating(&omdb_movie);
                 movie.rotten_tomatoes_rating = omdb_client.get_rotten_tomatoes_rating(&omdb_movie);
                 movie.metacritic_rating = omdb_client.get_metacritic_rating(&omdb_movie);
                 movie.imdb_id = omdb_movie.imdb_id.clone();
-                movie.release_date = omdb_movie.released.clone();
-                movie.plot = omdb_movie.plot.clone();
-                movie.awards = omdb_movie.awards.clone();
-                movie.synopsis = omdb_movie.plot.clone();
+                movie.release_date = omdb_client.get_release_date(&omdb_movie);
+                movie.plot = omdb_client.get_plot(&omdb_movie);
+                movie.awards = omdb_client.get_awards(&omdb_movie);
+                movie.synopsis = omdb_client.get_plot(&omdb_movie);
             }
 
             // Small delay to respect rate limits
@@ -745,4 +1212,160 @@ impl LetterboxdClient {
 
         Ok(movies)
     }
+
+    /// Fetches any public Letterboxd list by its full URL, e.g.
+    /// `https://letterboxd.com/user/list/best-of-2023/`.
+    ///
+    /// Note: rustboxd's list parser only scrapes the first page of results, so
+    /// lists with more films than fit on one page are returned truncated —
+    /// there is no pagination support to fetch further pages yet.
+    pub async fn get_list_by_url(&self, url: &str) -> Result<crate::profile::UserList> {
+        let list = rustboxd::List::from_url(url).await.map_err(|e| match e {
+            rustboxd::Error::PrivateRoute => {
+                anyhow::anyhow!("This list is private and can't be viewed")
+            }
+            rustboxd::Error::PageLoad { message, .. } if message.contains("not found") => {
+                anyhow::anyhow!("This list doesn't exist or has been deleted")
+            }
+            other => anyhow::anyhow!("Failed to fetch list: {}", other),
+        })?;
+
+        let movies = list
+            .films
+            .into_iter()
+            .map(|film| crate::profile::DetailedMovie {
+                title: crate::util::sanitize_display_text(&film.title),
+                year: film.year.map(|y| y as u16),
+                director: film.director,
+                genres: Vec::new(),
+                runtime: None,
+                poster_url: film.poster,
+                letterboxd_url: film.url,
+                tmdb_url: None,
+                cast: Vec::new(),
+                synopsis: None,
+                letterboxd_rating: None,
+                imdb_rating: None,
+                rotten_tomatoes_rating: None,
+                metacritic_rating: None,
+                imdb_id: None,
+                release_date: None,
+                plot: None,
+                awards: None,
+            })
+            .collect();
+
+        Ok(crate::profile::UserList {
+            name: crate::util::sanitize_display_text(&list.title),
+            description: list
+                .description
+                .map(|d| crate::util::sanitize_display_text(&d)),
+            url: list.url,
+            movies,
+            is_public: true,
+            created_date: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile::DetailedMovie;
+
+    fn entry(title: &str, watched_date: chrono::DateTime<chrono::Utc>) -> UserMovieEntry {
+        UserMovieEntry {
+            movie: DetailedMovie {
+                title: title.to_string(),
+                year: None,
+                director: None,
+                genres: Vec::new(),
+                runtime: None,
+                poster_url: None,
+                letterboxd_url: format!("https://letterboxd.com/film/{}", title),
+                tmdb_url: None,
+                cast: Vec::new(),
+                synopsis: None,
+                letterboxd_rating: None,
+                imdb_rating: None,
+                rotten_tomatoes_rating: None,
+                metacritic_rating: None,
+                imdb_id: None,
+                release_date: None,
+                plot: None,
+                awards: None,
+            },
+            user_rating: None,
+            review: None,
+            watched_date: Some(watched_date),
+            liked: false,
+            rewatched: false,
+            tags: Vec::new(),
+            same_day_rewatch_count: 1,
+        }
+    }
+
+    fn rated_entry(
+        title: &str,
+        user_rating: Option<f32>,
+        letterboxd_rating: Option<f32>,
+    ) -> UserMovieEntry {
+        let mut movie = entry(title, chrono::Utc::now());
+        movie.user_rating = user_rating;
+        movie.movie.letterboxd_rating = letterboxd_rating;
+        movie
+    }
+
+    #[test]
+    fn average_contrarianness_is_none_when_no_entry_has_both_ratings() {
+        let client = LetterboxdClient::new().unwrap();
+        let movies = vec![
+            rated_entry("No Ratings", None, None),
+            rated_entry("Only User Rating", Some(4.0), None),
+            rated_entry("Only Community Rating", None, Some(3.0)),
+        ];
+
+        let stats = client
+            .calculate_enhanced_stats_for_bench(&movies, false)
+            .unwrap();
+
+        assert!(stats.average_contrarianness.is_none());
+    }
+
+    #[test]
+    fn average_contrarianness_reflects_the_direction_of_the_rating_delta() {
+        let client = LetterboxdClient::new().unwrap();
+
+        let generous = vec![rated_entry("Beloved By Me", Some(5.0), Some(3.0))];
+        let stats = client
+            .calculate_enhanced_stats_for_bench(&generous, false)
+            .unwrap();
+        assert_eq!(stats.average_contrarianness, Some(2.0));
+
+        let critical = vec![rated_entry("Overrated", Some(2.0), Some(4.0))];
+        let stats = client
+            .calculate_enhanced_stats_for_bench(&critical, false)
+            .unwrap();
+        assert_eq!(stats.average_contrarianness, Some(-2.0));
+    }
+
+    #[test]
+    fn calculate_pace_projection_excludes_future_dated_entries() {
+        let client = LetterboxdClient::new().unwrap();
+        let now = chrono::Utc::now();
+
+        let movies = vec![
+            entry("Past Film A", now - chrono::Duration::days(60)),
+            entry("Past Film B", now - chrono::Duration::days(30)),
+            entry("Future Film", now + chrono::Duration::days(10)),
+        ];
+
+        let (with_future, projected_with_future) = client.calculate_pace_projection(&movies, false);
+        let (without_future, projected_without_future) =
+            client.calculate_pace_projection(&movies[..2], false);
+
+        assert_eq!(with_future, without_future);
+        assert_eq!(projected_with_future, projected_without_future);
+        assert!(with_future.is_some());
+    }
 }
@@ -6,21 +6,49 @@
 #![allow(clippy::ptr_arg)]
 #![allow(clippy::needless_borrow)]
 
+pub mod ascii;
 pub mod batch_loader;
 pub mod cache;
 pub mod cli;
+pub mod compatibility;
 pub mod config;
 pub mod display;
 pub mod export;
 pub mod feed;
 pub mod letterboxd_client_rust;
 pub mod models;
+pub mod notify;
 pub mod omdb;
 pub mod onboarding;
 pub mod profile;
+pub mod secrets;
+#[cfg(feature = "server")]
+pub mod server;
 pub mod tmdb;
 pub mod tui;
+pub mod util;
 pub mod viu;
+pub mod wrapped;
+
+/// High-level facade for embedding lbxd as a library: fetches a user's full
+/// Letterboxd profile (diary, watchlist, and enhanced stats) via the native
+/// rustboxd client, enriched with OMDB ratings unless `skip_enrichment` is set.
+///
+/// All modules remain public for advanced use (custom caching, RSS-only
+/// fetches via [`feed::FeedParser`], etc.) — this is just the common path.
+///
+/// Note: unlike the `recent` CLI command, this does not apply lbxd's on-disk
+/// cache; each call hits Letterboxd/OMDB directly. Wrap calls yourself with
+/// [`cache::CacheManager`] if you need that.
+pub async fn fetch_profile(
+    username: &str,
+    skip_enrichment: bool,
+) -> anyhow::Result<profile::ComprehensiveProfile> {
+    let client = letterboxd_client_rust::LetterboxdClient::new()?;
+    client
+        .get_comprehensive_profile_with_options(username, None, skip_enrichment, None, false)
+        .await
+}
 
 #[cfg(test)]
 mod tests {
@@ -50,4 +78,199 @@ mod tests {
         let _display = DisplayEngine::new();
         // Just verify we can create it without issues - test passes if no panic
     }
+
+    #[test]
+    fn test_atomic_write_leaves_old_content_on_failure() {
+        // Simulates a crash mid-write: a bogus parent path makes the rename
+        // fail, and the pre-existing file must be left intact rather than
+        // truncated.
+        use crate::util::atomic_write;
+
+        let dir =
+            std::env::temp_dir().join(format!("lbxd_atomic_write_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("data.json");
+
+        std::fs::write(&path, "original content").unwrap();
+
+        atomic_write(&path, "new content").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "new content");
+
+        // A write into a directory that doesn't exist can't create its temp
+        // file, so it must fail without touching the original file at all.
+        let missing_dir_path = dir.join("missing").join("data.json");
+        assert!(atomic_write(&missing_dir_path, "partial").is_err());
+        assert!(!missing_dir_path.exists());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_merge_same_day_rewatches_collapses_duplicates() {
+        use crate::profile::{DetailedMovie, UserMovieEntry};
+        use crate::util::merge_same_day_rewatches;
+        use chrono::TimeZone;
+
+        fn entry(title: &str, watched_date: chrono::DateTime<chrono::Utc>) -> UserMovieEntry {
+            UserMovieEntry {
+                movie: DetailedMovie {
+                    title: title.to_string(),
+                    year: None,
+                    director: None,
+                    genres: Vec::new(),
+                    runtime: None,
+                    poster_url: None,
+                    letterboxd_url: format!("https://letterboxd.com/film/{}", title),
+                    tmdb_url: None,
+                    cast: Vec::new(),
+                    synopsis: None,
+                    letterboxd_rating: None,
+                    imdb_rating: None,
+                    rotten_tomatoes_rating: None,
+                    metacritic_rating: None,
+                    imdb_id: None,
+                    release_date: None,
+                    plot: None,
+                    awards: None,
+                },
+                user_rating: None,
+                review: None,
+                watched_date: Some(watched_date),
+                liked: false,
+                rewatched: false,
+                tags: Vec::new(),
+                same_day_rewatch_count: 1,
+            }
+        }
+
+        let same_day = chrono::Utc.with_ymd_and_hms(2024, 3, 10, 9, 0, 0).unwrap();
+        let other_day = chrono::Utc.with_ymd_and_hms(2024, 3, 11, 9, 0, 0).unwrap();
+
+        let movies = vec![
+            entry("Paddington 2", same_day),
+            entry("Paddington 2", same_day),
+            entry("Paddington 2", other_day),
+        ];
+
+        let merged = merge_same_day_rewatches(movies);
+
+        assert_eq!(merged.len(), 2);
+        let same_day_entry = merged
+            .iter()
+            .find(|m| m.watched_date == Some(same_day))
+            .unwrap();
+        assert_eq!(same_day_entry.same_day_rewatch_count, 2);
+        let other_day_entry = merged
+            .iter()
+            .find(|m| m.watched_date == Some(other_day))
+            .unwrap();
+        assert_eq!(other_day_entry.same_day_rewatch_count, 1);
+    }
+
+    #[test]
+    fn test_is_challenge_page_detects_cloudflare_challenge() {
+        use crate::feed::is_challenge_page;
+
+        let challenge_html = r#"<!DOCTYPE html>
+<html>
+<head><title>Just a moment...</title></head>
+<body class="cf-browser-verification">
+Checking your browser before accessing letterboxd.com.
+This process is automatic. Your browser will redirect once Cloudflare has finished.
+</body>
+</html>"#;
+
+        assert!(is_challenge_page(
+            "text/html; charset=UTF-8",
+            challenge_html
+        ));
+
+        let real_feed = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0"><channel><title>Letterboxd - Watched films</title></channel></rss>"#;
+
+        assert!(!is_challenge_page(
+            "application/xml; charset=UTF-8",
+            real_feed
+        ));
+    }
+
+    #[test]
+    fn test_load_config_backs_up_corrupt_file() {
+        use crate::config::{Config, ConfigManager};
+
+        let dir =
+            std::env::temp_dir().join(format!("lbxd_corrupt_config_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let config_path = dir.join("config.toml");
+        let legacy_json_path = dir.join("config.json");
+
+        std::fs::write(&config_path, "this is not valid toml {{{").unwrap();
+
+        let manager = ConfigManager::with_paths(config_path.clone(), legacy_json_path);
+        let config = manager.load_config().unwrap();
+
+        assert_eq!(config.username, Config::default().username);
+
+        let backup_path = dir.join("config.toml.bak");
+        assert!(backup_path.exists());
+        assert_eq!(
+            std::fs::read_to_string(&backup_path).unwrap(),
+            "this is not valid toml {{{"
+        );
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_humanize_relative_date_thresholds() {
+        use crate::util::humanize_relative_date;
+        use chrono::Duration;
+
+        let now = chrono::Utc::now();
+
+        assert_eq!(
+            humanize_relative_date(&(now - Duration::seconds(30)), now).as_deref(),
+            Some("just now")
+        );
+        assert_eq!(
+            humanize_relative_date(&(now - Duration::minutes(5)), now).as_deref(),
+            Some("5 minutes ago")
+        );
+        assert_eq!(
+            humanize_relative_date(&(now - Duration::minutes(1)), now).as_deref(),
+            Some("1 minute ago")
+        );
+        assert_eq!(
+            humanize_relative_date(&(now - Duration::hours(3)), now).as_deref(),
+            Some("3 hours ago")
+        );
+        assert_eq!(
+            humanize_relative_date(&(now - Duration::days(1)), now).as_deref(),
+            Some("yesterday")
+        );
+        assert_eq!(
+            humanize_relative_date(&(now - Duration::days(4)), now).as_deref(),
+            Some("4 days ago")
+        );
+        assert_eq!(
+            humanize_relative_date(&(now - Duration::days(14)), now).as_deref(),
+            Some("2 weeks ago")
+        );
+        assert_eq!(
+            humanize_relative_date(&(now - Duration::days(40)), now).as_deref(),
+            Some("last month")
+        );
+        assert_eq!(
+            humanize_relative_date(&(now - Duration::days(90)), now).as_deref(),
+            Some("3 months ago")
+        );
+        assert_eq!(
+            humanize_relative_date(&(now - Duration::days(400)), now),
+            None
+        );
+        assert_eq!(
+            humanize_relative_date(&(now + Duration::days(1)), now),
+            None
+        );
+    }
 }
@@ -4,21 +4,32 @@
 use clap::Parser;
 use lbxd::{
     cache::CacheManager,
-    cli::{Cli, ColorModeArg, Commands, ConfigCommands, DisplayModeArg},
-    config::{ColorMode, ConfigManager, DisplayMode},
+    cli::{
+        ApiServiceArg, AsciiColorDepthArg, Cli, ClientArg, ColorModeArg, Commands, CompareSortArg,
+        ConfigCommands, DateFormatArg, DisplayModeArg, HeadlineStatArg, PosterGrayscaleArg,
+        PosterStyleArg, ThemeArg, TvAggregationArg, WatchlistSortArg,
+    },
+    config::{
+        AsciiColorDepth, ColorMode, ConfigManager, DataClient, DateFormat, DisplayMode,
+        HeadlineStat, PosterGrayscale, PosterStyle, Theme, TvAggregationMode,
+    },
     display::DisplayEngine,
     export::ExportManager,
     feed::FeedParser,
     letterboxd_client_rust::LetterboxdClient,
+    omdb::OMDBClient,
     onboarding::OnboardingManager,
-    tmdb::TMDBClient,
+    tmdb::{TMDBClient, TMDBMovie},
     tui,
 };
 
 #[tokio::main]
 async fn main() {
     let cli = Cli::parse();
-    let display = DisplayEngine::new();
+    let timeout = cli.timeout;
+    let verbose = cli.verbose;
+    let cli_client = cli.client;
+    let display = DisplayEngine::with_force_posters(cli.force_posters);
     let feed_parser = FeedParser::new();
     let export_manager = ExportManager::new();
 
@@ -53,6 +64,40 @@ async fn main() {
         }
     }
 
+    if let Some(theme) = cli.theme {
+        let theme = match theme {
+            ThemeArg::Letterboxd => Theme::Letterboxd,
+            ThemeArg::Solarized => Theme::Solarized,
+            ThemeArg::Mono => Theme::Mono,
+        };
+        if let Err(e) = config_manager.set_theme(theme) {
+            display.print_error(&format!("Failed to set theme: {}", e));
+        }
+    }
+
+    if cli.high_contrast {
+        if let Err(e) = config_manager.set_high_contrast_posters(true) {
+            display.print_error(&format!("Failed to enable high-contrast posters: {}", e));
+        }
+    }
+
+    if let Some(poster_style) = cli.poster_style {
+        let poster_style = match poster_style {
+            PosterStyleArg::Blocks => PosterStyle::Blocks,
+            PosterStyleArg::Braille => PosterStyle::Braille,
+        };
+        if let Err(e) = config_manager.set_poster_style(poster_style) {
+            display.print_error(&format!("Failed to set poster style: {}", e));
+        }
+    }
+
+    let data_client = cli_client
+        .map(|c| match c {
+            ClientArg::Rss => DataClient::Rss,
+            ClientArg::Native => DataClient::Native,
+        })
+        .unwrap_or_else(|| config_manager.get_default_client().unwrap_or_default());
+
     let cache_manager = match CacheManager::new() {
         Ok(cache) => Some(cache),
         Err(_) => {
@@ -78,11 +123,28 @@ async fn main() {
                                 .await;
 
                             match client
-                                .get_comprehensive_profile(&actual_username, None)
+                                .get_comprehensive_profile_with_options(
+                                    &actual_username,
+                                    None,
+                                    false,
+                                    None,
+                                    verbose,
+                                )
                                 .await
                             {
                                 Ok(comprehensive_profile) => {
                                     // Convert to basic profile stats for display
+                                    let (average_watches_per_week, projected_year_end_total) =
+                                        comprehensive_profile
+                                            .enhanced_stats
+                                            .as_ref()
+                                            .map(|s| {
+                                                (
+                                                    s.basic_stats.average_watches_per_week,
+                                                    s.basic_stats.projected_year_end_total,
+                                                )
+                                            })
+                                            .unwrap_or((None, None));
                                     let profile_stats = lbxd::profile::ProfileStats {
                                         name: comprehensive_profile.name,
                                         username: comprehensive_profile.username,
@@ -93,6 +155,8 @@ async fn main() {
                                         following_count: comprehensive_profile.following_count,
                                         followers_count: comprehensive_profile.followers_count,
                                         favorite_films: comprehensive_profile.favorite_films,
+                                        average_watches_per_week,
+                                        projected_year_end_total,
                                     };
                                     display.show_profile_stats(&profile_stats).await;
                                 }
@@ -129,251 +193,1724 @@ async fn main() {
         None => return,
     };
 
-    match command {
-        Commands::Recent {
-            username,
-            limit,
-            date,
-            rated,
-            reviewed,
-            vertical,
-            width,
-        } => {
-            let actual_username = resolve_username(&username, &config_manager, &display).await;
-            if actual_username.is_none() {
-                return;
-            }
-            let actual_username = actual_username.unwrap();
+    let command_future = async {
+        match command {
+            Commands::Recent {
+                username,
+                limit,
+                all,
+                since_last_run,
+                date,
+                max_age,
+                rated,
+                reviewed,
+                vertical,
+                group_by_date,
+                width,
+                poster_width,
+            } => {
+                let mut limit = if all { None } else { limit };
+                let poster_width = poster_width.unwrap_or(width);
+                let actual_username = resolve_username(&username, &config_manager, &display).await;
+                if actual_username.is_none() {
+                    return;
+                }
+                let actual_username = actual_username.unwrap();
 
-            display.print_minimal_logo();
+                let max_age = match max_age.as_deref().map(lbxd::util::parse_duration) {
+                    Some(Ok(duration)) => Some(duration),
+                    Some(Err(e)) => {
+                        display.print_error(&format!("{}", e));
+                        return;
+                    }
+                    None => None,
+                };
 
-            let profile = if let Some(ref cache) = cache_manager {
-                if let Some(cached) = cache.get_cached_profile(&actual_username) {
-                    cached
-                } else {
-                    match feed_parser.fetch_user_feed(&actual_username).await {
-                        Ok(profile) => {
-                            let _ = cache.cache_profile(&profile);
-                            profile
+                display.print_minimal_logo();
+
+                let profile = match data_client {
+                    DataClient::Native => match LetterboxdClient::new() {
+                        Ok(client) => {
+                            display
+                                .print_loading_animation(
+                                    "Fetching complete diary via native client...",
+                                    500,
+                                )
+                                .await;
+                            match client
+                                .get_comprehensive_profile(&actual_username, None)
+                                .await
+                            {
+                                Ok(comprehensive) => {
+                                    comprehensive_profile_to_user_profile(&comprehensive)
+                                }
+                                Err(e) => {
+                                    display
+                                        .print_error(&format!("Failed to fetch user data: {}", e));
+                                    return;
+                                }
+                            }
                         }
                         Err(e) => {
-                            display.print_error(&format!("Failed to fetch user data: {}", e));
+                            display
+                                .print_error(&format!("Failed to initialize native client: {}", e));
                             return;
                         }
+                    },
+                    DataClient::Rss => {
+                        if let Some(ref cache) = cache_manager {
+                            if let Some(cached) =
+                                cache.get_cached_profile_with_max_age(&actual_username, max_age)
+                            {
+                                cached
+                            } else {
+                                let cached_meta = cache.get_feed_meta(&actual_username);
+                                match feed_parser
+                                    .fetch_user_feed_conditional(
+                                        &actual_username,
+                                        cached_meta.as_ref(),
+                                    )
+                                    .await
+                                {
+                                    Ok(lbxd::feed::FeedFetchOutcome::NotModified) => {
+                                        if let Some(stale) =
+                                            cache.get_stale_profile(&actual_username)
+                                        {
+                                            let _ = cache.cache_profile(&stale);
+                                            stale
+                                        } else {
+                                            match feed_parser
+                                                .fetch_user_feed(&actual_username)
+                                                .await
+                                            {
+                                                Ok(profile) => {
+                                                    let _ = cache.cache_profile(&profile);
+                                                    profile
+                                                }
+                                                Err(e) => {
+                                                    display.print_error(&format!(
+                                                        "Failed to fetch user data: {}",
+                                                        e
+                                                    ));
+                                                    return;
+                                                }
+                                            }
+                                        }
+                                    }
+                                    Ok(lbxd::feed::FeedFetchOutcome::Fetched(
+                                        profile,
+                                        new_meta,
+                                        renamed_to,
+                                    )) => {
+                                        if let Some(new_username) = &renamed_to {
+                                            display.print_warning(&format!(
+                                                "@{} is now @{} on Letterboxd",
+                                                actual_username, new_username
+                                            ));
+                                            let _ = cache.invalidate_profile(&actual_username);
+                                        }
+                                        let _ = cache.cache_profile(&profile);
+                                        let _ = cache.save_feed_meta(&profile.username, &new_meta);
+                                        profile
+                                    }
+                                    Err(e) => {
+                                        display.print_error(&format!(
+                                            "Failed to fetch user data: {}",
+                                            e
+                                        ));
+                                        return;
+                                    }
+                                }
+                            }
+                        } else {
+                            match feed_parser.fetch_user_feed(&actual_username).await {
+                                Ok(profile) => profile,
+                                Err(e) => {
+                                    display
+                                        .print_error(&format!("Failed to fetch user data: {}", e));
+                                    return;
+                                }
+                            }
+                        }
+                    }
+                };
+
+                let mut filtered_profile = filter_entries(profile, date, rated, reviewed);
+
+                if since_last_run {
+                    if let Some(ref cache) = cache_manager {
+                        if let Some(cutoff) = cache.get_last_run_timestamp(&actual_username) {
+                            filtered_profile
+                                .entries
+                                .retain(|entry| entry.watched_date.is_some_and(|d| d > cutoff));
+                            limit = None;
+                        }
+                        // No prior run recorded: fall back to the normal limited view.
                     }
                 }
-            } else {
-                match feed_parser.fetch_user_feed(&actual_username).await {
+
+                display
+                    .show_user_activity(
+                        &filtered_profile,
+                        limit,
+                        vertical,
+                        group_by_date,
+                        width,
+                        poster_width,
+                    )
+                    .await;
+
+                if let Some(ref cache) = cache_manager {
+                    let _ = cache.save_last_run_timestamp(&actual_username, chrono::Utc::now());
+                }
+            }
+
+            Commands::Search {
+                username,
+                title,
+                width,
+                poster_width,
+                discover,
+            } => {
+                let poster_width = poster_width.unwrap_or(width);
+                let actual_username = resolve_username(&username, &config_manager, &display).await;
+                if actual_username.is_none() {
+                    return;
+                }
+                let actual_username = actual_username.unwrap();
+
+                display.print_minimal_logo();
+
+                let fetch_result: anyhow::Result<lbxd::models::UserProfile> = match data_client {
+                    DataClient::Native => {
+                        display
+                            .print_loading_animation(
+                                "Fetching complete diary via native client...",
+                                500,
+                            )
+                            .await;
+                        match LetterboxdClient::new() {
+                            Ok(client) => client
+                                .get_comprehensive_profile(&actual_username, None)
+                                .await
+                                .map(|p| comprehensive_profile_to_user_profile(&p)),
+                            Err(e) => Err(e),
+                        }
+                    }
+                    DataClient::Rss => feed_parser.fetch_user_feed(&actual_username).await,
+                };
+
+                match fetch_result {
+                    Ok(profile) => {
+                        let normalized_query = lbxd::util::normalize_title(&title).to_lowercase();
+                        let matching_entries: Vec<_> = profile
+                            .entries
+                            .iter()
+                            .filter(|entry| {
+                                lbxd::util::normalize_title(&entry.movie.title)
+                                    .to_lowercase()
+                                    .contains(&normalized_query)
+                            })
+                            .collect();
+
+                        if matching_entries.is_empty() && discover {
+                            display.print_warning(&format!(
+                                "No diary match for '{}' — searching TMDB instead...",
+                                title
+                            ));
+                            let tmdb_client = TMDBClient::new();
+                            match tmdb_client.search_movie(&title).await {
+                                Ok(Some(movie)) => {
+                                    display.print_warning("(not in your diary)");
+                                    display.show_tmdb_movie(&movie, poster_width).await;
+                                }
+                                Ok(None) => {
+                                    display.print_error(&format!(
+                                        "No movies found matching '{}', on Letterboxd or TMDB",
+                                        title
+                                    ));
+                                }
+                                Err(e) => {
+                                    display.print_error(&format!("Failed to search TMDB: {}", e));
+                                }
+                            }
+                        } else if matching_entries.is_empty() {
+                            display.print_error(&format!("No movies found matching '{}'", title));
+                        } else {
+                            display.print_success(&format!(
+                                "Found {} matching entries:",
+                                matching_entries.len()
+                            ));
+                            for entry in matching_entries {
+                                display
+                                    .show_user_activity(
+                                        &lbxd::models::UserProfile {
+                                            username: actual_username.clone(),
+                                            display_name: profile.display_name.clone(),
+                                            avatar_url: None,
+                                            rss_url: profile.rss_url.clone(),
+                                            entries: vec![entry.clone()],
+                                        },
+                                        None,
+                                        true,
+                                        false,
+                                        width,
+                                        poster_width,
+                                    )
+                                    .await; // Default to vertical for search results
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        display.print_error(&format!("Failed to fetch user data: {}", e));
+                    }
+                }
+            }
+
+            Commands::Entry {
+                username,
+                title,
+                date,
+                width,
+                poster_width,
+            } => {
+                let poster_width = poster_width.unwrap_or(width);
+                let actual_username = resolve_username(&username, &config_manager, &display).await;
+                if actual_username.is_none() {
+                    return;
+                }
+                let actual_username = actual_username.unwrap();
+
+                let target_date = match chrono::NaiveDate::parse_from_str(&date, "%Y-%m-%d") {
+                    Ok(date) => date,
+                    Err(_) => {
+                        display
+                            .print_error(&format!("Invalid date '{}': expected YYYY-MM-DD", date));
+                        return;
+                    }
+                };
+
+                display.print_minimal_logo();
+                display
+                    .print_loading_animation("Fetching complete diary via native client...", 500)
+                    .await;
+
+                let client = match LetterboxdClient::new() {
+                    Ok(client) => client,
+                    Err(e) => {
+                        display.print_error(&format!("Failed to initialize client: {}", e));
+                        return;
+                    }
+                };
+
+                let profile = match client
+                    .get_comprehensive_profile(&actual_username, None)
+                    .await
+                {
                     Ok(profile) => profile,
                     Err(e) => {
                         display.print_error(&format!("Failed to fetch user data: {}", e));
                         return;
                     }
-                }
-            };
+                };
 
-            let filtered_profile = filter_entries(profile, date, rated, reviewed);
-            display
-                .show_user_activity(&filtered_profile, limit, vertical, width)
-                .await;
-        }
+                let normalized_query = lbxd::util::normalize_title(&title).to_lowercase();
+                let mut matches: Vec<&lbxd::profile::UserMovieEntry> = profile
+                    .all_movies
+                    .iter()
+                    .filter(|entry| {
+                        lbxd::util::normalize_title(&entry.movie.title).to_lowercase()
+                            == normalized_query
+                    })
+                    .collect();
 
-        Commands::Search {
-            username,
-            title,
-            width,
-        } => {
-            let actual_username = resolve_username(&username, &config_manager, &display).await;
-            if actual_username.is_none() {
-                return;
+                if matches.is_empty() {
+                    display.print_error(&format!("No diary entries found for '{}'", title));
+                    return;
+                }
+
+                match matches
+                    .iter()
+                    .find(|entry| entry.watched_date.map(|d| d.date_naive()) == Some(target_date))
+                {
+                    Some(entry) => {
+                        display.show_diary_entry_detail(entry, poster_width).await;
+                    }
+                    None => {
+                        matches.sort_by_key(|entry| {
+                            entry
+                                .watched_date
+                                .map(|d| (d.date_naive() - target_date).num_days().abs())
+                                .unwrap_or(i64::MAX)
+                        });
+                        let closest = matches[0];
+                        display.print_warning(&format!(
+                        "No exact entry for '{}' on {} — showing the closest logged watch instead:",
+                        title, date
+                    ));
+                        display.show_diary_entry_detail(closest, poster_width).await;
+                    }
+                }
             }
-            let actual_username = actual_username.unwrap();
 
-            display.print_minimal_logo();
+            Commands::Compare {
+                usernames,
+                stdin,
+                sort_by,
+            } => {
+                let mut usernames = usernames;
+                if stdin {
+                    usernames.extend(read_usernames_from_stdin());
+                }
+                if usernames.len() < 2 {
+                    display.print_error("Compare needs at least 2 usernames (pass them as arguments or via --stdin)");
+                    return;
+                }
 
-            match feed_parser.fetch_user_feed(&actual_username).await {
-                Ok(profile) => {
-                    let matching_entries: Vec<_> = profile
-                        .entries
-                        .iter()
-                        .filter(|entry| {
-                            entry
-                                .movie
-                                .title
-                                .to_lowercase()
-                                .contains(&title.to_lowercase())
-                        })
-                        .collect();
-
-                    if matching_entries.is_empty() {
-                        display.print_error(&format!("No movies found matching '{}'", title));
-                    } else {
-                        display.print_success(&format!(
-                            "Found {} matching entries:",
-                            matching_entries.len()
-                        ));
-                        for entry in matching_entries {
-                            display
-                                .show_user_activity(
-                                    &lbxd::models::UserProfile {
-                                        username: actual_username.clone(),
-                                        display_name: profile.display_name.clone(),
-                                        avatar_url: None,
-                                        rss_url: profile.rss_url.clone(),
-                                        entries: vec![entry.clone()],
-                                    },
-                                    None,
-                                    true,
-                                    width,
-                                )
-                                .await; // Default to vertical for search results
+                display.print_minimal_logo();
+
+                let client = match LetterboxdClient::new() {
+                    Ok(client) => client,
+                    Err(e) => {
+                        display.print_error(&format!("Failed to initialize client: {}", e));
+                        return;
+                    }
+                };
+
+                display
+                    .print_loading_animation("Fetching profiles...", 500)
+                    .await;
+
+                let mut profiles = Vec::with_capacity(usernames.len());
+                for username in &usernames {
+                    match client.get_comprehensive_profile(username, None).await {
+                        Ok(profile) => profiles.push((username.clone(), profile)),
+                        Err(e) => {
+                            display.print_error(&format!(
+                                "Failed to fetch profile for {}: {}",
+                                username, e
+                            ));
                         }
                     }
                 }
-                Err(e) => {
-                    display.print_error(&format!("Failed to fetch user data: {}", e));
+
+                let mut summary_rows: Vec<lbxd::profile::CompareSummaryRow> = profiles
+                    .iter()
+                    .map(|(username, profile)| {
+                        let rated: Vec<f32> = profile
+                            .all_movies
+                            .iter()
+                            .filter_map(|entry| entry.user_rating)
+                            .collect();
+                        let average_rating = if rated.is_empty() {
+                            None
+                        } else {
+                            Some(rated.iter().sum::<f32>() / rated.len() as f32)
+                        };
+                        let review_count = profile
+                            .all_movies
+                            .iter()
+                            .filter(|entry| entry.review.is_some())
+                            .count() as u32;
+
+                        lbxd::profile::CompareSummaryRow {
+                            username: username.clone(),
+                            total_films: profile.total_films,
+                            average_rating,
+                            review_count,
+                        }
+                    })
+                    .collect();
+
+                // Stable sort (ties keep input order); default is input order
+                // (no --sort-by given).
+                if let Some(sort_by) = sort_by {
+                    match sort_by {
+                        CompareSortArg::Films => {
+                            summary_rows.sort_by_key(|row| std::cmp::Reverse(row.total_films));
+                        }
+                        CompareSortArg::Rating => {
+                            summary_rows.sort_by(|a, b| {
+                                b.average_rating
+                                    .unwrap_or(0.0)
+                                    .partial_cmp(&a.average_rating.unwrap_or(0.0))
+                                    .unwrap_or(std::cmp::Ordering::Equal)
+                            });
+                        }
+                        CompareSortArg::Reviews => {
+                            summary_rows.sort_by_key(|row| std::cmp::Reverse(row.review_count));
+                        }
+                    }
                 }
-            }
-        }
 
-        Commands::Compare { usernames: _ } => {
-            display.print_minimal_logo();
-            display.print_error("Compare feature is under development. Check back soon!");
-        }
+                display.show_compare_summary_table(&summary_rows);
 
-        Commands::Export {
-            username,
-            format,
-            output,
-        } => {
-            let actual_username = resolve_username(&username, &config_manager, &display).await;
-            if actual_username.is_none() {
-                return;
+                for i in 0..profiles.len() {
+                    for j in (i + 1)..profiles.len() {
+                        let (username_a, profile_a) = &profiles[i];
+                        let (username_b, profile_b) = &profiles[j];
+                        let score = lbxd::compatibility::compute(profile_a, profile_b);
+                        display.show_compatibility_score(username_a, username_b, score.as_ref());
+                    }
+                }
             }
-            let actual_username = actual_username.unwrap();
 
-            match feed_parser.fetch_user_feed(&actual_username).await {
-                Ok(profile) => match export_manager.export_profile(&profile, &format, &output) {
-                    Ok(_) => display.print_success(&format!("Data exported to {}", output)),
-                    Err(e) => display.print_error(&format!("Export failed: {}", e)),
-                },
-                Err(e) => {
-                    display.print_error(&format!("Failed to fetch user data: {}", e));
+            Commands::Feed {
+                usernames,
+                limit,
+                stdin,
+            } => {
+                let mut usernames = usernames;
+                if stdin {
+                    usernames.extend(read_usernames_from_stdin());
+                }
+                if usernames.is_empty() {
+                    display.print_error(
+                        "Feed needs at least 1 username (pass it as an argument or via --stdin)",
+                    );
+                    return;
+                }
+
+                display.print_minimal_logo();
+
+                display
+                    .print_loading_animation("Fetching activity...", 500)
+                    .await;
+
+                // Fetch all users concurrently, same pattern as `export-batch`; each
+                // user's failure is reported individually rather than aborting the feed.
+                let mut handles = Vec::new();
+                for username in usernames {
+                    handles.push(tokio::spawn(async move {
+                        let feed_parser = FeedParser::new();
+                        let result = feed_parser.fetch_user_feed(&username).await;
+                        (username, result)
+                    }));
                 }
+
+                let mut merged: Vec<(String, lbxd::models::UserEntry)> = Vec::new();
+                for handle in handles {
+                    let (username, result) = match handle.await {
+                        Ok(pair) => pair,
+                        Err(e) => {
+                            display.print_error(&format!("Feed task panicked: {}", e));
+                            continue;
+                        }
+                    };
+
+                    match result {
+                        Ok(profile) => {
+                            merged.extend(
+                                profile
+                                    .entries
+                                    .into_iter()
+                                    .map(|entry| (username.clone(), entry)),
+                            );
+                        }
+                        Err(e) => {
+                            display.print_error(&format!(
+                                "{}: failed to fetch activity: {}",
+                                username, e
+                            ));
+                        }
+                    }
+                }
+
+                merged.sort_by_key(|(_, entry)| std::cmp::Reverse(entry.watched_date));
+                merged.truncate(limit);
+
+                display.show_feed(&merged);
             }
-        }
 
-        Commands::Summary {
-            username: _,
-            year: _,
-        } => {
-            display.print_minimal_logo();
-            display.print_error("Summary feature is under development. Check back soon!");
-        }
+            Commands::Export {
+                username,
+                format,
+                output,
+            } => {
+                let actual_username = resolve_username(&username, &config_manager, &display).await;
+                if actual_username.is_none() {
+                    return;
+                }
+                let actual_username = actual_username.unwrap();
 
-        Commands::Movie { title, width } => {
-            display.print_minimal_logo();
+                match feed_parser.fetch_user_feed(&actual_username).await {
+                    Ok(profile) => {
+                        match export_manager.export_profile(&profile, &format, &output) {
+                            Ok(_) if output == "-" => {}
+                            Ok(_) => display.print_success(&format!("Data exported to {}", output)),
+                            Err(e) => display.print_error(&format!("Export failed: {}", e)),
+                        }
+                    }
+                    Err(e) => {
+                        display.print_error(&format!("Failed to fetch user data: {}", e));
+                    }
+                }
+            }
 
-            let tmdb_client = TMDBClient::new();
-            display
-                .print_loading_animation("Searching TMDB...", 1000)
-                .await;
+            Commands::ExportBatch {
+                usernames,
+                format,
+                output_dir,
+                stdin,
+            } => {
+                let mut usernames = usernames;
+                if stdin {
+                    usernames.extend(read_usernames_from_stdin());
+                }
+                if usernames.is_empty() {
+                    display.print_error("Export-batch needs at least 1 username (pass it as an argument or via --stdin)");
+                    return;
+                }
+
+                display.print_minimal_logo();
 
-            match tmdb_client.search_movie(&title).await {
-                Ok(Some(movie)) => {
-                    display.show_tmdb_movie(&movie, width).await;
+                if let Err(e) = std::fs::create_dir_all(&output_dir) {
+                    display.print_error(&format!("Failed to create output directory: {}", e));
+                    return;
                 }
-                Ok(None) => {
-                    display.print_error(&format!("No movies found for '{}'", title));
+
+                let batch_started = std::time::Instant::now();
+
+                // Fetch all profiles concurrently, same as the per-entry concurrent
+                // fetch pattern used elsewhere (e.g. `BatchLoader`); each user's
+                // failure is reported individually rather than aborting the batch.
+                let mut handles = Vec::new();
+                let batch_size = usernames.len();
+                for username in usernames {
+                    handles.push(tokio::spawn(async move {
+                        let feed_parser = FeedParser::new();
+                        let result = feed_parser.fetch_user_feed(&username).await;
+                        (username, result)
+                    }));
                 }
-                Err(e) => {
-                    display.print_error(&format!("Failed to search TMDB: {}", e));
+
+                let export_manager = ExportManager::new();
+                for handle in handles {
+                    let (username, result) = match handle.await {
+                        Ok(pair) => pair,
+                        Err(e) => {
+                            display.print_error(&format!("Export task panicked: {}", e));
+                            continue;
+                        }
+                    };
+
+                    match result {
+                        Ok(profile) => {
+                            let output_path = format!(
+                                "{}/{}.{}",
+                                output_dir.trim_end_matches('/'),
+                                username,
+                                format.extension()
+                            );
+                            match export_manager.export_profile(&profile, &format, &output_path) {
+                                Ok(_) => display.print_success(&format!(
+                                    "{}: exported to {}",
+                                    username, output_path
+                                )),
+                                Err(e) => display
+                                    .print_error(&format!("{}: export failed: {}", username, e)),
+                            }
+                        }
+                        Err(e) => {
+                            display
+                                .print_error(&format!("{}: failed to fetch data: {}", username, e));
+                        }
+                    }
                 }
+
+                lbxd::notify::notify_completion(
+                    &format!("Finished exporting {} profile(s)", batch_size),
+                    batch_started.elapsed(),
+                );
             }
-        }
 
-        Commands::Config { config_command } => {
-            display.print_minimal_logo();
+            Commands::Stats {
+                username,
+                compare_years,
+                export,
+                export_format,
+                list_genres,
+                list_directors,
+                min_count,
+            } => {
+                let actual_username = resolve_username(&username, &config_manager, &display).await;
+                if actual_username.is_none() {
+                    return;
+                }
+                let actual_username = actual_username.unwrap();
+
+                display.print_minimal_logo();
 
-            match config_command {
-                ConfigCommands::Whoami => match config_manager.get_username() {
-                    Ok(Some(username)) => {
-                        display.print_success(&format!("Current username: {}", username));
+                if list_genres || list_directors {
+                    let client = match LetterboxdClient::new() {
+                        Ok(client) => client,
+                        Err(e) => {
+                            display.print_error(&format!(
+                                "Failed to initialize Letterboxd client: {}",
+                                e
+                            ));
+                            return;
+                        }
+                    };
+
+                    display
+                        .print_loading_animation("Crunching stats...", 800)
+                        .await;
+
+                    match client
+                        .get_comprehensive_profile(&actual_username, None)
+                        .await
+                    {
+                        Ok(profile) => {
+                            if list_genres {
+                                let genres =
+                                    client.list_genre_stats(&profile.all_movies, min_count);
+                                display.show_genre_list(&genres);
+                            }
+                            if list_directors {
+                                let directors =
+                                    client.list_director_stats(&profile.all_movies, min_count);
+                                display.show_director_list(&directors);
+                            }
+                        }
+                        Err(e) => {
+                            display.print_error(&format!("Failed to fetch profile: {}", e));
+                        }
                     }
-                    Ok(None) => {
-                        display.print_warning("No username is currently saved");
+
+                    return;
+                }
+
+                if let Some(output_path) = export {
+                    let client = match LetterboxdClient::new() {
+                        Ok(client) => client,
+                        Err(e) => {
+                            display.print_error(&format!(
+                                "Failed to initialize Letterboxd client: {}",
+                                e
+                            ));
+                            return;
+                        }
+                    };
+
+                    display
+                        .print_loading_animation("Crunching enhanced stats...", 800)
+                        .await;
+
+                    match client
+                        .get_comprehensive_profile(&actual_username, None)
+                        .await
+                    {
+                        Ok(profile) => match profile.enhanced_stats {
+                            Some(stats) => {
+                                match export_manager.export_enhanced_stats(
+                                    &actual_username,
+                                    &stats,
+                                    &export_format,
+                                    &output_path,
+                                ) {
+                                    Ok(_) => display.print_success(&format!(
+                                        "Stats report exported to {}",
+                                        output_path
+                                    )),
+                                    Err(e) => display.print_error(&format!("Export failed: {}", e)),
+                                }
+                            }
+                            None => display
+                                .print_error("No enhanced stats available for this user to export"),
+                        },
+                        Err(e) => {
+                            display.print_error(&format!("Failed to fetch profile: {}", e));
+                        }
+                    }
+
+                    return;
+                }
+
+                if compare_years.len() != 2 {
+                    display.print_error(
+                        "--compare-years YEAR_A YEAR_B is required unless --export is given",
+                    );
+                    return;
+                }
+                let (year_a, year_b) = (compare_years[0], compare_years[1]);
+
+                display
+                    .print_loading_animation("Crunching yearly stats...", 800)
+                    .await;
+
+                match LetterboxdClient::new() {
+                    Ok(client) => {
+                        match client
+                            .get_yearly_stats_comparison(&actual_username, year_a, year_b, verbose)
+                            .await
+                        {
+                            Ok((stats_a, stats_b)) => {
+                                display
+                                    .show_stats_diff(
+                                        &actual_username,
+                                        year_a,
+                                        &stats_a,
+                                        year_b,
+                                        &stats_b,
+                                    )
+                                    .await;
+                            }
+                            Err(e) => {
+                                display.print_error(&format!("Failed to compute stats: {}", e));
+                            }
+                        }
                     }
                     Err(e) => {
-                        display.print_error(&format!("Failed to read config: {}", e));
+                        display
+                            .print_error(&format!("Failed to initialize Letterboxd client: {}", e));
                     }
-                },
-                ConfigCommands::SetUser { username } => {
-                    match config_manager.change_username(username.clone()) {
-                        Ok(_) => {
-                            display.print_success(&format!("Username set to: {}", username));
-                        }
+                }
+            }
+
+            Commands::Summary {
+                username: _,
+                year: _,
+                with_reviews: _,
+            } => {
+                display.print_minimal_logo();
+                display.print_error("Summary feature is under development. Check back soon!");
+            }
+
+            Commands::Watchlist {
+                username,
+                in_theaters,
+                region,
+                mark_seen,
+            } => {
+                display.print_minimal_logo();
+
+                let client = match LetterboxdClient::new() {
+                    Ok(client) => client,
+                    Err(e) => {
+                        display.print_error(&format!("Failed to initialize client: {}", e));
+                        return;
+                    }
+                };
+
+                display
+                    .print_loading_animation(&format!("Fetching {}'s watchlist...", username), 1000)
+                    .await;
+
+                let profile = match client.get_comprehensive_profile(&username, None).await {
+                    Ok(profile) => profile,
+                    Err(e) => {
+                        display.print_error(&format!("Failed to fetch watchlist: {}", e));
+                        return;
+                    }
+                };
+
+                let in_theaters_titles = if in_theaters {
+                    let tmdb_client = TMDBClient::new();
+                    match tmdb_client.get_now_playing(region.as_deref()).await {
+                        Ok(now_playing) => Some(
+                            now_playing
+                                .iter()
+                                .map(|m| lbxd::util::normalize_title(&m.title))
+                                .collect::<std::collections::HashSet<_>>(),
+                        ),
                         Err(e) => {
-                            display.print_error(&format!("Failed to save username: {}", e));
+                            display.print_warning(&format!(
+                                "Failed to fetch now-playing films, skipping --in-theaters: {}",
+                                e
+                            ));
+                            None
                         }
                     }
-                }
-                ConfigCommands::Show => match config_manager.get_all_config() {
-                    Ok(config) => {
-                        display.print_info("Current Configuration:");
-                        println!(
-                            "  Username: {}",
-                            config.username.unwrap_or_else(|| "Not set".to_string())
+                } else {
+                    None
+                };
+
+                let seen_titles = if mark_seen {
+                    match config_manager.get_username() {
+                        Ok(Some(me_username)) => {
+                            match client.get_comprehensive_profile(&me_username, None).await {
+                                Ok(me_profile) => Some(
+                                    me_profile
+                                        .all_movies
+                                        .iter()
+                                        .map(|entry| {
+                                            lbxd::util::normalize_title(&entry.movie.title)
+                                        })
+                                        .collect::<std::collections::HashSet<_>>(),
+                                ),
+                                Err(e) => {
+                                    display.print_warning(&format!(
+                                        "Failed to fetch your diary, skipping --mark-seen: {}",
+                                        e
+                                    ));
+                                    None
+                                }
+                            }
+                        }
+                        Ok(None) => {
+                            display.print_warning(
+                            "No username saved. Run a command with your own username first to enable --mark-seen.",
                         );
-                        println!("  Color mode: {:?}", config.color_mode);
-                        println!("  Display mode: {:?}", config.display_mode);
+                            None
+                        }
+                        Err(_) => {
+                            display
+                                .print_warning("Error reading configuration, skipping --mark-seen");
+                            None
+                        }
                     }
+                } else {
+                    None
+                };
+
+                display.show_watchlist(
+                    &profile.watchlist,
+                    in_theaters_titles.as_ref(),
+                    seen_titles.as_ref(),
+                );
+            }
+
+            Commands::Wrapped {
+                username,
+                year,
+                output,
+                width,
+                height,
+                theme,
+            } => {
+                display.print_minimal_logo();
+
+                let client = match LetterboxdClient::new() {
+                    Ok(client) => client,
                     Err(e) => {
-                        display.print_error(&format!("Failed to read config: {}", e));
+                        display.print_error(&format!("Failed to initialize client: {}", e));
+                        return;
                     }
-                },
-                ConfigCommands::SwitchColor { mode } => {
-                    let color_mode = match mode {
-                        ColorModeArg::Color => ColorMode::Color,
-                        ColorModeArg::Grayscale => ColorMode::Grayscale,
-                    };
-                    match config_manager.set_color_mode(color_mode) {
-                        Ok(_) => {
-                            display.print_success(&format!("Color mode switched to: {:?}", mode));
+                };
+
+                display
+                    .print_loading_animation(
+                        &format!("Building {}'s {} wrapped...", username, year),
+                        1000,
+                    )
+                    .await;
+
+                let (stats, movies) = match client.get_yearly_stats(&username, year, false).await {
+                    Ok(result) => result,
+                    Err(e) => {
+                        display.print_error(&format!("Failed to fetch {}'s stats: {}", year, e));
+                        return;
+                    }
+                };
+
+                let theme = match theme.as_str() {
+                    "light" => lbxd::wrapped::WrappedTheme::Light,
+                    "vibrant" => lbxd::wrapped::WrappedTheme::Vibrant,
+                    _ => lbxd::wrapped::WrappedTheme::Dark,
+                };
+                let config = lbxd::wrapped::WrappedConfig {
+                    width,
+                    height,
+                    theme,
+                };
+
+                let card = match lbxd::wrapped::generate_wrapped_card(
+                    &username, year, &movies, &stats, config,
+                )
+                .await
+                {
+                    Ok(card) => card,
+                    Err(e) => {
+                        display.print_error(&format!("Failed to generate wrapped card: {}", e));
+                        return;
+                    }
+                };
+
+                if let Err(e) = card.save(&output) {
+                    display.print_error(&format!("Failed to save wrapped card: {}", e));
+                    return;
+                }
+
+                display.print_success(&format!("Saved wrapped card to {}", output));
+            }
+
+            Commands::Movie {
+                title,
+                width,
+                poster_width,
+                compare,
+            } => {
+                let poster_width = poster_width.unwrap_or(width);
+                display.print_minimal_logo();
+
+                let tmdb_client = TMDBClient::new();
+                display
+                    .print_loading_animation("Searching TMDB...", 1000)
+                    .await;
+
+                match tmdb_client.search_movie(&title).await {
+                    Ok(Some(movie)) => {
+                        display_tmdb_movie_result(&display, &movie, poster_width, compare).await;
+                    }
+                    Ok(None) => {
+                        // The exact query came up empty. Retry once with a cleaned
+                        // title (strips Letterboxd's trailing rewatch "*", stray
+                        // whitespace) before giving up on finding a single match.
+                        let cleaned_title = lbxd::util::normalize_title(&title);
+                        let retried = if cleaned_title != title {
+                            tmdb_client
+                                .search_movie(&cleaned_title)
+                                .await
+                                .ok()
+                                .flatten()
+                        } else {
+                            None
+                        };
+
+                        match retried {
+                            Some(movie) => {
+                                display_tmdb_movie_result(&display, &movie, poster_width, compare)
+                                    .await;
+                            }
+                            None => {
+                                match tmdb_client.search_movies_multi(&cleaned_title, 5).await {
+                                    Ok(suggestions) => {
+                                        display.show_tmdb_suggestions(&title, &suggestions)
+                                    }
+                                    Err(_) => {
+                                        display.print_error(&format!(
+                                            "No movies found for '{}'",
+                                            title
+                                        ));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        display.print_error(&format!("Failed to search TMDB: {}", e));
+                    }
+                }
+            }
+
+            Commands::Config { config_command } => {
+                display.print_minimal_logo();
+
+                match config_command {
+                    ConfigCommands::Whoami => match config_manager.get_username() {
+                        Ok(Some(username)) => {
+                            display.print_success(&format!("Current username: {}", username));
+                        }
+                        Ok(None) => {
+                            display.print_warning("No username is currently saved");
                         }
                         Err(e) => {
-                            display.print_error(&format!("Failed to update color mode: {}", e));
+                            display.print_error(&format!("Failed to read config: {}", e));
+                        }
+                    },
+                    ConfigCommands::SetUser { username } => {
+                        match config_manager.change_username(username.clone()) {
+                            Ok(_) => {
+                                display.print_success(&format!("Username set to: {}", username));
+                            }
+                            Err(e) => {
+                                display.print_error(&format!("Failed to save username: {}", e));
+                            }
                         }
                     }
-                }
-                ConfigCommands::SetMode { mode } => {
-                    let display_mode = match mode {
-                        DisplayModeArg::Pixelated => DisplayMode::Pixelated,
-                        DisplayModeArg::Full => DisplayMode::FullResolution,
-                    };
-                    match config_manager.set_display_mode(display_mode) {
-                        Ok(_) => {
-                            display.print_success(&format!("Display mode set to: {:?}", mode));
+                    ConfigCommands::Show => match config_manager.get_all_config() {
+                        Ok(config) => {
+                            display.print_info("Current Configuration:");
+                            println!(
+                                "  Username: {}",
+                                config.username.unwrap_or_else(|| "Not set".to_string())
+                            );
+                            println!("  Color mode: {:?}", config.color_mode);
+                            println!("  Poster grayscale: {:?}", config.poster_grayscale);
+                            println!("  Display mode: {:?}", config.display_mode);
+                            println!("  Theme: {:?}", config.theme);
+                            println!(
+                                "  Skip enrichment by default: {}",
+                                config.skip_enrichment_by_default
+                            );
+                            println!("  Recent activity count: {}", config.recent_activity_count);
+                            println!("  High-contrast posters: {}", config.high_contrast_posters);
+                            println!(
+                                "  High-contrast threshold: {}",
+                                config.high_contrast_threshold
+                            );
+                            println!(
+                                "  Max diary entries: {}",
+                                config
+                                    .max_diary_entries
+                                    .map(|n| n.to_string())
+                                    .unwrap_or_else(|| "unlimited".to_string())
+                            );
+                            println!("  Headline stat: {:?}", config.headline_stat);
+                            println!("  Notify on completion: {}", config.notify_on_completion);
+                            println!(
+                                "  Date format: {:?} (pattern: {})",
+                                config.date_format,
+                                config.date_format.strftime_pattern()
+                            );
+                            if config.aliases.is_empty() {
+                                println!("  Aliases: none");
+                            } else {
+                                let mut aliases: Vec<_> = config.aliases.iter().collect();
+                                aliases.sort_by_key(|(name, _)| (*name).clone());
+                                let rendered = aliases
+                                    .iter()
+                                    .map(|(name, username)| format!("{} -> {}", name, username))
+                                    .collect::<Vec<_>>()
+                                    .join(", ");
+                                println!("  Aliases: {}", rendered);
+                            }
+
+                            display.print_info("Resolved Effective Values:");
+                            let (tmdb_key, tmdb_source) = TMDBClient::resolve_api_key();
+                            println!(
+                                "  TMDB API key: {} (source: {})",
+                                mask_api_key(&tmdb_key),
+                                tmdb_source
+                            );
+                            let (omdb_key, omdb_source) = OMDBClient::resolve_api_key();
+                            println!(
+                                "  OMDB API key: {} (source: {})",
+                                mask_api_key(&omdb_key),
+                                omdb_source
+                            );
+                            println!("  Config file: {}", config_manager.config_path().display());
+                            match &cache_manager {
+                                Some(cache) => {
+                                    println!("  Cache directory: {}", cache.cache_dir().display());
+                                }
+                                None => {
+                                    println!("  Cache directory: unavailable (cache disabled)");
+                                }
+                            }
+                        }
+                        Err(e) => {
+                            display.print_error(&format!("Failed to read config: {}", e));
+                        }
+                    },
+                    ConfigCommands::SwitchColor { mode } => {
+                        let color_mode = match mode {
+                            ColorModeArg::Color => ColorMode::Color,
+                            ColorModeArg::Grayscale => ColorMode::Grayscale,
+                        };
+                        match config_manager.set_color_mode(color_mode) {
+                            Ok(_) => {
+                                display
+                                    .print_success(&format!("Color mode switched to: {:?}", mode));
+                            }
+                            Err(e) => {
+                                display.print_error(&format!("Failed to update color mode: {}", e));
+                            }
+                        }
+                    }
+                    ConfigCommands::SetPosterGrayscale { mode } => {
+                        let poster_grayscale = match mode {
+                            PosterGrayscaleArg::Auto => PosterGrayscale::Auto,
+                            PosterGrayscaleArg::On => PosterGrayscale::On,
+                            PosterGrayscaleArg::Off => PosterGrayscale::Off,
+                        };
+                        match config_manager.set_poster_grayscale(poster_grayscale) {
+                            Ok(_) => {
+                                display.print_success(&format!(
+                                    "Poster grayscale mode switched to: {:?}",
+                                    mode
+                                ));
+                            }
+                            Err(e) => {
+                                display.print_error(&format!(
+                                    "Failed to update poster grayscale mode: {}",
+                                    e
+                                ));
+                            }
+                        }
+                    }
+                    ConfigCommands::SetMode { mode } => {
+                        let display_mode = match mode {
+                            DisplayModeArg::Pixelated => DisplayMode::Pixelated,
+                            DisplayModeArg::Full => DisplayMode::FullResolution,
+                        };
+                        match config_manager.set_display_mode(display_mode) {
+                            Ok(_) => {
+                                display.print_success(&format!("Display mode set to: {:?}", mode));
+                            }
+                            Err(e) => {
+                                display
+                                    .print_error(&format!("Failed to update display mode: {}", e));
+                            }
+                        }
+                    }
+                    ConfigCommands::SetApiKey {
+                        service,
+                        key,
+                        keyring,
+                    } => {
+                        let account = match &service {
+                            ApiServiceArg::Tmdb => "tmdb_api_key",
+                            ApiServiceArg::Omdb => "omdb_api_key",
+                        };
+
+                        let result = if keyring {
+                            lbxd::secrets::set_key(account, &key)
+                        } else {
+                            match &service {
+                                ApiServiceArg::Tmdb => config_manager.set_tmdb_api_key(key),
+                                ApiServiceArg::Omdb => config_manager.set_omdb_api_key(key),
+                            }
+                        };
+                        match result {
+                            Ok(_) => {
+                                let where_stored = if keyring { "OS keyring" } else { "config" };
+                                display.print_success(&format!(
+                                    "{:?} API key saved to {}",
+                                    service, where_stored
+                                ));
+                            }
+                            Err(e) => {
+                                display.print_error(&format!("Failed to save API key: {}", e));
+                            }
+                        }
+                    }
+                    ConfigCommands::SetNoEnrich { enabled } => {
+                        match config_manager.set_skip_enrichment_by_default(enabled) {
+                            Ok(_) => {
+                                display.print_success(&format!(
+                                    "Default OMDB enrichment skip set to: {}",
+                                    enabled
+                                ));
+                            }
+                            Err(e) => {
+                                display.print_error(&format!("Failed to update setting: {}", e));
+                            }
+                        }
+                    }
+                    ConfigCommands::SetTvAggregation { mode } => {
+                        let tv_mode = match mode {
+                            TvAggregationArg::PerEpisode => TvAggregationMode::PerEpisode,
+                            TvAggregationArg::PerSeries => TvAggregationMode::PerSeries,
+                        };
+                        match config_manager.set_tv_aggregation(tv_mode) {
+                            Ok(_) => {
+                                display
+                                    .print_success(&format!("TV aggregation set to: {:?}", mode));
+                            }
+                            Err(e) => {
+                                display.print_error(&format!(
+                                    "Failed to update TV aggregation: {}",
+                                    e
+                                ));
+                            }
+                        }
+                    }
+                    ConfigCommands::SetRecentActivityCount { count } => {
+                        match config_manager.set_recent_activity_count(count) {
+                            Ok(_) => {
+                                display.print_success(&format!(
+                                    "Recent activity count set to: {}",
+                                    count
+                                ));
+                            }
+                            Err(e) => {
+                                display.print_error(&format!(
+                                    "Failed to update recent activity count: {}",
+                                    e
+                                ));
+                            }
+                        }
+                    }
+                    ConfigCommands::SetHighContrast { enabled } => {
+                        match config_manager.set_high_contrast_posters(enabled) {
+                            Ok(_) => {
+                                display.print_success(&format!(
+                                    "High-contrast posters set to: {}",
+                                    enabled
+                                ));
+                            }
+                            Err(e) => {
+                                display.print_error(&format!(
+                                    "Failed to update high-contrast posters: {}",
+                                    e
+                                ));
+                            }
+                        }
+                    }
+                    ConfigCommands::SetContrastThreshold { threshold } => {
+                        match config_manager.set_high_contrast_threshold(threshold) {
+                            Ok(_) => {
+                                display.print_success(&format!(
+                                    "High-contrast threshold set to: {}",
+                                    threshold
+                                ));
+                            }
+                            Err(e) => {
+                                display.print_error(&format!(
+                                    "Failed to update high-contrast threshold: {}",
+                                    e
+                                ));
+                            }
+                        }
+                    }
+                    ConfigCommands::SetMaxImageDownloadBytes { max_bytes } => {
+                        match config_manager.set_max_image_download_bytes(max_bytes) {
+                            Ok(_) => {
+                                display.print_success(&format!(
+                                    "Max image download size set to: {} bytes",
+                                    max_bytes
+                                ));
+                            }
+                            Err(e) => {
+                                display.print_error(&format!(
+                                    "Failed to update max image download size: {}",
+                                    e
+                                ));
+                            }
+                        }
+                    }
+                    ConfigCommands::SetMaxDiaryEntries { max } => {
+                        let cap = if max == 0 { None } else { Some(max) };
+                        match config_manager.set_max_diary_entries(cap) {
+                            Ok(_) => match cap {
+                                Some(n) => display
+                                    .print_success(&format!("Max diary entries set to: {}", n)),
+                                None => display.print_success("Max diary entries: unlimited"),
+                            },
+                            Err(e) => {
+                                display.print_error(&format!(
+                                    "Failed to update max diary entries: {}",
+                                    e
+                                ));
+                            }
+                        }
+                    }
+                    ConfigCommands::SetMergeSameDayRewatches { enabled } => {
+                        match config_manager.set_merge_same_day_rewatches(enabled) {
+                            Ok(_) => {
+                                display.print_success(&format!(
+                                    "Merge same-day rewatches: {}",
+                                    if enabled { "on" } else { "off" }
+                                ));
+                            }
+                            Err(e) => {
+                                display.print_error(&format!(
+                                    "Failed to update merge same-day rewatches setting: {}",
+                                    e
+                                ));
+                            }
+                        }
+                    }
+                    ConfigCommands::SetRelativeDates { enabled } => {
+                        match config_manager.set_relative_dates(enabled) {
+                            Ok(_) => {
+                                display.print_success(&format!(
+                                    "Relative dates: {}",
+                                    if enabled { "on" } else { "off" }
+                                ));
+                            }
+                            Err(e) => {
+                                display.print_error(&format!(
+                                    "Failed to update relative dates setting: {}",
+                                    e
+                                ));
+                            }
+                        }
+                    }
+                    ConfigCommands::SetAsciiColorDepth { depth } => {
+                        let ascii_color_depth = match depth {
+                            AsciiColorDepthArg::Auto => AsciiColorDepth::Auto,
+                            AsciiColorDepthArg::Truecolor => AsciiColorDepth::TrueColor,
+                            AsciiColorDepthArg::Color256 => AsciiColorDepth::Color256,
+                            AsciiColorDepthArg::Color16 => AsciiColorDepth::Color16,
+                            AsciiColorDepthArg::Mono => AsciiColorDepth::Mono,
+                        };
+                        match config_manager.set_ascii_color_depth(ascii_color_depth) {
+                            Ok(_) => {
+                                display.print_success(&format!(
+                                    "ASCII poster color depth set to: {:?}",
+                                    depth
+                                ));
+                            }
+                            Err(e) => {
+                                display.print_error(&format!(
+                                    "Failed to update ASCII color depth: {}",
+                                    e
+                                ));
+                            }
                         }
+                    }
+                    ConfigCommands::SetPosterStyle { style } => {
+                        let poster_style = match style {
+                            PosterStyleArg::Blocks => PosterStyle::Blocks,
+                            PosterStyleArg::Braille => PosterStyle::Braille,
+                        };
+                        match config_manager.set_poster_style(poster_style) {
+                            Ok(_) => {
+                                display.print_success(&format!("Poster style set to: {:?}", style));
+                            }
+                            Err(e) => {
+                                display
+                                    .print_error(&format!("Failed to update poster style: {}", e));
+                            }
+                        }
+                    }
+                    ConfigCommands::SetBackgroundTaskLimit { limit } => {
+                        match config_manager.set_tui_background_task_limit(limit) {
+                            Ok(_) => {
+                                display.print_success(&format!(
+                                    "TUI background task limit set to: {}",
+                                    limit.max(1)
+                                ));
+                            }
+                            Err(e) => {
+                                display.print_error(&format!(
+                                    "Failed to update background task limit: {}",
+                                    e
+                                ));
+                            }
+                        }
+                    }
+                    ConfigCommands::SetHeadlineStat { stat } => {
+                        let headline_stat = match stat {
+                            HeadlineStatArg::TotalFilms => HeadlineStat::TotalFilms,
+                            HeadlineStatArg::ViewingHours => HeadlineStat::ViewingHours,
+                            HeadlineStatArg::FilmsThisYear => HeadlineStat::FilmsThisYear,
+                        };
+                        match config_manager.set_headline_stat(headline_stat) {
+                            Ok(_) => {
+                                display.print_success(&format!("Headline stat set to: {:?}", stat));
+                            }
+                            Err(e) => {
+                                display
+                                    .print_error(&format!("Failed to update headline stat: {}", e));
+                            }
+                        }
+                    }
+                    ConfigCommands::SetTheme { theme } => {
+                        let new_theme = match theme {
+                            ThemeArg::Letterboxd => Theme::Letterboxd,
+                            ThemeArg::Solarized => Theme::Solarized,
+                            ThemeArg::Mono => Theme::Mono,
+                        };
+                        match config_manager.set_theme(new_theme) {
+                            Ok(_) => {
+                                display.print_success(&format!("Theme set to: {:?}", theme));
+                            }
+                            Err(e) => {
+                                display.print_error(&format!("Failed to update theme: {}", e));
+                            }
+                        }
+                    }
+                    ConfigCommands::SetDateFormat { preset, pattern } => {
+                        let date_format = match preset {
+                            DateFormatArg::Iso => DateFormat::Iso,
+                            DateFormatArg::Us => DateFormat::Us,
+                            DateFormatArg::Eu => DateFormat::Eu,
+                            DateFormatArg::Custom => match pattern {
+                                Some(p) => DateFormat::Custom(p),
+                                None => {
+                                    display.print_error(
+                                        "`custom` requires a strftime pattern, e.g. \
+                                     `config set-date-format custom '%d/%m/%Y'`",
+                                    );
+                                    return;
+                                }
+                            },
+                        };
+                        match config_manager.set_date_format(date_format) {
+                            Ok(_) => {
+                                display.print_success("Date format updated");
+                            }
+                            Err(e) => {
+                                display
+                                    .print_error(&format!("Failed to update date format: {}", e));
+                            }
+                        }
+                    }
+                    ConfigCommands::SetNotifyOnCompletion { enabled } => {
+                        match config_manager.set_notify_on_completion(enabled) {
+                            Ok(_) => {
+                                display.print_success(&format!(
+                                    "Completion notifications set to: {}",
+                                    enabled
+                                ));
+                            }
+                            Err(e) => {
+                                display.print_error(&format!(
+                                    "Failed to update completion notifications: {}",
+                                    e
+                                ));
+                            }
+                        }
+                    }
+                    ConfigCommands::SetRssContact { contact } => {
+                        match config_manager.set_rss_contact(contact) {
+                            Ok(_) => {
+                                display.print_success("RSS contact info updated");
+                            }
+                            Err(e) => {
+                                display.print_error(&format!(
+                                    "Failed to update RSS contact info: {}",
+                                    e
+                                ));
+                            }
+                        }
+                    }
+                    ConfigCommands::SetTmdbLanguage { language } => {
+                        match config_manager.set_tmdb_language(language.clone()) {
+                            Ok(_) => {
+                                display
+                                    .print_success(&format!("TMDB language set to: {}", language));
+                            }
+                            Err(e) => {
+                                display
+                                    .print_error(&format!("Failed to update TMDB language: {}", e));
+                            }
+                        }
+                    }
+                    ConfigCommands::SetTmdbRegion { region } => {
+                        match config_manager.set_tmdb_region(region.clone()) {
+                            Ok(_) => {
+                                display.print_success(&format!("TMDB region set to: {}", region));
+                            }
+                            Err(e) => {
+                                display
+                                    .print_error(&format!("Failed to update TMDB region: {}", e));
+                            }
+                        }
+                    }
+                    ConfigCommands::SetDefaultClient { client } => {
+                        let data_client = match client {
+                            ClientArg::Rss => DataClient::Rss,
+                            ClientArg::Native => DataClient::Native,
+                        };
+                        match config_manager.set_default_client(data_client) {
+                            Ok(_) => {
+                                display
+                                    .print_success(&format!("Default client set to: {:?}", client));
+                            }
+                            Err(e) => {
+                                display.print_error(&format!(
+                                    "Failed to update default client: {}",
+                                    e
+                                ));
+                            }
+                        }
+                    }
+                    ConfigCommands::AddAlias { name, username } => {
+                        match config_manager.add_alias(name.clone(), username.clone()) {
+                            Ok(_) => {
+                                display.print_success(&format!(
+                                    "Alias '{}' now resolves to '{}'",
+                                    name, username
+                                ));
+                            }
+                            Err(e) => {
+                                display.print_error(&format!("Failed to save alias: {}", e));
+                            }
+                        }
+                    }
+                }
+            }
+
+            Commands::Browse {
+                username,
+                compare_username,
+                no_enrich,
+                recommend,
+                limit,
+                watchlist_sort_by,
+            } => {
+                let actual_username = resolve_username(&username, &config_manager, &display).await;
+                if actual_username.is_none() {
+                    return;
+                }
+                let actual_username = actual_username.unwrap();
+
+                let skip_enrich = no_enrich
+                    || config_manager
+                        .get_skip_enrichment_by_default()
+                        .unwrap_or(false);
+
+                let watchlist_sort_by = watchlist_sort_by.map(|arg| match arg {
+                    WatchlistSortArg::Added => tui::SortMode::Added,
+                    WatchlistSortArg::Release => tui::SortMode::Year,
+                    WatchlistSortArg::Title => tui::SortMode::Title,
+                    WatchlistSortArg::Runtime => tui::SortMode::Runtime,
+                });
+
+                // Launch TUI
+                let load_started = std::time::Instant::now();
+                let result = tui::run_tui_with_options(
+                    &actual_username,
+                    compare_username,
+                    skip_enrich,
+                    recommend,
+                    limit,
+                    watchlist_sort_by,
+                )
+                .await;
+                lbxd::notify::notify_completion(
+                    &format!("Finished loading {}'s collection", actual_username),
+                    load_started.elapsed(),
+                );
+                if let Err(e) = result {
+                    display.print_error(&format!("TUI failed: {}", e));
+                }
+            }
+            Commands::List {
+                url,
+                width,
+                poster_width,
+            } => {
+                let poster_width = poster_width.unwrap_or(width);
+                display.print_minimal_logo();
+
+                let list = if let Some(cached) =
+                    cache_manager.as_ref().and_then(|c| c.get_cached_list(&url))
+                {
+                    Some(cached)
+                } else {
+                    display
+                        .print_loading_animation("Fetching list...", 500)
+                        .await;
+                    match LetterboxdClient::new() {
+                        Ok(client) => match client.get_list_by_url(&url).await {
+                            Ok(list) => {
+                                if let Some(ref cache) = cache_manager {
+                                    let _ = cache.cache_list(&url, &list);
+                                }
+                                Some(list)
+                            }
+                            Err(e) => {
+                                display.print_error(&format!("{}", e));
+                                None
+                            }
+                        },
                         Err(e) => {
-                            display.print_error(&format!("Failed to update display mode: {}", e));
+                            display.print_error(&format!("Failed to initialize client: {}", e));
+                            None
                         }
                     }
+                };
+
+                if let Some(list) = list {
+                    display.print_header(&format!("📋 {}", list.name));
+                    if let Some(ref description) = list.description {
+                        println!("{}", description);
+                        println!();
+                    }
+                    println!(
+                        "{} film{}",
+                        list.movies.len(),
+                        if list.movies.len() == 1 { "" } else { "s" }
+                    );
+
+                    for movie in &list.movies {
+                        display
+                            .display_movie_with_poster(
+                                &movie.title,
+                                movie.year.map(|y| y as i32),
+                                movie.poster_url.clone(),
+                                None,
+                                None,
+                                movie.synopsis.as_ref(),
+                                None,
+                                None,
+                                None,
+                                poster_width,
+                            )
+                            .await;
+                    }
+                    display.print_footer();
                 }
             }
-        }
 
-        Commands::Browse { username } => {
-            let actual_username = resolve_username(&username, &config_manager, &display).await;
-            if actual_username.is_none() {
-                return;
+            Commands::Serve { port } => {
+                #[cfg(feature = "server")]
+                {
+                    display.print_minimal_logo();
+                    if let Err(e) = lbxd::server::serve(port).await {
+                        display.print_error(&format!("Server failed: {}", e));
+                    }
+                }
+                #[cfg(not(feature = "server"))]
+                {
+                    let _ = port;
+                    display.print_error(
+                        "The `serve` command requires lbxd to be built with the `server` feature: \
+                     cargo install --path . --features server",
+                    );
+                }
             }
-            let actual_username = actual_username.unwrap();
+        }
+    };
 
-            // Launch TUI
-            if let Err(e) = tui::run_tui(&actual_username).await {
-                display.print_error(&format!("TUI failed: {}", e));
+    match timeout {
+        Some(secs) => {
+            if tokio::time::timeout(std::time::Duration::from_secs(secs), command_future)
+                .await
+                .is_err()
+            {
+                // Dropping `command_future` here runs the TUI's `TerminalGuard`
+                // (see `tui::run_tui_with_options`), restoring the terminal even
+                // though the future never got to finish its own cleanup code.
+                display.print_error(&format!("Operation timed out after {}s", secs));
+                std::process::exit(1);
             }
         }
+        None => command_future.await,
+    }
+}
+
+/// Masks all but the last 4 characters of an API key for safe display.
+fn mask_api_key(key: &str) -> String {
+    if key.len() <= 4 {
+        "*".repeat(key.len())
+    } else {
+        format!("{}{}", "*".repeat(key.len() - 4), &key[key.len() - 4..])
+    }
+}
+
+/// Converts a fully-scraped `ComprehensiveProfile` (native client) into the
+/// lighter `UserProfile`/`UserEntry` shape the RSS-oriented display paths
+/// (`show_user_activity`) expect, so `--client native` can reuse them.
+fn comprehensive_profile_to_user_profile(
+    profile: &lbxd::profile::ComprehensiveProfile,
+) -> lbxd::models::UserProfile {
+    let entries = profile
+        .all_movies
+        .iter()
+        .map(|entry| lbxd::models::UserEntry {
+            movie: lbxd::models::Movie {
+                title: entry.movie.title.clone(),
+                year: entry.movie.year.map(i32::from),
+                director: entry.movie.director.clone(),
+                letterboxd_url: entry.movie.letterboxd_url.clone(),
+                poster_url: entry.movie.poster_url.clone(),
+                tmdb_id: None,
+            },
+            rating: entry.user_rating,
+            review: entry.review.clone(),
+            watched_date: entry.watched_date,
+            entry_type: if entry.review.is_some() {
+                lbxd::models::EntryType::Review
+            } else {
+                lbxd::models::EntryType::Watch
+            },
+            liked: entry.liked,
+        })
+        .collect();
+
+    lbxd::models::UserProfile {
+        username: profile.username.clone(),
+        display_name: Some(profile.name.clone()),
+        avatar_url: profile.avatar_url.clone(),
+        rss_url: format!("https://letterboxd.com/{}/rss/", profile.username),
+        entries,
     }
 }
 
@@ -411,6 +1948,68 @@ fn filter_entries(
     profile
 }
 
+/// Resolves the `me` alias to the configured username.
+///
+/// Note: config only ever stores a single saved username — there is no
+/// multi-account alias list yet, so `me` can't actually be ambiguous today.
+/// Once multiple stored accounts exist, the ambiguous case should present an
+/// interactive chooser when `stdout_is_terminal()`, and otherwise (including
+/// any future `--json` mode) fail with an error asking for an explicit
+/// handle rather than guessing, matching the non-interactive failure below.
+/// Reads newline-separated usernames from stdin for `--stdin` batch
+/// operations (`export-batch`, `compare`, `feed`). Blank lines and lines
+/// starting with `#` are skipped, so a plain text file of handles can also
+/// carry comments without extra filtering on the caller's end.
+fn read_usernames_from_stdin() -> Vec<String> {
+    use std::io::BufRead;
+
+    std::io::stdin()
+        .lock()
+        .lines()
+        .map_while(Result::ok)
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect()
+}
+
+/// Shows a single resolved TMDB search match, either as a poster + details
+/// view or, with `--compare`, as a cross-source rating comparison. Shared by
+/// the `movie` command's exact-match and cleaned-title-retry paths.
+async fn display_tmdb_movie_result(
+    display: &DisplayEngine,
+    movie: &TMDBMovie,
+    poster_width: u32,
+    compare: bool,
+) {
+    if compare {
+        let omdb_client = OMDBClient::new();
+        let omdb_movie = omdb_client
+            .get_movie_by_title(&movie.title, movie.get_year().map(|y| y as u16))
+            .await
+            .ok()
+            .flatten();
+
+        let (imdb, rotten_tomatoes, metacritic) = match &omdb_movie {
+            Some(m) => (
+                omdb_client.get_imdb_rating(m),
+                omdb_client.get_rotten_tomatoes_rating(m),
+                omdb_client.get_metacritic_rating(m),
+            ),
+            None => (None, None, None),
+        };
+
+        display.show_rating_comparison(
+            &movie.title,
+            Some(movie.vote_average),
+            imdb,
+            rotten_tomatoes,
+            metacritic,
+        );
+    } else {
+        display.show_tmdb_movie(movie, poster_width).await;
+    }
+}
+
 async fn resolve_username(
     username: &str,
     config_manager: &ConfigManager,
@@ -420,7 +2019,7 @@ async fn resolve_username(
         match config_manager.get_username() {
             Ok(Some(saved_username)) => Some(saved_username),
             Ok(None) => {
-                display.print_error("No username saved. Please provide a username or run a command with your actual username first.");
+                display.print_error("No username saved. Please provide a username explicitly (e.g. `lbxd recent <username>`) or run a command with your actual username first.");
                 None
             }
             Err(_) => {
@@ -429,12 +2028,16 @@ async fn resolve_username(
             }
         }
     } else {
+        let resolved = match config_manager.get_alias(username) {
+            Ok(Some(aliased_username)) => aliased_username,
+            _ => username.to_string(),
+        };
         if config_manager.get_username().unwrap_or(None).is_none() {
-            if let Err(_) = config_manager.set_username(username.to_string()) {
+            if let Err(_) = config_manager.set_username(resolved.clone()) {
                 display.print_error("Warning: Could not save username to configuration");
             }
         }
-        Some(username.to_string())
+        Some(resolved)
     }
 }
 
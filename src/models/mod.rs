@@ -45,7 +45,24 @@ pub struct ViewingSummary {
     pub total_movies: usize,
     pub total_reviews: usize,
     pub average_rating: Option<f32>,
-    pub top_movies: Vec<(Movie, f32)>,
+    /// Movie, rating, and (when `--with-reviews` was requested) a first-sentence
+    /// excerpt of the user's review for that film.
+    pub top_movies: Vec<(Movie, f32, Option<String>)>,
     pub favorite_directors: Vec<(String, usize)>,
-    pub months_breakdown: Vec<(String, usize)>,
+    pub months_breakdown: Vec<MonthBreakdown>,
+}
+
+/// One month's worth of the monthly chart, split into rating buckets so the
+/// chart bar can be colored/segmented by how well a month's films were rated
+/// rather than just showing a flat count.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonthBreakdown {
+    pub month: String,
+    pub total: usize,
+    /// Films rated 4★ or higher.
+    pub high_rated: usize,
+    /// Films rated 3★ up to (but not including) 4★.
+    pub mid_rated: usize,
+    /// Films rated below 3★, including unrated films.
+    pub low_rated: usize,
 }
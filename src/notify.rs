@@ -0,0 +1,42 @@
+use std::time::Duration;
+
+/// Operations shorter than this don't bother the user — a bell on every
+/// few-second `browse` load would be more annoying than helpful.
+const MIN_NOTIFY_DURATION: Duration = Duration::from_secs(10);
+
+/// Rings the terminal bell and, when built with the `desktop-notify`
+/// feature, shows a desktop notification, for a long-running operation that
+/// just finished. No-op unless `notify_on_completion` is enabled in config
+/// and `elapsed` exceeds a short threshold, so quick fetches stay silent.
+/// Failures (no notification daemon running, no config directory, etc.) are
+/// swallowed — a missed notification isn't worth failing the command over.
+pub fn notify_completion(message: &str, elapsed: Duration) {
+    if elapsed < MIN_NOTIFY_DURATION {
+        return;
+    }
+
+    let enabled = crate::config::ConfigManager::new()
+        .and_then(|cm| cm.get_notify_on_completion())
+        .unwrap_or(false);
+
+    if !enabled {
+        return;
+    }
+
+    use std::io::Write;
+    print!("\x07");
+    let _ = std::io::stdout().flush();
+
+    #[cfg(feature = "desktop-notify")]
+    {
+        let _ = notify_rust::Notification::new()
+            .summary("lbxd")
+            .body(message)
+            .show();
+    }
+
+    #[cfg(not(feature = "desktop-notify"))]
+    {
+        let _ = message;
+    }
+}
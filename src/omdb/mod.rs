@@ -8,6 +8,40 @@ use std::time::Duration;
 const DEFAULT_OMDB_API_KEY: &str = "ad032cc2";
 const OMDB_BASE_URL: &str = "http://www.omdbapi.com/";
 
+/// Why an OMDB movie lookup failed, distinguished up front from the HTTP
+/// status and the response body's `Response`/`Error` fields, rather than
+/// letting every failure mode (a bad key, a rate limit, a genuinely missing
+/// title, a malformed body) collapse into the same `serde_json` error.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OmdbError {
+    /// The API key was rejected (HTTP 401, or an "Invalid API key!" body).
+    Auth,
+    /// OMDB's request-limit response (HTTP 429, or a "Request limit
+    /// reached!" body).
+    RateLimited,
+    /// No title matched the query. Not necessarily a problem — most lookups
+    /// by a scraped or user-typed title won't match exactly.
+    NotFound,
+    /// The body wasn't valid JSON, or didn't match the expected shape.
+    Parse(String),
+    /// The request itself failed (DNS, connection, timeout).
+    Network(String),
+}
+
+impl std::fmt::Display for OmdbError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OmdbError::Auth => write!(f, "OMDB rejected the request (invalid or revoked API key)"),
+            OmdbError::RateLimited => write!(f, "OMDB rate limit reached"),
+            OmdbError::NotFound => write!(f, "No matching title found on OMDB"),
+            OmdbError::Parse(msg) => write!(f, "Failed to parse OMDB response: {}", msg),
+            OmdbError::Network(msg) => write!(f, "Network error contacting OMDB: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for OmdbError {}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OMDBMovie {
     #[serde(rename = "Title")]
@@ -54,6 +88,8 @@ pub struct OMDBMovie {
     pub box_office: Option<String>,
     #[serde(rename = "Response")]
     pub response: String,
+    #[serde(rename = "Error")]
+    pub error: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -104,9 +140,111 @@ impl OMDBClient {
         Self { client }
     }
 
-    /// Get OMDB API key from environment variable or use default
+    /// Get OMDB API key from the OS keyring, then the environment variable, then
+    /// saved config, then the default.
     fn get_api_key() -> String {
-        env::var("OMDB_API_KEY").unwrap_or_else(|_| DEFAULT_OMDB_API_KEY.to_string())
+        Self::resolve_api_key().0
+    }
+
+    /// Resolves the OMDB API key using the same precedence as [`Self::get_api_key`],
+    /// also returning which source won, for display in `config show`.
+    pub fn resolve_api_key() -> (String, &'static str) {
+        if let Some(key) = crate::secrets::get_key("omdb_api_key") {
+            return (key, "OS keyring");
+        }
+        if let Ok(key) = env::var("OMDB_API_KEY") {
+            return (key, "OMDB_API_KEY env var");
+        }
+        if let Ok(cm) = crate::config::ConfigManager::new() {
+            if let Ok(Some(key)) = cm.get_omdb_api_key() {
+                return (key, "config (omdb_api_key)");
+            }
+        }
+        (DEFAULT_OMDB_API_KEY.to_string(), "built-in default")
+    }
+
+    /// Warns the user once per run that the shared default API key has been rejected.
+    fn warn_auth_failure() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        static WARNED: AtomicBool = AtomicBool::new(false);
+        if WARNED.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        eprintln!(
+            "OMDB rejected the request (invalid or revoked API key). Set your own key with \
+             'lbxd config set-api-key omdb <key>' or the OMDB_API_KEY environment variable."
+        );
+    }
+
+    /// Warns the user once per run that OMDB's request-limit has been hit.
+    fn warn_rate_limited() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        static WARNED: AtomicBool = AtomicBool::new(false);
+        if WARNED.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        eprintln!(
+            "OMDB rate limit reached; ratings/enrichment will be skipped until it resets. \
+             Set your own key with 'lbxd config set-api-key omdb <key>' for a higher limit."
+        );
+    }
+
+    fn is_auth_error(status_unauthorized: bool, error: &Option<String>) -> bool {
+        status_unauthorized
+            || error
+                .as_deref()
+                .map(|e| e.to_lowercase().contains("invalid api key"))
+                .unwrap_or(false)
+    }
+
+    /// Fetches and parses an OMDB movie-lookup response, checking the HTTP
+    /// status and the body's `Response`/`Error` fields before attempting to
+    /// deserialize into `OMDBMovie`, so auth failures, rate limits, and
+    /// "no match" all come back as a distinguishable `OmdbError` instead of
+    /// a generic parse error. Shared by all three movie-by-* lookups below.
+    async fn fetch_movie(&self, url: &str) -> std::result::Result<OMDBMovie, OmdbError> {
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| OmdbError::Network(e.to_string()))?;
+
+        let status_unauthorized = response.status().as_u16() == 401;
+        let status_rate_limited = response.status().as_u16() == 429;
+
+        let body = response
+            .text()
+            .await
+            .map_err(|e| OmdbError::Network(e.to_string()))?;
+
+        let value: serde_json::Value =
+            serde_json::from_str(&body).map_err(|e| OmdbError::Parse(e.to_string()))?;
+
+        let is_success = value.get("Response").and_then(|v| v.as_str()) == Some("True");
+        let error_text = value
+            .get("Error")
+            .and_then(|v| v.as_str())
+            .map(str::to_string);
+
+        if Self::is_auth_error(status_unauthorized, &error_text) {
+            Self::warn_auth_failure();
+            return Err(OmdbError::Auth);
+        }
+        if status_rate_limited
+            || error_text
+                .as_deref()
+                .map(|e| e.to_lowercase().contains("request limit"))
+                .unwrap_or(false)
+        {
+            Self::warn_rate_limited();
+            return Err(OmdbError::RateLimited);
+        }
+        if !is_success {
+            return Err(OmdbError::NotFound);
+        }
+
+        serde_json::from_value(value).map_err(|e| OmdbError::Parse(e.to_string()))
     }
 
     pub async fn get_movie_by_title(
@@ -126,13 +264,10 @@ impl OMDBClient {
             url.push_str(&format!("&y={}", year));
         }
 
-        let response = self.client.get(&url).send().await?;
-        let omdb_movie: OMDBMovie = response.json().await?;
-
-        if omdb_movie.response == "True" {
-            Ok(Some(omdb_movie))
-        } else {
-            Ok(None)
+        match self.fetch_movie(&url).await {
+            Ok(movie) => Ok(Some(movie)),
+            Err(OmdbError::NotFound) => Ok(None),
+            Err(e) => Err(e.into()),
         }
     }
 
@@ -153,12 +288,41 @@ impl OMDBClient {
             url.push_str(&format!("&y={}", year));
         }
 
-        let response = self.client.get(&url).send().await?;
-        let search_result: OMDBSearchResult = response.json().await?;
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| OmdbError::Network(e.to_string()))?;
+        let status_unauthorized = response.status().as_u16() == 401;
+        let status_rate_limited = response.status().as_u16() == 429;
+
+        let body = response
+            .text()
+            .await
+            .map_err(|e| OmdbError::Network(e.to_string()))?;
+        let search_result: OMDBSearchResult =
+            serde_json::from_str(&body).map_err(|e| OmdbError::Parse(e.to_string()))?;
+
+        if Self::is_auth_error(status_unauthorized, &search_result.error) {
+            Self::warn_auth_failure();
+            return Err(OmdbError::Auth.into());
+        }
+        if status_rate_limited
+            || search_result
+                .error
+                .as_deref()
+                .map(|e| e.to_lowercase().contains("request limit"))
+                .unwrap_or(false)
+        {
+            Self::warn_rate_limited();
+            return Err(OmdbError::RateLimited.into());
+        }
 
         if search_result.response == "True" {
             Ok(search_result.search.unwrap_or_default())
         } else {
+            // A genuine "no results" response, not an error condition.
             Ok(Vec::new())
         }
     }
@@ -167,16 +331,86 @@ impl OMDBClient {
         let api_key = Self::get_api_key();
         let url = format!("{}?apikey={}&i={}", OMDB_BASE_URL, api_key, imdb_id);
 
-        let response = self.client.get(&url).send().await?;
-        let omdb_movie: OMDBMovie = response.json().await?;
+        match self.fetch_movie(&url).await {
+            Ok(movie) => Ok(Some(movie)),
+            Err(OmdbError::NotFound) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
 
-        if omdb_movie.response == "True" {
-            Ok(Some(omdb_movie))
+    /// OMDB returns the literal string `"N/A"` (and sometimes an empty string)
+    /// for fields it doesn't have data for, e.g. runtime/year/awards on very
+    /// obscure titles. Collapses both down to `None` so callers never need to
+    /// special-case the placeholder themselves.
+    fn clean_field(value: &str) -> Option<&str> {
+        let trimmed = value.trim();
+        if trimmed.is_empty() || trimmed.eq_ignore_ascii_case("n/a") {
+            None
         } else {
-            Ok(None)
+            Some(trimmed)
         }
     }
 
+    /// Parsed release year, with `"N/A"` mapped to `None` before parsing.
+    pub fn get_year(&self, movie: &OMDBMovie) -> Option<u16> {
+        Self::clean_field(&movie.year).and_then(|y| y.parse().ok())
+    }
+
+    /// Runtime in minutes, with `"N/A"` mapped to `None` before parsing.
+    pub fn get_runtime_minutes(&self, movie: &OMDBMovie) -> Option<u16> {
+        movie
+            .runtime
+            .as_deref()
+            .and_then(Self::clean_field)
+            .and_then(|r| r.trim_end_matches(" min").parse().ok())
+    }
+
+    /// Comma-separated genre list, with `"N/A"` mapped to an empty `Vec`.
+    pub fn get_genres(&self, movie: &OMDBMovie) -> Vec<String> {
+        movie
+            .genre
+            .as_deref()
+            .and_then(Self::clean_field)
+            .map(|g| g.split(", ").map(String::from).collect())
+            .unwrap_or_default()
+    }
+
+    /// Director, with `"N/A"` mapped to `None`.
+    pub fn get_director(&self, movie: &OMDBMovie) -> Option<String> {
+        movie
+            .director
+            .as_deref()
+            .and_then(Self::clean_field)
+            .map(String::from)
+    }
+
+    /// Plot summary, with `"N/A"` mapped to `None`.
+    pub fn get_plot(&self, movie: &OMDBMovie) -> Option<String> {
+        movie
+            .plot
+            .as_deref()
+            .and_then(Self::clean_field)
+            .map(String::from)
+    }
+
+    /// Awards text, with `"N/A"` mapped to `None`.
+    pub fn get_awards(&self, movie: &OMDBMovie) -> Option<String> {
+        movie
+            .awards
+            .as_deref()
+            .and_then(Self::clean_field)
+            .map(String::from)
+    }
+
+    /// Release date, with `"N/A"` mapped to `None`.
+    pub fn get_release_date(&self, movie: &OMDBMovie) -> Option<String> {
+        movie
+            .released
+            .as_deref()
+            .and_then(Self::clean_field)
+            .map(String::from)
+    }
+
     // Helper methods to extract specific ratings
     pub fn get_imdb_rating(&self, movie: &OMDBMovie) -> Option<f32> {
         movie
@@ -216,3 +450,104 @@ impl Default for OMDBClient {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn na_movie() -> OMDBMovie {
+        OMDBMovie {
+            title: "Obscure Film".to_string(),
+            year: "N/A".to_string(),
+            rated: Some("N/A".to_string()),
+            released: Some("N/A".to_string()),
+            runtime: Some("N/A".to_string()),
+            genre: Some("N/A".to_string()),
+            director: Some("N/A".to_string()),
+            writer: Some("N/A".to_string()),
+            actors: Some("N/A".to_string()),
+            plot: Some("N/A".to_string()),
+            language: Some("N/A".to_string()),
+            country: Some("N/A".to_string()),
+            awards: Some("N/A".to_string()),
+            poster: Some("N/A".to_string()),
+            ratings: Some(Vec::new()),
+            metascore: Some("N/A".to_string()),
+            imdb_rating: Some("N/A".to_string()),
+            imdb_votes: Some("N/A".to_string()),
+            imdb_id: Some("tt0000000".to_string()),
+            movie_type: Some("movie".to_string()),
+            box_office: Some("N/A".to_string()),
+            response: "True".to_string(),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn is_auth_error_detects_401_status_or_invalid_key_message() {
+        assert!(OMDBClient::is_auth_error(true, &None));
+        assert!(OMDBClient::is_auth_error(
+            false,
+            &Some("Invalid API key!".to_string())
+        ));
+        assert!(!OMDBClient::is_auth_error(
+            false,
+            &Some("Movie not found!".to_string())
+        ));
+    }
+
+    #[test]
+    fn omdb_error_display_messages_are_distinguishable_per_shape() {
+        assert_eq!(
+            OmdbError::Auth.to_string(),
+            "OMDB rejected the request (invalid or revoked API key)"
+        );
+        assert_eq!(
+            OmdbError::RateLimited.to_string(),
+            "OMDB rate limit reached"
+        );
+        assert_eq!(
+            OmdbError::NotFound.to_string(),
+            "No matching title found on OMDB"
+        );
+        assert_eq!(
+            OmdbError::Parse("unexpected EOF".to_string()).to_string(),
+            "Failed to parse OMDB response: unexpected EOF"
+        );
+        assert_eq!(
+            OmdbError::Network("connection refused".to_string()).to_string(),
+            "Network error contacting OMDB: connection refused"
+        );
+    }
+
+    #[test]
+    fn omdb_error_variants_are_not_equal_across_shapes() {
+        assert_ne!(OmdbError::Auth, OmdbError::RateLimited);
+        assert_ne!(OmdbError::NotFound, OmdbError::Auth);
+        assert_ne!(
+            OmdbError::Parse("a".to_string()),
+            OmdbError::Parse("b".to_string())
+        );
+        assert_ne!(
+            OmdbError::Network("a".to_string()),
+            OmdbError::Parse("a".to_string())
+        );
+    }
+
+    #[test]
+    fn n_a_fields_map_to_none_or_empty() {
+        let client = OMDBClient::new();
+        let movie = na_movie();
+
+        assert_eq!(client.get_year(&movie), None);
+        assert_eq!(client.get_runtime_minutes(&movie), None);
+        assert_eq!(client.get_genres(&movie), Vec::<String>::new());
+        assert_eq!(client.get_director(&movie), None);
+        assert_eq!(client.get_plot(&movie), None);
+        assert_eq!(client.get_awards(&movie), None);
+        assert_eq!(client.get_release_date(&movie), None);
+        assert_eq!(client.get_imdb_rating(&movie), None);
+        assert_eq!(client.get_rotten_tomatoes_rating(&movie), None);
+        assert_eq!(client.get_metacritic_rating(&movie), None);
+    }
+}
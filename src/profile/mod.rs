@@ -40,6 +40,17 @@ pub struct UserMovieEntry {
     pub liked: bool,
     pub rewatched: bool,
     pub tags: Vec<String>,
+    /// How many diary log entries for this film on `watched_date`'s calendar
+    /// day were merged into this one, when `merge_same_day_rewatches` is
+    /// enabled (1 if no same-day duplicates were found, or the feature is
+    /// off). Shown as a "×N" indicator rather than silently inflating the
+    /// diary with what looks like one watch per logged entry.
+    #[serde(default = "default_same_day_rewatch_count")]
+    pub same_day_rewatch_count: u32,
+}
+
+fn default_same_day_rewatch_count() -> u32 {
+    1
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -90,6 +101,9 @@ pub struct ProfileStats {
     pub following_count: u32,
     pub followers_count: u32,
     pub favorite_films: Vec<FavoriteFilm>,
+    /// Pace projection derived from the diary, when enough dated entries exist.
+    pub average_watches_per_week: Option<f32>,
+    pub projected_year_end_total: Option<u32>,
 }
 
 #[derive(Debug, Clone)]
@@ -109,6 +123,16 @@ pub struct LoadingProgress {
     pub message: String,
 }
 
+/// A single film's freshly-fetched OMDB data, sent from the TUI's background
+/// enrichment task (see `LetterboxdClient::enrich_movies_in_background`) back
+/// to the app so it can patch the matching row(s) in `MovieGrid` in place and
+/// let the next `render` show real ratings instead of "-" placeholders.
+#[derive(Debug, Clone)]
+pub struct EnrichmentUpdate {
+    pub letterboxd_url: String,
+    pub movie: DetailedMovie,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserStatistics {
     pub total_viewing_time_hours: f32,
@@ -122,6 +146,11 @@ pub struct UserStatistics {
     pub average_rating: f32,
     pub most_watched_year: Option<u16>,
     pub most_watched_decade: Option<String>,
+    /// Average films watched per week across the diary's date span.
+    /// `None` when fewer than two dated entries exist (not enough span to derive a rate).
+    pub average_watches_per_week: Option<f32>,
+    /// Projected films for the current calendar year at the current pace.
+    pub projected_year_end_total: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -131,6 +160,8 @@ pub struct GenreStats {
     pub percentage: f32,
     pub average_rating: f32,
     pub emoji: String,
+    /// The user's highest-rated film in this genre, if any were rated.
+    pub top_film: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -157,6 +188,9 @@ pub struct YearlyBreakdown {
     pub average_rating: f32,
     pub top_genre: Option<String>,
     pub favorite_film: Option<String>,
+    /// How many of `film_count` were rewatches rather than first-time
+    /// watches. See [`crate::util::mark_rewatches`] for how this is detected.
+    pub rewatch_count: u32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -183,4 +217,68 @@ pub struct EnhancedStatistics {
     pub rating_distribution: Vec<RatingDistribution>,
     pub viewing_patterns: Vec<ViewingPattern>,
     pub data_source: String, // "premium" or "calculated" or "letterboxdpy"
+    /// `Some(n)` when these stats were computed over only the `n` most recent
+    /// diary entries because of a `max_diary_entries` cap, `None` when they
+    /// cover the whole diary.
+    pub capped_at: Option<u32>,
+    /// The longest film watched, by runtime. `None` if no watched film has a
+    /// known runtime (e.g. OMDB enrichment was skipped or capped).
+    pub longest_film: Option<RuntimeSuperlative>,
+    /// The shortest film watched, by runtime. See `longest_film`.
+    pub shortest_film: Option<RuntimeSuperlative>,
+    /// How many watched films had a known runtime when `longest_film`/
+    /// `shortest_film` were computed. Often much smaller than the full diary
+    /// since OMDB enrichment (the source of `DetailedMovie::runtime`) can be
+    /// skipped or capped, so these superlatives are a sample, not exhaustive.
+    pub runtime_sample_size: u32,
+    /// Average of (personal rating - Letterboxd community average rating)
+    /// over films with both ratings known. Positive means the user tends to
+    /// rate above the crowd, negative means below. `None` if no watched film
+    /// has both ratings.
+    pub average_contrarianness: Option<f32>,
+    /// Playful comparison against rough Letterboxd community baselines. See
+    /// `CommunityComparison`. `None` if there isn't enough data (e.g. no
+    /// genre breakdown) to compare against.
+    pub community_comparison: Option<CommunityComparison>,
+}
+
+/// A playful "you vs. the average Letterboxd user" comparison, computed
+/// against a small hardcoded table of rough community baselines (see
+/// `LetterboxdClient::calculate_community_comparison`) rather than any live
+/// aggregate data — Letterboxd doesn't expose one via RSS or rustboxd. Every
+/// surface that shows this should label it as approximate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommunityComparison {
+    /// User's average rating minus the community baseline. Positive means
+    /// the user rates higher than average.
+    pub rating_diff: f32,
+    /// User's average films-per-year minus the community baseline.
+    pub films_per_year_diff: f32,
+    /// The user's most-watched genre.
+    pub top_genre: String,
+    /// Rough share (0.0-1.0) of the community estimated to favor `top_genre`,
+    /// from the baseline table.
+    pub top_genre_community_share: f32,
+    /// Disclaimer shown alongside the comparison, making clear the baselines
+    /// are approximate hand-maintained figures, not live Letterboxd data.
+    pub note: String,
+}
+
+/// One row of the `compare` command's per-user summary table, shown above
+/// the pairwise compatibility scores. Sortable via `--sort-by` (see
+/// `crate::cli::CompareSortArg`).
+#[derive(Debug, Clone)]
+pub struct CompareSummaryRow {
+    pub username: String,
+    pub total_films: u32,
+    /// Average of the user's own ratings over rated films in their diary.
+    /// `None` if they haven't rated anything.
+    pub average_rating: Option<f32>,
+    pub review_count: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuntimeSuperlative {
+    pub title: String,
+    pub runtime_minutes: u16,
 }
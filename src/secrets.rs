@@ -0,0 +1,23 @@
+use anyhow::{Context, Result};
+
+const KEYRING_SERVICE: &str = "lbxd";
+
+/// Thin wrapper around the OS keyring (Keychain on macOS, Credential Manager on
+/// Windows, Secret Service on Linux) for storing API keys outside of the
+/// plaintext `config.toml`. Each key is namespaced by `account` (e.g.
+/// `"tmdb_api_key"`, `"omdb_api_key"`) under a single `lbxd` service entry.
+pub fn set_key(account: &str, value: &str) -> Result<()> {
+    let entry =
+        keyring::Entry::new(KEYRING_SERVICE, account).context("Failed to access OS keyring")?;
+    entry
+        .set_password(value)
+        .context("Failed to store key in OS keyring")
+}
+
+/// Reads a key back from the OS keyring. Returns `Ok(None)` (rather than an
+/// error) when no entry is stored or the platform has no keyring backend
+/// available, so callers can fall back to config/env transparently.
+pub fn get_key(account: &str) -> Option<String> {
+    let entry = keyring::Entry::new(KEYRING_SERVICE, account).ok()?;
+    entry.get_password().ok()
+}
@@ -0,0 +1,102 @@
+//! Minimal local HTTP/JSON API over cached Letterboxd data, for building
+//! dashboards against `lbxd` without scraping a feed yourself. Gated behind the
+//! `server` cargo feature so the default binary doesn't pull in axum.
+
+use crate::cache::CacheManager;
+use crate::feed::FeedParser;
+use crate::letterboxd_client_rust::LetterboxdClient;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Json};
+use axum::routing::get;
+use axum::Router;
+use std::sync::Arc;
+
+/// Shared state handed to every request handler. `FeedParser` and
+/// `LetterboxdClient` are cheap to construct (just an HTTP client each) so
+/// each handler builds its own rather than sharing one across requests,
+/// matching the pattern used elsewhere (`ExportBatch`, the favorites poster
+/// strip) for per-request concurrency.
+struct ServerState {
+    cache: Option<CacheManager>,
+}
+
+/// Starts the server and blocks until it's shut down (e.g. Ctrl-C). Serves:
+/// - `GET /profile/{username}` — the full `ComprehensiveProfile` (diary, watchlist,
+///   enhanced stats), same shape as `export --format json`. Like
+///   [`fetch_profile`](crate::fetch_profile), this always hits Letterboxd/OMDB
+///   directly rather than going through the on-disk cache.
+/// - `GET /recent/{username}` — the lightweight RSS-feed-derived `UserProfile`,
+///   backed by the same on-disk cache as the `recent` command.
+pub async fn serve(port: u16) -> anyhow::Result<()> {
+    let state = Arc::new(ServerState {
+        cache: CacheManager::new().ok(),
+    });
+
+    let app = Router::new()
+        .route("/profile/{username}", get(get_profile))
+        .route("/recent/{username}", get(get_recent))
+        .with_state(state);
+
+    let addr = format!("127.0.0.1:{}", port);
+    let listener = tokio::net::TcpListener::bind(&addr).await?;
+    println!("lbxd server listening on http://{}", addr);
+    axum::serve(listener, app).await?;
+
+    Ok(())
+}
+
+async fn get_profile(Path(username): Path<String>) -> axum::response::Response {
+    // rustboxd's scraping holds `scraper::Html` (backed by a non-`Send` tendril
+    // `Cell`) across `.await` points, so the comprehensive-profile future is not
+    // `Send` and can't be driven directly by axum's handler, which spawns each
+    // request onto the multi-threaded runtime. Running it to completion on its
+    // own single-threaded runtime inside `spawn_blocking` keeps that `!Send`
+    // state on one OS thread for its whole lifetime.
+    let result = tokio::task::spawn_blocking(move || {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        rt.block_on(async move {
+            let client = LetterboxdClient::new()?;
+            client.get_comprehensive_profile(&username, None).await
+        })
+    })
+    .await;
+
+    match result {
+        Ok(Ok(profile)) => Json(profile).into_response(),
+        Ok(Err(e)) => error_response(e),
+        Err(e) => error_response(anyhow::anyhow!("profile fetch task panicked: {e}")),
+    }
+}
+
+async fn get_recent(
+    State(state): State<Arc<ServerState>>,
+    Path(username): Path<String>,
+) -> impl IntoResponse {
+    if let Some(cache) = state.cache.as_ref() {
+        if let Some(cached) = cache.get_cached_profile(&username) {
+            return Json(cached).into_response();
+        }
+    }
+
+    let feed_parser = FeedParser::new();
+    match feed_parser.fetch_user_feed(&username).await {
+        Ok(profile) => {
+            if let Some(cache) = state.cache.as_ref() {
+                let _ = cache.cache_profile(&profile);
+            }
+            Json(profile).into_response()
+        }
+        Err(e) => error_response(e),
+    }
+}
+
+fn error_response(err: anyhow::Error) -> axum::response::Response {
+    (
+        StatusCode::BAD_GATEWAY,
+        Json(serde_json::json!({ "error": err.to_string() })),
+    )
+        .into_response()
+}
@@ -9,6 +9,7 @@ const DEFAULT_TMDB_API_KEY: &str = "bce5788c33b687c14b610654579ff6aa";
 const TMDB_BASE_URL: &str = "https://api.themoviedb.org/3";
 const TMDB_IMAGE_BASE_URL: &str = "https://image.tmdb.org/t/p/w780"; // Higher quality images
 const TMDB_IMAGE_ORIGINAL: &str = "https://image.tmdb.org/t/p/original";
+const DEFAULT_LANGUAGE: &str = "en-US";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TMDBMovie {
@@ -25,6 +26,11 @@ struct TMDBSearchResponse {
     results: Vec<TMDBMovie>,
 }
 
+#[derive(Debug, Deserialize)]
+struct TMDBNowPlayingResponse {
+    results: Vec<TMDBMovie>,
+}
+
 pub struct TMDBClient {
     client: reqwest::Client,
 }
@@ -45,9 +51,42 @@ impl TMDBClient {
         Self { client }
     }
 
-    /// Get TMDB API key from environment variable or use default
+    /// Get TMDB API key from the OS keyring, then the environment variable, then
+    /// saved config, then the default.
     fn get_api_key() -> String {
-        env::var("TMDB_API_KEY").unwrap_or_else(|_| DEFAULT_TMDB_API_KEY.to_string())
+        Self::resolve_api_key().0
+    }
+
+    /// Resolves the TMDB API key using the same precedence as [`Self::get_api_key`],
+    /// also returning which source won, for display in `config show`.
+    pub fn resolve_api_key() -> (String, &'static str) {
+        if let Some(key) = crate::secrets::get_key("tmdb_api_key") {
+            return (key, "OS keyring");
+        }
+        if let Ok(key) = env::var("TMDB_API_KEY") {
+            return (key, "TMDB_API_KEY env var");
+        }
+        if let Ok(cm) = crate::config::ConfigManager::new() {
+            if let Ok(Some(key)) = cm.get_tmdb_api_key() {
+                return (key, "config (tmdb_api_key)");
+            }
+        }
+        (DEFAULT_TMDB_API_KEY.to_string(), "built-in default")
+    }
+
+    /// Warns the user once per run that the shared default API key has been rejected.
+    fn warn_auth_failure() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        static WARNED: AtomicBool = AtomicBool::new(false);
+        if WARNED.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        eprintln!(
+            "{}",
+            "TMDB rejected the request (invalid or revoked API key). Set your own key with \
+             'lbxd config set-api-key tmdb <key>' or the TMDB_API_KEY environment variable."
+                .yellow()
+        );
     }
 
     pub async fn search_movie(&self, query: &str) -> Result<Option<TMDBMovie>> {
@@ -60,11 +99,45 @@ impl TMDBClient {
         year: Option<i32>,
     ) -> Result<Option<TMDBMovie>> {
         let api_key = Self::get_api_key();
+        let language = Self::resolve_language();
+
+        let mut movie = match self
+            .search_movie_in_language(query, year, &api_key, &language)
+            .await?
+        {
+            Some(movie) => movie,
+            None => return Ok(None),
+        };
+
+        // TMDB returns an empty (not missing) overview string when no translation
+        // exists for the requested language. Fall back to English rather than
+        // showing a blank synopsis.
+        let overview_missing = movie.overview.as_deref().is_none_or(str::is_empty);
+        if overview_missing && language != DEFAULT_LANGUAGE {
+            if let Some(fallback) = self
+                .search_movie_in_language(query, year, &api_key, DEFAULT_LANGUAGE)
+                .await?
+            {
+                movie.overview = fallback.overview;
+            }
+        }
+
+        Ok(Some(movie))
+    }
+
+    async fn search_movie_in_language(
+        &self,
+        query: &str,
+        year: Option<i32>,
+        api_key: &str,
+        language: &str,
+    ) -> Result<Option<TMDBMovie>> {
         let mut url = format!(
-            "{}/search/movie?api_key={}&query={}",
+            "{}/search/movie?api_key={}&query={}&language={}",
             TMDB_BASE_URL,
             api_key,
-            urlencoding::encode(query)
+            urlencoding::encode(query),
+            urlencoding::encode(language)
         );
 
         if let Some(year) = year {
@@ -73,6 +146,11 @@ impl TMDBClient {
 
         let response = self.client.get(&url).send().await?;
 
+        if response.status().as_u16() == 401 {
+            Self::warn_auth_failure();
+            return Err(anyhow::anyhow!("TMDB authentication failed (401)"));
+        }
+
         if !response.status().is_success() {
             return Err(anyhow::anyhow!(
                 "TMDB API request failed: {}",
@@ -85,8 +163,130 @@ impl TMDBClient {
         Ok(search_result.results.into_iter().next())
     }
 
+    /// Searches TMDB for `query` and returns up to `limit` matches instead of
+    /// just the best one, for "Did you mean...?" style fallbacks when an
+    /// exact-match search (`search_movie`) comes up empty.
+    pub async fn search_movies_multi(&self, query: &str, limit: usize) -> Result<Vec<TMDBMovie>> {
+        let api_key = Self::get_api_key();
+        let language = Self::resolve_language();
+
+        let url = format!(
+            "{}/search/movie?api_key={}&query={}&language={}",
+            TMDB_BASE_URL,
+            api_key,
+            urlencoding::encode(query),
+            urlencoding::encode(&language)
+        );
+
+        let response = self.client.get(&url).send().await?;
+
+        if response.status().as_u16() == 401 {
+            Self::warn_auth_failure();
+            return Err(anyhow::anyhow!("TMDB authentication failed (401)"));
+        }
+
+        if !response.status().is_success() {
+            return Err(anyhow::anyhow!(
+                "TMDB API request failed: {}",
+                response.status()
+            ));
+        }
+
+        let search_result: TMDBSearchResponse = response.json().await?;
+
+        Ok(search_result.results.into_iter().take(limit).collect())
+    }
+
+    /// Fetches TMDB's "now playing" list (films currently in theaters),
+    /// scoped to `region` (an ISO 3166-1 country code, e.g. `US`) when given,
+    /// falling back to the saved `tmdb_region` config, then TMDB's own
+    /// region default. Only the first page (~20 films) is fetched, which is
+    /// enough to check a watchlist against.
+    pub async fn get_now_playing(&self, region: Option<&str>) -> Result<Vec<TMDBMovie>> {
+        let api_key = Self::get_api_key();
+        let language = Self::resolve_language();
+        let region = region.map(str::to_string).or_else(Self::resolve_region);
+
+        let mut url = format!(
+            "{}/movie/now_playing?api_key={}&language={}",
+            TMDB_BASE_URL,
+            api_key,
+            urlencoding::encode(&language)
+        );
+
+        if let Some(region) = region {
+            url.push_str(&format!("&region={}", urlencoding::encode(&region)));
+        }
+
+        let response = self.client.get(&url).send().await?;
+
+        if response.status().as_u16() == 401 {
+            Self::warn_auth_failure();
+            return Err(anyhow::anyhow!("TMDB authentication failed (401)"));
+        }
+
+        if !response.status().is_success() {
+            return Err(anyhow::anyhow!(
+                "TMDB API request failed: {}",
+                response.status()
+            ));
+        }
+
+        let now_playing: TMDBNowPlayingResponse = response.json().await?;
+        Ok(now_playing.results)
+    }
+
+    /// Resolves the saved `tmdb_region` config, if any.
+    fn resolve_region() -> Option<String> {
+        crate::config::ConfigManager::new()
+            .ok()
+            .and_then(|cm| cm.get_tmdb_region().ok())
+            .flatten()
+    }
+
+    /// Resolves the TMDB content-negotiation language: saved config, then
+    /// `$LANG` (normalized from POSIX form, e.g. `fr_FR.UTF-8` -> `fr-FR`),
+    /// then the built-in default.
+    fn resolve_language() -> String {
+        if let Ok(cm) = crate::config::ConfigManager::new() {
+            if let Ok(Some(language)) = cm.get_tmdb_language() {
+                return language;
+            }
+        }
+
+        if let Ok(lang) = env::var("LANG") {
+            if let Some(normalized) = Self::normalize_lang_env(&lang) {
+                return normalized;
+            }
+        }
+
+        DEFAULT_LANGUAGE.to_string()
+    }
+
+    /// Normalizes a POSIX locale string (e.g. `en_US.UTF-8`, `C`) into a TMDB
+    /// language tag (e.g. `en-US`). Returns `None` for the "C"/"POSIX"
+    /// locale, which carries no real language information.
+    fn normalize_lang_env(lang: &str) -> Option<String> {
+        let base = lang.split('.').next()?;
+        if base.is_empty() || base.eq_ignore_ascii_case("C") || base.eq_ignore_ascii_case("POSIX") {
+            return None;
+        }
+        Some(base.replace('_', "-"))
+    }
+
     pub fn get_poster_url(&self, poster_path: &str) -> String {
-        format!("{}{}", TMDB_IMAGE_BASE_URL, poster_path)
+        Self::resolve_image_url(poster_path, TMDB_IMAGE_BASE_URL)
+    }
+
+    /// Builds an image URL from a TMDB `poster_path`, prefixing it with `base_url`
+    /// unless `poster_path` is already an absolute URL (e.g. from cache migration
+    /// or a different source), in which case it's returned unchanged.
+    fn resolve_image_url(poster_path: &str, base_url: &str) -> String {
+        if poster_path.starts_with("http") {
+            poster_path.to_string()
+        } else {
+            format!("{}{}", base_url, poster_path)
+        }
     }
 
     pub fn print_tmdb_attribution() {
@@ -108,18 +308,40 @@ impl TMDBMovie {
     pub fn get_full_poster_url(&self) -> Option<String> {
         self.poster_path
             .as_ref()
-            .map(|path| format!("{}{}", TMDB_IMAGE_BASE_URL, path))
+            .map(|path| TMDBClient::resolve_image_url(path, TMDB_IMAGE_BASE_URL))
     }
 
     pub fn get_high_quality_poster_url(&self) -> Option<String> {
         self.poster_path
             .as_ref()
-            .map(|path| format!("{}{}", TMDB_IMAGE_BASE_URL, path))
+            .map(|path| TMDBClient::resolve_image_url(path, TMDB_IMAGE_BASE_URL))
     }
 
     pub fn get_original_poster_url(&self) -> Option<String> {
         self.poster_path
             .as_ref()
-            .map(|path| format!("{}{}", TMDB_IMAGE_ORIGINAL, path))
+            .map(|path| TMDBClient::resolve_image_url(path, TMDB_IMAGE_ORIGINAL))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_image_url_prefixes_a_bare_path() {
+        assert_eq!(
+            TMDBClient::resolve_image_url("/abc123.jpg", TMDB_IMAGE_BASE_URL),
+            "https://image.tmdb.org/t/p/w780/abc123.jpg"
+        );
+    }
+
+    #[test]
+    fn resolve_image_url_leaves_a_full_url_unchanged() {
+        let full_url = "https://example.com/already-absolute.jpg";
+        assert_eq!(
+            TMDBClient::resolve_image_url(full_url, TMDB_IMAGE_BASE_URL),
+            full_url
+        );
     }
 }
@@ -1,13 +1,53 @@
 use crossterm::event::KeyEvent;
 use ratatui::{
+    buffer::Buffer,
     layout::{Constraint, Direction, Layout, Rect},
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Widget},
     Frame,
 };
+use unicode_width::UnicodeWidthStr;
 
 use super::{AppStyles, MovieGrid, MovieGridAction, ProgressBar};
 use crate::profile::{ComprehensiveProfile, LoadingProgress};
 
+/// Rows to move per PgUp/PgDn press when scrolling the Statistics tab.
+const STATS_SCROLL_STEP: u16 = 5;
+
+/// Minimum terminal dimensions the main layouts are designed for (header +
+/// tabs + content + status bar, each needing at least a few rows). Below
+/// this, `App::render` shows a "too small" message instead of attempting the
+/// full layout, which would otherwise compute zero/negative content areas.
+const MIN_TERMINAL_WIDTH: u16 = 40;
+const MIN_TERMINAL_HEIGHT: u16 = 15;
+
+/// Number of render ticks (~100ms apiece, driven by the main loop's event
+/// poll timeout) the "surprise me" reveal spends typewriting the title and
+/// unblurring the poster before showing the pick in full.
+const SURPRISE_REVEAL_TICKS: u8 = 10;
+
+/// A random watchlist pick mid-reveal, for `browse`'s "surprise me"
+/// decision-maker (`r` on the Watchlist tab). The title types out and the
+/// poster unblurs line-by-line over `SURPRISE_REVEAL_TICKS` render ticks,
+/// using whatever ASCII art `watchlist_grid`'s poster cache already holds
+/// (or fetching it fresh, same as any other poster load).
+pub struct SurpriseReveal {
+    pub movie: crate::profile::DetailedMovie,
+    pub ticks_elapsed: u8,
+}
+
+/// Side-by-side comparison state for `lbxd browse alice bob`: a second
+/// user's diary shown next to the primary one, with shared films
+/// highlighted in both. `None` unless a comparison username was given.
+pub struct WatchParty {
+    pub username: String,
+    pub grid: MovieGrid,
+    /// Which pane has keyboard focus: 0 for the primary grid, 1 for `grid`.
+    pub focus: usize,
+    /// Set if the comparison user's profile failed to load; the tab still
+    /// shows so the failure is visible instead of the pane just looking empty.
+    pub error: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub enum AppState {
     Loading,
@@ -29,6 +69,36 @@ pub struct App {
     pub search_results: Vec<crate::omdb::OMDBSearchMovie>,
     pub search_selected: usize,
     pub pending_poster_load: Option<String>, // movie title to load poster for
+    pub enrichment_skipped: bool,
+    pub genre_selected: usize,
+    /// Vertical scroll offset (in rows) into the Statistics tab's virtual
+    /// buffer, so panels below the viewport (e.g. rating distribution on a
+    /// short terminal) stay reachable via PgUp/PgDn.
+    pub stats_scroll: u16,
+    /// When true, the watchlist tab opens pre-sorted by predicted interest
+    /// (see `build_recommendation_scores`) instead of the default sort.
+    pub recommend_watchlist: bool,
+    /// Initial sort mode for the watchlist tab, from `--watchlist-sort-by`.
+    /// Ignored if `recommend_watchlist` is also set, since that takes priority.
+    pub watchlist_sort_by: Option<super::grid::SortMode>,
+    /// Active "surprise me" reveal, if the user picked one on the Watchlist
+    /// tab. `Some` while the overlay is showing, regardless of animation
+    /// progress.
+    pub surprise_reveal: Option<SurpriseReveal>,
+    /// Side-by-side diary comparison, set when `browse` was given a second
+    /// username. Adds a "Watch Party" tab when present.
+    pub watch_party: Option<WatchParty>,
+    /// Whether the debug overlay (background task pool usage) is shown in
+    /// the status bar. Toggled with F2.
+    pub show_debug_overlay: bool,
+    /// Number of background tasks (poster prefetch, OMDB enrichment)
+    /// currently running, as tracked by the TUI's `BackgroundTaskPool`.
+    /// Updated once per render tick; only meaningful when
+    /// `show_debug_overlay` is on.
+    pub background_tasks_active: usize,
+    /// Configured concurrency cap for the background task pool, shown
+    /// alongside `background_tasks_active` in the debug overlay.
+    pub background_task_limit: usize,
 }
 
 impl App {
@@ -46,14 +116,100 @@ impl App {
             search_results: Vec::new(),
             search_selected: 0,
             pending_poster_load: None,
+            enrichment_skipped: false,
+            genre_selected: 0,
+            stats_scroll: 0,
+            recommend_watchlist: false,
+            watchlist_sort_by: None,
+            surprise_reveal: None,
+            watch_party: None,
+            show_debug_overlay: false,
+            background_tasks_active: 0,
+            background_task_limit: 0,
         }
     }
 
+    /// Number of tabs currently shown: 3, plus a 4th "Watch Party" tab once
+    /// a comparison user has been requested.
+    fn tab_count(&self) -> usize {
+        if self.watch_party.is_some() {
+            4
+        } else {
+            3
+        }
+    }
+
+    /// Records the comparison user's loaded profile and recomputes the
+    /// shared-titles highlighting. The primary profile may not have finished
+    /// loading yet (the two fetches run concurrently), so the actual
+    /// intersection is deferred to `recompute_shared_titles`.
+    pub fn set_watch_party_profile(&mut self, username: String, profile: ComprehensiveProfile) {
+        let mut grid = MovieGrid::new();
+        grid.set_movies(profile.all_movies.clone());
+
+        self.watch_party = Some(WatchParty {
+            username,
+            grid,
+            focus: 0,
+            error: None,
+        });
+        self.recompute_shared_titles();
+    }
+
+    /// Highlights the films present in both the primary diary and the
+    /// "watch party" comparison diary, if both have loaded. Called whenever
+    /// either profile finishes loading, since the two fetches run
+    /// concurrently and either may resolve first.
+    fn recompute_shared_titles(&mut self) {
+        let Some(ref mut party) = self.watch_party else {
+            return;
+        };
+        if party.error.is_some() {
+            return;
+        }
+
+        let shared: std::collections::HashSet<String> = self
+            .movie_grid
+            .titles()
+            .intersection(&party.grid.titles())
+            .cloned()
+            .collect();
+        self.movie_grid.set_shared_titles(shared.clone());
+        party.grid.set_shared_titles(shared);
+    }
+
+    /// Records that the comparison user's profile failed to load. The tab
+    /// still appears so the failure is visible rather than the pane
+    /// silently looking empty.
+    pub fn set_watch_party_error(&mut self, username: String, error: String) {
+        self.watch_party = Some(WatchParty {
+            username,
+            grid: MovieGrid::new(),
+            focus: 0,
+            error: Some(error),
+        });
+    }
+
     pub fn update_progress(&mut self, progress: LoadingProgress) {
         self.progress = Some(progress);
     }
 
+    /// The primary grid's currently-loaded movies, for seeding the
+    /// background enrichment task once the initial profile load completes.
+    pub fn movies_for_background_enrichment(&self) -> Vec<crate::profile::UserMovieEntry> {
+        self.movie_grid.all_loaded_movies()
+    }
+
+    /// Applies a single row's freshly-fetched OMDB data as it streams in from
+    /// the background enrichment task.
+    pub fn apply_enrichment(&mut self, update: crate::profile::EnrichmentUpdate) {
+        self.movie_grid
+            .apply_enrichment(&update.letterboxd_url, update.movie);
+    }
+
     pub fn set_profile(&mut self, profile: ComprehensiveProfile) {
+        self.movie_grid.ratings_unavailable = self.enrichment_skipped;
+        self.watchlist_grid.ratings_unavailable = self.enrichment_skipped;
         self.movie_grid.set_movies(profile.all_movies.clone());
 
         // Convert watchlist DetailedMovies to UserMovieEntry for the grid
@@ -68,13 +224,86 @@ impl App {
                 liked: false,
                 rewatched: false,
                 tags: Vec::new(),
+                same_day_rewatch_count: 1,
             })
             .collect();
 
         self.watchlist_grid.set_movies(watchlist_entries);
+
+        if self.recommend_watchlist {
+            let recommendations = Self::build_recommendation_scores(&profile);
+            self.watchlist_grid.set_recommendations(recommendations);
+        } else if let Some(mode) = self.watchlist_sort_by {
+            self.watchlist_grid.set_sort_mode(mode);
+        }
+
         self.profile = Some(profile);
         self.state = AppState::Loaded;
         self.progress = None;
+        self.recompute_shared_titles();
+    }
+
+    /// Scores each watchlist film by how well its genres/director match the
+    /// user's highest-rated watched films, using `genre_breakdown` and
+    /// `director_stats` from `enhanced_stats` as the taste profile. Returns a
+    /// map from movie title to `(score out of 100, rationale)`.
+    fn build_recommendation_scores(
+        profile: &ComprehensiveProfile,
+    ) -> std::collections::HashMap<String, (f32, String)> {
+        let mut scores = std::collections::HashMap::new();
+
+        let Some(ref stats) = profile.enhanced_stats else {
+            return scores;
+        };
+
+        for movie in &profile.watchlist {
+            let mut weighted_sum = 0.0;
+            let mut factor_count = 0.0;
+            let mut best_reason: Option<(f32, String)> = None;
+
+            for genre_name in &movie.genres {
+                if let Some(genre) = stats.genre_breakdown.iter().find(|g| &g.name == genre_name) {
+                    weighted_sum += genre.average_rating;
+                    factor_count += 1.0;
+                    if best_reason
+                        .as_ref()
+                        .is_none_or(|(r, _)| genre.average_rating > *r)
+                    {
+                        best_reason = Some((
+                            genre.average_rating,
+                            format!("you liked {} films", genre.name),
+                        ));
+                    }
+                }
+            }
+
+            if let Some(ref director) = movie.director {
+                if let Some(director_stats) =
+                    stats.director_stats.iter().find(|d| &d.name == director)
+                {
+                    // Directors are a stronger taste signal than genre overlap alone.
+                    weighted_sum += director_stats.average_rating * 1.5;
+                    factor_count += 1.5;
+                    if best_reason
+                        .as_ref()
+                        .is_none_or(|(r, _)| director_stats.average_rating > *r)
+                    {
+                        best_reason = Some((
+                            director_stats.average_rating,
+                            format!("you liked films by {}", director_stats.name),
+                        ));
+                    }
+                }
+            }
+
+            if factor_count > 0.0 {
+                let score = (weighted_sum / (factor_count * 5.0)) * 100.0;
+                let rationale = best_reason.map(|(_, reason)| reason).unwrap_or_default();
+                scores.insert(movie.title.clone(), (score, rationale));
+            }
+        }
+
+        scores
     }
 
     pub fn set_error(&mut self, error: String) {
@@ -99,6 +328,74 @@ impl App {
         matches!(self.state, AppState::Search)
     }
 
+    /// Whether the active grid's compact-mode full-screen detail overlay
+    /// (opened with Enter) is showing, so the main loop routes Esc to the
+    /// grid to close it instead of treating Esc as "quit the app".
+    pub fn is_showing_fullscreen_detail(&self) -> bool {
+        match self.selected_tab {
+            0 => self.movie_grid.is_fullscreen_detail(),
+            1 => self.watchlist_grid.is_fullscreen_detail(),
+            _ => false,
+        }
+    }
+
+    /// Whether the "surprise me" reveal overlay is showing, so the main loop
+    /// routes Esc to it (to cancel) instead of treating Esc as "quit the app".
+    pub fn is_showing_surprise_reveal(&self) -> bool {
+        self.surprise_reveal.is_some()
+    }
+
+    /// Picks a random watchlist film and starts its reveal animation.
+    /// Reuses `watchlist_grid`'s poster cache/fetch path, same as any other
+    /// poster load, rather than a separate fetch mechanism.
+    fn start_surprise_pick(&mut self) {
+        let Some(ref profile) = self.profile else {
+            return;
+        };
+        if profile.watchlist.is_empty() {
+            return;
+        }
+
+        let index = Self::pseudo_random_index(profile.watchlist.len());
+        let movie = profile.watchlist[index].clone();
+
+        if !self.watchlist_grid.is_poster_cached(&movie.title) {
+            self.pending_poster_load = Some(movie.title.clone());
+        }
+
+        self.surprise_reveal = Some(SurpriseReveal {
+            movie,
+            ticks_elapsed: 0,
+        });
+    }
+
+    /// A simple, dependency-free index into `0..len`, seeded from the
+    /// current time's sub-second nanoseconds. Good enough for picking a
+    /// random watchlist film; not suitable for anything security-sensitive.
+    fn pseudo_random_index(len: usize) -> usize {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.subsec_nanos())
+            .unwrap_or(0);
+        (nanos as usize) % len
+    }
+
+    fn handle_surprise_reveal_key(&mut self, key: KeyEvent) {
+        let Some(reveal) = self.surprise_reveal.as_mut() else {
+            return;
+        };
+        match key.code {
+            crossterm::event::KeyCode::Esc => self.surprise_reveal = None,
+            // Mid-animation: any other key skips straight to the full reveal.
+            _ if reveal.ticks_elapsed < SURPRISE_REVEAL_TICKS => {
+                reveal.ticks_elapsed = SURPRISE_REVEAL_TICKS;
+            }
+            // Fully revealed: any key dismisses the overlay.
+            _ => self.surprise_reveal = None,
+        }
+    }
+
     pub fn should_perform_search(&self) -> bool {
         matches!(self.state, AppState::Search) && !self.search_query.is_empty()
     }
@@ -112,6 +409,13 @@ impl App {
                     self.movie_grid.set_loading_poster(true);
                 } else if self.selected_tab == 1 {
                     self.watchlist_grid.set_loading_poster(true);
+                } else if self.selected_tab == 3 {
+                    let focus = self.watch_party.as_ref().map(|p| p.focus).unwrap_or(0);
+                    if focus == 0 {
+                        self.movie_grid.set_loading_poster(true);
+                    } else if let Some(ref mut party) = self.watch_party {
+                        party.grid.set_loading_poster(true);
+                    }
                 }
             }
         }
@@ -126,10 +430,14 @@ impl App {
     }
 
     pub fn set_poster_result(&mut self, title: String, ascii_art: String) {
-        // Cache the poster in both grids
+        // Cache the poster in every grid that might display it
         self.movie_grid
             .set_poster_cache(title.clone(), ascii_art.clone());
-        self.watchlist_grid.set_poster_cache(title, ascii_art);
+        self.watchlist_grid
+            .set_poster_cache(title.clone(), ascii_art.clone());
+        if let Some(ref mut party) = self.watch_party {
+            party.grid.set_poster_cache(title, ascii_art);
+        }
     }
 
     pub fn get_first_movie_title(&self) -> Option<String> {
@@ -146,15 +454,54 @@ impl App {
         self.movie_grid.set_loading_poster(true);
     }
 
+    /// Selection position `(tab, index)` of whichever grid is currently
+    /// active, used to detect when the user has scrolled to a new row so
+    /// speculative poster prefetching can be kicked off for it.
+    pub fn active_grid_selection(&self) -> Option<(usize, usize)> {
+        match self.selected_tab {
+            0 => Some((0, self.movie_grid.selected_index())),
+            1 => Some((1, self.watchlist_grid.selected_index())),
+            3 => match self.watch_party {
+                Some(ref party) if party.focus == 0 => Some((0, self.movie_grid.selected_index())),
+                Some(ref party) => Some((3, party.grid.selected_index())),
+                None => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Titles of the active grid's selected row and its ±1 neighbours that
+    /// aren't already poster-cached, for speculative prefetching.
+    pub fn active_grid_prefetch_candidates(&self) -> Vec<String> {
+        match self.selected_tab {
+            0 => self.movie_grid.prefetch_candidates(),
+            1 => self.watchlist_grid.prefetch_candidates(),
+            3 => match self.watch_party {
+                Some(ref party) if party.focus == 0 => self.movie_grid.prefetch_candidates(),
+                Some(ref party) => party.grid.prefetch_candidates(),
+                None => Vec::new(),
+            },
+            _ => Vec::new(),
+        }
+    }
+
     pub fn handle_key(&mut self, key: KeyEvent) {
+        if self.surprise_reveal.is_some() {
+            self.handle_surprise_reveal_key(key);
+            return;
+        }
+
         match &self.state {
             AppState::Loaded => match key.code {
+                crossterm::event::KeyCode::Char('r') if self.selected_tab == 1 => {
+                    self.start_surprise_pick();
+                }
                 crossterm::event::KeyCode::Tab => {
-                    self.selected_tab = (self.selected_tab + 1) % 3;
+                    self.selected_tab = (self.selected_tab + 1) % self.tab_count();
                 }
                 crossterm::event::KeyCode::BackTab => {
                     self.selected_tab = if self.selected_tab == 0 {
-                        2
+                        self.tab_count() - 1
                     } else {
                         self.selected_tab - 1
                     };
@@ -162,12 +509,56 @@ impl App {
                 crossterm::event::KeyCode::Char('1') => self.selected_tab = 0,
                 crossterm::event::KeyCode::Char('2') => self.selected_tab = 1,
                 crossterm::event::KeyCode::Char('3') => self.selected_tab = 2,
+                crossterm::event::KeyCode::Char('4') if self.watch_party.is_some() => {
+                    self.selected_tab = 3;
+                }
+                crossterm::event::KeyCode::Left | crossterm::event::KeyCode::Char('h')
+                    if self.selected_tab == 3 =>
+                {
+                    if let Some(ref mut party) = self.watch_party {
+                        party.focus = 0;
+                    }
+                }
+                crossterm::event::KeyCode::Right | crossterm::event::KeyCode::Char('l')
+                    if self.selected_tab == 3 =>
+                {
+                    if let Some(ref mut party) = self.watch_party {
+                        party.focus = 1;
+                    }
+                }
                 crossterm::event::KeyCode::Char('/') => {
                     self.state = AppState::Search;
                     self.search_query.clear();
                     self.search_results.clear();
                     self.search_selected = 0;
                 }
+                crossterm::event::KeyCode::F(2) => {
+                    self.show_debug_overlay = !self.show_debug_overlay;
+                }
+                crossterm::event::KeyCode::Up | crossterm::event::KeyCode::Char('k')
+                    if self.selected_tab == 2 =>
+                {
+                    self.genre_selected = self.genre_selected.saturating_sub(1);
+                }
+                crossterm::event::KeyCode::Down | crossterm::event::KeyCode::Char('j')
+                    if self.selected_tab == 2 =>
+                {
+                    let max = self
+                        .profile
+                        .as_ref()
+                        .and_then(|p| p.enhanced_stats.as_ref())
+                        .map(|s| s.genre_breakdown.len().min(8))
+                        .unwrap_or(1);
+                    if max > 0 {
+                        self.genre_selected = (self.genre_selected + 1).min(max - 1);
+                    }
+                }
+                crossterm::event::KeyCode::PageUp if self.selected_tab == 2 => {
+                    self.stats_scroll = self.stats_scroll.saturating_sub(STATS_SCROLL_STEP);
+                }
+                crossterm::event::KeyCode::PageDown if self.selected_tab == 2 => {
+                    self.stats_scroll = self.stats_scroll.saturating_add(STATS_SCROLL_STEP);
+                }
                 _ => {
                     if self.selected_tab == 0 {
                         if let Some(action) = self.movie_grid.handle_key(key) {
@@ -177,6 +568,18 @@ impl App {
                         if let Some(action) = self.watchlist_grid.handle_key(key) {
                             self.handle_movie_grid_action(action);
                         }
+                    } else if self.selected_tab == 3 {
+                        let focus = self.watch_party.as_ref().map(|p| p.focus).unwrap_or(0);
+                        let action = if focus == 0 {
+                            self.movie_grid.handle_key(key)
+                        } else {
+                            self.watch_party
+                                .as_mut()
+                                .and_then(|party| party.grid.handle_key(key))
+                        };
+                        if let Some(action) = action {
+                            self.handle_movie_grid_action(action);
+                        }
                     }
                 }
             },
@@ -219,6 +622,11 @@ impl App {
     pub fn render(&mut self, f: &mut Frame) {
         let size = f.size();
 
+        if size.width < MIN_TERMINAL_WIDTH || size.height < MIN_TERMINAL_HEIGHT {
+            self.render_too_small(f, size);
+            return;
+        }
+
         match &self.state {
             AppState::Loading => self.render_loading(f, size),
             AppState::Loaded => self.render_main(f, size),
@@ -227,6 +635,23 @@ impl App {
         }
     }
 
+    /// Shown instead of the full layout when the terminal is smaller than
+    /// `MIN_TERMINAL_WIDTH`x`MIN_TERMINAL_HEIGHT`, so a tiny window resizes
+    /// back into a normal render rather than leaving behind a crash or
+    /// garbled output from layouts computed with zero/negative space.
+    fn render_too_small(&self, f: &mut Frame, area: Rect) {
+        let message = format!(
+            "Terminal too small\nResize to at least {}x{}",
+            MIN_TERMINAL_WIDTH, MIN_TERMINAL_HEIGHT
+        );
+
+        let paragraph = Paragraph::new(message)
+            .style(self.styles.warning_text_style())
+            .alignment(ratatui::layout::Alignment::Center);
+
+        f.render_widget(paragraph, area);
+    }
+
     fn render_loading(&self, f: &mut Frame, area: Rect) {
         let block = Block::default()
             .title(format!(" Loading Profile: {} ", self.username))
@@ -246,41 +671,86 @@ impl App {
         }
     }
 
+    /// True when the profile's header stats (e.g. total film count) are
+    /// populated but the diary itself came back empty — the signature of a
+    /// Letterboxd account with public stats but a private diary. Showing the
+    /// Movies tab and computed stats as all-zero in that case would
+    /// contradict the header, so callers use this to show an explanatory
+    /// banner instead.
+    fn diary_private(&self) -> bool {
+        self.profile
+            .as_ref()
+            .is_some_and(|p| p.total_films > 0 && p.all_movies.is_empty())
+    }
+
     fn render_main(&mut self, f: &mut Frame, area: Rect) {
+        if let Some(ref mut reveal) = self.surprise_reveal {
+            if reveal.ticks_elapsed < SURPRISE_REVEAL_TICKS {
+                reveal.ticks_elapsed += 1;
+            }
+        }
+
+        let show_diary_private_banner = self.diary_private();
+
+        let mut constraints = vec![Constraint::Length(3)]; // Header
+        if show_diary_private_banner {
+            constraints.push(Constraint::Length(3)); // Diary-private banner
+        }
+        constraints.push(Constraint::Length(3)); // Tabs
+        constraints.push(Constraint::Min(10)); // Content
+        constraints.push(Constraint::Length(1)); // Status bar
+
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(3), // Header
-                Constraint::Length(3), // Tabs
-                Constraint::Min(10),   // Content
-                Constraint::Length(1), // Status bar
-            ])
+            .constraints(constraints)
             .split(area);
 
+        let mut chunk_idx = 0;
+
         // Render header
-        self.render_header(f, chunks[0]);
+        self.render_header(f, chunks[chunk_idx]);
+        chunk_idx += 1;
+
+        if show_diary_private_banner {
+            self.render_diary_private_banner(f, chunks[chunk_idx]);
+            chunk_idx += 1;
+        }
 
         // Render tabs
-        self.render_tabs(f, chunks[1]);
+        self.render_tabs(f, chunks[chunk_idx]);
+        chunk_idx += 1;
+
+        let content_area = chunks[chunk_idx];
+        chunk_idx += 1;
+        let status_area = chunks[chunk_idx];
 
         // Render content based on selected tab
-        match self.selected_tab {
-            0 => self.movie_grid.render(f, chunks[2], &self.styles),
-            1 => self.watchlist_grid.render(f, chunks[2], &self.styles),
-            2 => self.render_statistics(f, chunks[2]),
-            _ => {}
+        if self.surprise_reveal.is_some() {
+            self.render_surprise_reveal(f, content_area);
+        } else {
+            match self.selected_tab {
+                0 => self.movie_grid.render(f, content_area, &self.styles),
+                1 => self.watchlist_grid.render(f, content_area, &self.styles),
+                2 => self.render_statistics(f, content_area),
+                3 => self.render_watch_party(f, content_area),
+                _ => {}
+            }
         }
 
         // Render status bar
-        self.render_status_bar(f, chunks[3]);
+        self.render_status_bar(f, status_area);
     }
 
     fn render_header(&self, f: &mut Frame, area: Rect) {
         if let Some(ref profile) = self.profile {
+            let headline = Self::headline_stat_text(profile);
             let title = if let Some(ref bio) = profile.bio {
-                format!(" {} (@{}) - {} ", profile.name, profile.username, bio)
+                format!(
+                    " {} (@{}) - {} | {} ",
+                    profile.name, profile.username, bio, headline
+                )
             } else {
-                format!(" {} (@{}) ", profile.name, profile.username)
+                format!(" {} (@{}) | {} ", profile.name, profile.username, headline)
             };
 
             let block = Block::default()
@@ -292,15 +762,56 @@ impl App {
         }
     }
 
+    fn render_diary_private_banner(&self, f: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(self.styles.warning_border_style());
+
+        let paragraph = Paragraph::new(
+            "⚠ Profile stats are public but the diary is private — detailed stats unavailable",
+        )
+        .style(self.styles.warning_text_style())
+        .block(block);
+
+        f.render_widget(paragraph, area);
+    }
+
+    /// Renders the configured headline stat (default: total films) for the
+    /// header bar, using values already present on `ComprehensiveProfile`.
+    fn headline_stat_text(profile: &crate::profile::ComprehensiveProfile) -> String {
+        let stat = crate::config::ConfigManager::new()
+            .and_then(|cm| cm.get_headline_stat())
+            .unwrap_or_default();
+
+        match stat {
+            crate::config::HeadlineStat::TotalFilms => {
+                format!("🎬 {} films", profile.total_films)
+            }
+            crate::config::HeadlineStat::ViewingHours => match &profile.enhanced_stats {
+                Some(stats) => format!(
+                    "⏱ {:.0}h watched",
+                    stats.basic_stats.total_viewing_time_hours
+                ),
+                None => format!("🎬 {} films", profile.total_films),
+            },
+            crate::config::HeadlineStat::FilmsThisYear => {
+                format!("📅 {} this year", profile.films_this_year)
+            }
+        }
+    }
+
     fn render_tabs(&self, f: &mut Frame, area: Rect) {
-        let tabs = ["🎬 Movies", "📝 Watchlist", "📊 Statistics"];
+        let mut tabs = vec!["🎬 Movies", "📝 Watchlist", "📊 Statistics"];
+        if self.watch_party.is_some() {
+            tabs.push("🤝 Watch Party");
+        }
+        let tab_count = tabs.len() as u32;
+        let constraints: Vec<Constraint> = (0..tab_count)
+            .map(|_| Constraint::Ratio(1, tab_count))
+            .collect();
         let chunks = Layout::default()
             .direction(Direction::Horizontal)
-            .constraints([
-                Constraint::Ratio(1, 3),
-                Constraint::Ratio(1, 3),
-                Constraint::Ratio(1, 3),
-            ])
+            .constraints(constraints)
             .split(area);
 
         for (i, tab) in tabs.iter().enumerate() {
@@ -320,28 +831,172 @@ impl App {
     }
 
     fn render_status_bar(&self, f: &mut Frame, area: Rect) {
-        let help_text =
-            "1-3: Switch tabs | ↑↓: Browse | s: Sort | p: Load poster | /: Search | q/Esc: Quit";
-        let paragraph = Paragraph::new(help_text).style(self.styles.status_bar_style());
+        let help_text = if self.watch_party.is_some() {
+            "1-4: Switch tabs | ↑↓: Browse | ←→: Switch pane (Watch Party) | PgUp/PgDn: Scroll stats | [ ]: Scroll details | s: Sort | v: Details view | c: Compact | Enter: Details (compact) | p: Load poster | r: Surprise me (Watchlist) | /: Search | F2: Debug | q/Esc: Quit"
+        } else {
+            "1-3: Switch tabs | ↑↓: Browse | PgUp/PgDn: Scroll stats | [ ]: Scroll details | s: Sort | v: Details view | c: Compact | Enter: Details (compact) | p: Load poster | r: Surprise me (Watchlist) | /: Search | F2: Debug | q/Esc: Quit"
+        };
+        let text = if self.show_debug_overlay {
+            format!(
+                "{}  |  bg tasks: {}/{}",
+                help_text, self.background_tasks_active, self.background_task_limit
+            )
+        } else {
+            help_text.to_string()
+        };
+        let paragraph = Paragraph::new(text).style(self.styles.status_bar_style());
+
+        f.render_widget(paragraph, area);
+    }
+
+    /// Renders the "Watch Party" tab: the primary diary and the comparison
+    /// user's diary side by side, sharing the same `MovieGrid` rendering as
+    /// the Movies tab. Shows the comparison user's load error instead of an
+    /// empty pane if their profile failed to fetch.
+    fn render_watch_party(&mut self, f: &mut Frame, area: Rect) {
+        let Some(ref mut party) = self.watch_party else {
+            return;
+        };
+
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(area);
+
+        self.movie_grid.render(f, chunks[0], &self.styles);
+
+        if let Some(ref error) = party.error {
+            let block = Block::default()
+                .title(format!(" {} ", party.username))
+                .borders(Borders::ALL)
+                .border_style(self.styles.error_border_style());
+
+            let paragraph = Paragraph::new(error.clone())
+                .block(block)
+                .style(self.styles.error_text_style());
+
+            f.render_widget(paragraph, chunks[1]);
+        } else {
+            party.grid.render(f, chunks[1], &self.styles);
+        }
+    }
+
+    /// Renders the "surprise me" reveal overlay: the picked film's title
+    /// types out character-by-character and its poster unblurs line-by-line
+    /// over `SURPRISE_REVEAL_TICKS` render ticks, then the controls hint
+    /// switches to "dismiss".
+    fn render_surprise_reveal(&self, f: &mut Frame, area: Rect) {
+        let Some(ref reveal) = self.surprise_reveal else {
+            return;
+        };
+        let fully_revealed = reveal.ticks_elapsed >= SURPRISE_REVEAL_TICKS;
+
+        let block = Block::default()
+            .title(" 🎲 Surprise Me ")
+            .borders(Borders::ALL)
+            .border_style(self.styles.header_border_style());
+
+        let title_text = if fully_revealed {
+            reveal.movie.title.clone()
+        } else {
+            let total_chars = reveal.movie.title.chars().count();
+            let shown =
+                total_chars * reveal.ticks_elapsed as usize / SURPRISE_REVEAL_TICKS as usize;
+            let mut typed: String = reveal.movie.title.chars().take(shown).collect();
+            typed.push('▌');
+            typed
+        };
+
+        let poster_text = match self.watchlist_grid.get_cached_poster(&reveal.movie.title) {
+            Some(ascii_art) => Self::unblur_poster_lines(ascii_art, reveal.ticks_elapsed),
+            None => "🎬 Fetching poster...".to_string(),
+        };
+
+        let footer = if fully_revealed {
+            "Press any key to dismiss"
+        } else {
+            "Press any key to reveal instantly, Esc to cancel"
+        };
+
+        let text = format!("{}\n\n{}\n\n{}", title_text, poster_text, footer);
+
+        let paragraph = Paragraph::new(text)
+            .block(block)
+            .style(self.styles.text_style())
+            .alignment(ratatui::layout::Alignment::Center);
 
         f.render_widget(paragraph, area);
     }
 
-    fn render_statistics(&self, f: &mut Frame, area: Rect) {
+    /// Replaces the bottom `lines.len() - visible` lines of `ascii_art` with
+    /// solid blur blocks, where `visible` grows with `ticks_elapsed`, so the
+    /// poster appears to unblur from the top down as the reveal progresses.
+    fn unblur_poster_lines(ascii_art: &str, ticks_elapsed: u8) -> String {
+        if ticks_elapsed >= SURPRISE_REVEAL_TICKS {
+            return ascii_art.to_string();
+        }
+
+        let lines: Vec<&str> = ascii_art.lines().collect();
+        let visible = lines.len() * ticks_elapsed as usize / SURPRISE_REVEAL_TICKS as usize;
+
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                if i < visible {
+                    line.to_string()
+                } else {
+                    "░".repeat(line.chars().count().max(1))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Renders the Statistics tab's panels (Overview/Genres/Rating
+    /// distribution) into an off-screen buffer tall enough to hold all of
+    /// them at their natural size, then blits the `stats_scroll`-offset
+    /// window of that buffer into the real viewport. This is what makes
+    /// panels that don't fit a short terminal reachable via PgUp/PgDn
+    /// instead of being silently clipped.
+    fn render_statistics(&mut self, f: &mut Frame, area: Rect) {
         if let Some(ref profile) = self.profile {
             if let Some(ref enhanced_stats) = profile.enhanced_stats {
+                const BASIC_STATS_HEIGHT: u16 = 11;
+                const GENRE_BREAKDOWN_HEIGHT: u16 = 13;
+                let rating_height = (enhanced_stats.rating_distribution.len() as u16 + 2).max(10);
+                let virtual_height = BASIC_STATS_HEIGHT + GENRE_BREAKDOWN_HEIGHT + rating_height;
+
+                let max_scroll = virtual_height.saturating_sub(area.height);
+                if self.stats_scroll > max_scroll {
+                    self.stats_scroll = max_scroll;
+                }
+
+                let virtual_area = Rect::new(0, 0, area.width, virtual_height);
+                let mut buffer = Buffer::empty(virtual_area);
+
                 let chunks = Layout::default()
                     .direction(Direction::Vertical)
                     .constraints([
-                        Constraint::Length(8),  // Basic stats
-                        Constraint::Length(12), // Genre breakdown
-                        Constraint::Min(10),    // Rating distribution
+                        Constraint::Length(BASIC_STATS_HEIGHT),
+                        Constraint::Length(GENRE_BREAKDOWN_HEIGHT),
+                        Constraint::Length(rating_height),
                     ])
-                    .split(area);
-
-                self.render_basic_stats(f, chunks[0], &enhanced_stats.basic_stats);
-                self.render_genre_breakdown(f, chunks[1], &enhanced_stats.genre_breakdown);
-                self.render_rating_distribution(f, chunks[2], &enhanced_stats.rating_distribution);
+                    .split(virtual_area);
+
+                self.render_basic_stats(&mut buffer, chunks[0], enhanced_stats);
+                self.render_genre_breakdown(
+                    &mut buffer,
+                    chunks[1],
+                    &enhanced_stats.genre_breakdown,
+                );
+                self.render_rating_distribution(
+                    &mut buffer,
+                    chunks[2],
+                    &enhanced_stats.rating_distribution,
+                );
+
+                Self::blit_scrolled(&buffer, f.buffer_mut(), area, self.stats_scroll);
             } else {
                 let block = Block::default()
                     .title(" 📊 Statistics ")
@@ -357,12 +1012,33 @@ impl App {
         }
     }
 
+    /// Copies the `scroll`-offset window of `source` into `dest` at
+    /// `dest_area`, clipping to whichever of `dest_area` or `source`'s
+    /// bounds is smaller.
+    fn blit_scrolled(source: &Buffer, dest: &mut Buffer, dest_area: Rect, scroll: u16) {
+        for y in 0..dest_area.height {
+            let src_y = scroll + y;
+            if src_y >= source.area.height {
+                break;
+            }
+            for x in 0..dest_area.width {
+                if x >= source.area.width {
+                    break;
+                }
+                let cell = source.get(x, src_y).clone();
+                *dest.get_mut(dest_area.x + x, dest_area.y + y) = cell;
+            }
+        }
+    }
+
     fn render_basic_stats(
         &self,
-        f: &mut Frame,
+        buf: &mut Buffer,
         area: Rect,
-        stats: &crate::profile::UserStatistics,
+        enhanced_stats: &crate::profile::EnhancedStatistics,
     ) {
+        let stats = &enhanced_stats.basic_stats;
+
         let block = Block::default()
             .title(" 📊 Overview ")
             .borders(Borders::ALL)
@@ -383,25 +1059,87 @@ impl App {
             format!("{:.0}m", stats.average_film_length)
         };
 
+        let pace_str = match (
+            stats.average_watches_per_week,
+            stats.projected_year_end_total,
+        ) {
+            (Some(per_week), Some(projected)) => {
+                format!(
+                    "\n📈 Pace: {:.1}/week (on track for {} films this year)",
+                    per_week, projected
+                )
+            }
+            (Some(per_week), None) => format!("\n📈 Pace: {:.1}/week", per_week),
+            _ => String::new(),
+        };
+
+        let capped_note = match enhanced_stats.capped_at {
+            Some(n) => format!("\n📼 Computed over the {} most recent entries", n),
+            None => String::new(),
+        };
+
+        let runtime_superlatives_str =
+            match (&enhanced_stats.longest_film, &enhanced_stats.shortest_film) {
+                (Some(longest), Some(shortest)) => format!(
+                    "\n🎞️  Longest: {} ({})\n⏳ Shortest: {} ({}) [of {} with known runtime]",
+                    longest.title,
+                    crate::util::format_runtime_minutes(longest.runtime_minutes),
+                    shortest.title,
+                    crate::util::format_runtime_minutes(shortest.runtime_minutes),
+                    enhanced_stats.runtime_sample_size
+                ),
+                _ => String::new(),
+            };
+
+        let contrarianness_str = match enhanced_stats.average_contrarianness {
+            Some(delta) => format!("\n🎯 Contrarianness: {:+.2} vs. Letterboxd average", delta),
+            None => String::new(),
+        };
+
+        let community_comparison_str = match &enhanced_stats.community_comparison {
+            Some(c) => format!(
+                "\n🆚 You rate {:+.1}★ vs. the average user, watch {:+.0} films/year vs. average, and lean into {} more than most (approximate)",
+                c.rating_diff, c.films_per_year_diff, c.top_genre
+            ),
+            None => String::new(),
+        };
+
         let stats_text = format!(
-            "🎬 Total Viewing Time: {}\n⏱️  Average Film Length: {}\n📊 Average Rating: {:.1}/5\n🎭 Unique Directors: {}\n🎪 Unique Genres: {}",
+            "🎬 Total Viewing Time: {}\n⏱️  Average Film Length: {}\n📊 Average Rating: {:.1}/5\n🎭 Unique Directors: {}\n🎪 Unique Genres: {}{}{}{}{}{}",
             viewing_hours_str,
             avg_length_str,
             stats.average_rating,
             stats.unique_directors_count,
-            stats.unique_genres_count
+            stats.unique_genres_count,
+            pace_str,
+            capped_note,
+            runtime_superlatives_str,
+            contrarianness_str,
+            community_comparison_str
         );
 
         let paragraph = Paragraph::new(stats_text)
             .block(block)
             .style(self.styles.stats_value_style());
 
-        f.render_widget(paragraph, area);
+        paragraph.render(area, buf);
+    }
+
+    /// Right-pads `text` with spaces to `width` *display columns*, using
+    /// `unicode-width` rather than byte/char count so wide glyphs like emoji
+    /// (which render as 2 columns) don't throw off column alignment.
+    fn pad_to_width(text: &str, width: usize) -> String {
+        let visible_width = text.width();
+        if visible_width >= width {
+            text.to_string()
+        } else {
+            format!("{}{}", text, " ".repeat(width - visible_width))
+        }
     }
 
     fn render_genre_breakdown(
         &self,
-        f: &mut Frame,
+        buf: &mut Buffer,
         area: Rect,
         genres: &[crate::profile::GenreStats],
     ) {
@@ -411,28 +1149,47 @@ impl App {
             .border_style(self.styles.stats_title_style())
             .border_type(self.styles.border_type());
 
+        let shown = genres.iter().take(8).collect::<Vec<_>>();
         let mut genre_lines = Vec::new();
-        for (_i, genre) in genres.iter().take(8).enumerate() {
+        for (i, genre) in shown.iter().enumerate() {
             let bar_length = ((genre.percentage / 100.0) * 20.0) as usize;
             let bar = "█".repeat(bar_length) + &"░".repeat(20 - bar_length);
+            let marker = if i == self.genre_selected { "▶" } else { " " };
 
             let line = format!(
-                "{:<2} {:<15} {:>5.1}% {}",
-                genre.emoji, genre.name, genre.percentage, bar
+                "{} {} {} {:>5.1}% {}",
+                marker,
+                Self::pad_to_width(&genre.emoji, 2),
+                Self::pad_to_width(&genre.name, 15),
+                genre.percentage,
+                bar
             );
             genre_lines.push(line);
         }
 
+        let detail = shown.get(self.genre_selected).map_or_else(
+            || "Select a genre for details".to_string(),
+            |genre| {
+                format!(
+                    "⭐ Avg rating: {:.1}/5  🏆 Top film: {}",
+                    genre.average_rating,
+                    genre.top_film.as_deref().unwrap_or("—")
+                )
+            },
+        );
+        genre_lines.push(String::new());
+        genre_lines.push(detail);
+
         let paragraph = Paragraph::new(genre_lines.join("\n"))
             .block(block)
             .style(self.styles.text_style());
 
-        f.render_widget(paragraph, area);
+        paragraph.render(area, buf);
     }
 
     fn render_rating_distribution(
         &self,
-        f: &mut Frame,
+        buf: &mut Buffer,
         area: Rect,
         distribution: &[crate::profile::RatingDistribution],
     ) {
@@ -447,7 +1204,7 @@ impl App {
                 .block(block)
                 .style(self.styles.dim_text_style());
 
-            f.render_widget(paragraph, area);
+            paragraph.render(area, buf);
             return;
         }
 
@@ -467,7 +1224,7 @@ impl App {
             .block(block)
             .style(self.styles.text_style());
 
-        f.render_widget(paragraph, area);
+        paragraph.render(area, buf);
     }
 
     fn render_search(&self, f: &mut Frame, area: Rect) {
@@ -1,12 +1,14 @@
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
 
 use super::AppStyles;
-use crate::profile::UserMovieEntry;
+use crate::profile::{DetailedMovie, UserMovieEntry};
+use std::collections::HashMap;
 
 pub struct MovieGrid {
     movies: Vec<UserMovieEntry>,
@@ -15,14 +17,63 @@ pub struct MovieGrid {
     sort_by: SortMode,
     poster_cache: std::collections::HashMap<String, String>, // title -> ascii art
     loading_poster: bool,
+    /// Set when the profile was loaded with `--no-enrich`, so rating fields
+    /// are expected to be empty rather than missing due to an error.
+    pub ratings_unavailable: bool,
+    /// Predicted-interest score and "because you liked X" rationale per movie
+    /// title, used by `SortMode::Recommended`. Populated for the watchlist
+    /// grid from the user's genre/director taste profile.
+    recommendations: HashMap<String, (f32, String)>,
+    /// Vertical scroll offset into the details pane, for movies with reviews
+    /// too long to fit on screen. Reset whenever the selection changes.
+    details_scroll: u16,
+    /// Which layout the details pane uses, cycled with `v`. Restored from and
+    /// persisted to config so it survives across `browse` sessions.
+    details_view_mode: crate::config::DetailsViewMode,
+    /// List-only layout with the details pane hidden, auto-enabled below
+    /// [`COMPACT_WIDTH_THRESHOLD`] and toggled manually with `c`. Needed on
+    /// narrow terminals (a phone SSH client, a small tmux pane) where the
+    /// normal two-pane layout doesn't fit.
+    compact: bool,
+    /// Once `c` is pressed, stop auto-adjusting `compact` from terminal
+    /// width so the manual choice sticks for the rest of the session.
+    compact_manual: bool,
+    /// In compact mode, shows the details pane full-screen instead of the
+    /// list, toggled with Enter — the only way to reach movie details when
+    /// the two-pane layout is hidden.
+    fullscreen_detail: bool,
+    /// Titles also present in the other user's diary, for the "watch party"
+    /// side-by-side comparison (`lbxd browse alice bob`). Highlighted in the
+    /// list; empty outside that mode.
+    shared_titles: std::collections::HashSet<String>,
+    /// Set by `f` until the following digit key is read, so `1`-`3` keep
+    /// working as tab switches everywhere else. `f` then a digit `1`-`5`
+    /// filters to films rated at least that many stars; `f0` clears it.
+    filter_pending: bool,
+    /// Minimum star rating currently filtering `movies`, shown in the title.
+    rating_filter: Option<f32>,
+    /// Full list as it was before `rating_filter` was applied, restored when
+    /// the filter is cleared.
+    unfiltered_movies: Option<Vec<UserMovieEntry>>,
 }
 
+/// Terminal columns below which the two-pane layout stops being usable and
+/// compact (list-only) mode kicks in automatically.
+const COMPACT_WIDTH_THRESHOLD: u16 = 100;
+
 #[derive(Debug, Clone, Copy)]
 pub enum SortMode {
     Date,
     Rating,
     Title,
     Year,
+    Runtime,
+    Recommended,
+    /// Pass-through order, not reachable via the `s` cycle key. rustboxd
+    /// doesn't expose a watchlist "date added," so this just preserves
+    /// whatever (now-stabilized) order the movies were loaded in — used as
+    /// the `added` value of `--watchlist-sort-by`.
+    Added,
 }
 
 #[derive(Debug, Clone)]
@@ -48,18 +99,123 @@ impl MovieGrid {
             sort_by: SortMode::Date,
             poster_cache: std::collections::HashMap::new(),
             loading_poster: false,
+            ratings_unavailable: false,
+            recommendations: HashMap::new(),
+            details_scroll: 0,
+            details_view_mode: crate::config::ConfigManager::new()
+                .and_then(|cm| cm.get_details_view_mode())
+                .unwrap_or_default(),
+            compact: false,
+            compact_manual: false,
+            fullscreen_detail: false,
+            shared_titles: std::collections::HashSet::new(),
+            filter_pending: false,
+            rating_filter: None,
+            unfiltered_movies: None,
         }
     }
 
+    /// The active minimum-rating filter, if any, for display in the title.
+    pub fn rating_filter(&self) -> Option<f32> {
+        self.rating_filter
+    }
+
+    /// Whether Enter is currently showing the details pane full-screen.
+    /// Used by the main loop to route Esc to this grid (to close the
+    /// overlay) instead of treating it as "quit the app".
+    pub fn is_fullscreen_detail(&self) -> bool {
+        self.fullscreen_detail
+    }
+
     pub fn set_movies(&mut self, mut movies: Vec<UserMovieEntry>) {
         self.sort_movies(&mut movies);
         self.movies = movies;
         self.selected = 0;
+        self.details_scroll = 0;
         self.state.select(Some(0));
+        self.rating_filter = None;
+        self.unfiltered_movies = None;
+    }
+
+    /// The full set of loaded movies, ignoring any active rating filter —
+    /// what the background enrichment task should walk, since a row hidden
+    /// by `f`-filtering right now may still be browsed to later.
+    pub fn all_loaded_movies(&self) -> Vec<UserMovieEntry> {
+        self.unfiltered_movies
+            .as_ref()
+            .unwrap_or(&self.movies)
+            .clone()
+    }
+
+    /// Patches every loaded row for `letterboxd_url` with freshly-fetched
+    /// `movie` data, as rows stream in from the background enrichment task.
+    /// Updates both the live and (if a rating filter is active) the
+    /// unfiltered backing list, since the row may be hidden by the filter
+    /// right now but should still show correct data once it's cleared.
+    pub fn apply_enrichment(&mut self, letterboxd_url: &str, movie: DetailedMovie) {
+        for entry in self.movies.iter_mut() {
+            if entry.movie.letterboxd_url == letterboxd_url {
+                entry.movie = movie.clone();
+            }
+        }
+        if let Some(unfiltered) = self.unfiltered_movies.as_mut() {
+            for entry in unfiltered.iter_mut() {
+                if entry.movie.letterboxd_url == letterboxd_url {
+                    entry.movie = movie.clone();
+                }
+            }
+        }
+    }
+
+    /// All movie titles currently loaded, for computing the shared set
+    /// between two grids in "watch party" mode.
+    pub fn titles(&self) -> std::collections::HashSet<String> {
+        self.movies.iter().map(|m| m.movie.title.clone()).collect()
+    }
+
+    /// Marks which titles should be highlighted as shared with another
+    /// user's diary, for "watch party" mode.
+    pub fn set_shared_titles(&mut self, shared_titles: std::collections::HashSet<String>) {
+        self.shared_titles = shared_titles;
+    }
+
+    /// Sets the predicted-interest scores and rationale used by
+    /// `SortMode::Recommended`, and switches to that sort mode.
+    pub fn set_recommendations(&mut self, recommendations: HashMap<String, (f32, String)>) {
+        self.recommendations = recommendations;
+        self.sort_by = SortMode::Recommended;
+        let mut movies = self.movies.clone();
+        self.sort_movies(&mut movies);
+        self.movies = movies;
+    }
+
+    /// Switches to the given sort mode and re-sorts in place. Used to apply
+    /// `--watchlist-sort-by` when the TUI first loads.
+    pub fn set_sort_mode(&mut self, mode: SortMode) {
+        self.sort_by = mode;
+        let mut movies = self.movies.clone();
+        self.sort_movies(&mut movies);
+        self.movies = movies;
     }
 
     pub fn handle_key(&mut self, key: KeyEvent) -> Option<MovieGridAction> {
+        if self.filter_pending {
+            self.filter_pending = false;
+            match key.code {
+                KeyCode::Char('0') => self.clear_rating_filter(),
+                KeyCode::Char(c @ '1'..='5') => {
+                    self.apply_rating_filter(c.to_digit(10).unwrap() as f32);
+                }
+                _ => {}
+            }
+            return None;
+        }
+
         match key.code {
+            KeyCode::Char('f') => {
+                self.filter_pending = true;
+                None
+            }
             KeyCode::Up | KeyCode::Char('k') => {
                 self.previous();
                 None
@@ -84,10 +240,40 @@ impl MovieGrid {
                 self.go_to_bottom();
                 None
             }
+            KeyCode::Char('[') => {
+                self.details_scroll = self.details_scroll.saturating_sub(3);
+                None
+            }
+            KeyCode::Char(']') => {
+                self.details_scroll = self.details_scroll.saturating_add(3);
+                None
+            }
             KeyCode::Char('s') => {
                 self.cycle_sort();
                 None
             }
+            KeyCode::Char('v') => {
+                self.cycle_details_view_mode();
+                None
+            }
+            KeyCode::Char('c') => {
+                self.compact_manual = true;
+                self.compact = !self.compact;
+                if !self.compact {
+                    self.fullscreen_detail = false;
+                }
+                None
+            }
+            KeyCode::Enter => {
+                if self.compact {
+                    self.fullscreen_detail = !self.fullscreen_detail;
+                }
+                None
+            }
+            KeyCode::Esc if self.fullscreen_detail => {
+                self.fullscreen_detail = false;
+                None
+            }
             KeyCode::Char('p') | KeyCode::Char('P') => {
                 if let Some(movie) = self.movies.get(self.selected) {
                     Some(MovieGridAction::LoadPoster(movie.movie.title.clone()))
@@ -110,6 +296,7 @@ impl MovieGrid {
             self.selected - 1
         };
         self.state.select(Some(self.selected));
+        self.details_scroll = 0;
     }
 
     fn next(&mut self) {
@@ -119,6 +306,7 @@ impl MovieGrid {
 
         self.selected = (self.selected + 1) % self.movies.len();
         self.state.select(Some(self.selected));
+        self.details_scroll = 0;
     }
 
     fn page_up(&mut self) {
@@ -128,6 +316,7 @@ impl MovieGrid {
 
         self.selected = self.selected.saturating_sub(10);
         self.state.select(Some(self.selected));
+        self.details_scroll = 0;
     }
 
     fn page_down(&mut self) {
@@ -137,12 +326,14 @@ impl MovieGrid {
 
         self.selected = std::cmp::min(self.selected + 10, self.movies.len() - 1);
         self.state.select(Some(self.selected));
+        self.details_scroll = 0;
     }
 
     fn go_to_top(&mut self) {
         if !self.movies.is_empty() {
             self.selected = 0;
             self.state.select(Some(0));
+            self.details_scroll = 0;
         }
     }
 
@@ -150,6 +341,7 @@ impl MovieGrid {
         if !self.movies.is_empty() {
             self.selected = self.movies.len() - 1;
             self.state.select(Some(self.selected));
+            self.details_scroll = 0;
         }
     }
 
@@ -158,7 +350,17 @@ impl MovieGrid {
             SortMode::Date => SortMode::Rating,
             SortMode::Rating => SortMode::Title,
             SortMode::Title => SortMode::Year,
-            SortMode::Year => SortMode::Date,
+            SortMode::Year => SortMode::Runtime,
+            SortMode::Runtime => {
+                if self.recommendations.is_empty() {
+                    SortMode::Date
+                } else {
+                    SortMode::Recommended
+                }
+            }
+            // `Added` isn't part of the cycle (there's nothing meaningful to
+            // cycle to "after" a pass-through order besides back to Date).
+            SortMode::Recommended | SortMode::Added => SortMode::Date,
         };
 
         let mut movies = self.movies.clone();
@@ -166,6 +368,53 @@ impl MovieGrid {
         self.movies = movies;
     }
 
+    /// Filters the list to films rated at least `min_rating` stars, backing
+    /// up the unfiltered list on first use so repeated `f`+digit presses
+    /// re-filter from the full set rather than compounding.
+    fn apply_rating_filter(&mut self, min_rating: f32) {
+        let source = self
+            .unfiltered_movies
+            .get_or_insert_with(|| self.movies.clone());
+        let mut filtered: Vec<UserMovieEntry> = source
+            .iter()
+            .filter(|m| m.user_rating.is_some_and(|r| r >= min_rating))
+            .cloned()
+            .collect();
+        self.sort_movies(&mut filtered);
+        self.movies = filtered;
+        self.rating_filter = Some(min_rating);
+        self.selected = 0;
+        self.details_scroll = 0;
+        self.state.select(Some(0));
+    }
+
+    /// Restores the full list saved by [`Self::apply_rating_filter`].
+    fn clear_rating_filter(&mut self) {
+        if let Some(movies) = self.unfiltered_movies.take() {
+            self.movies = movies;
+            self.rating_filter = None;
+            self.selected = 0;
+            self.details_scroll = 0;
+            self.state.select(Some(0));
+        }
+    }
+
+    /// Cycles the details pane between "full details," "poster only," and
+    /// "review only," persisting the choice so it's restored on the next `browse`.
+    fn cycle_details_view_mode(&mut self) {
+        use crate::config::DetailsViewMode;
+
+        self.details_view_mode = match self.details_view_mode {
+            DetailsViewMode::Full => DetailsViewMode::PosterOnly,
+            DetailsViewMode::PosterOnly => DetailsViewMode::ReviewOnly,
+            DetailsViewMode::ReviewOnly => DetailsViewMode::Full,
+        };
+
+        if let Ok(cm) = crate::config::ConfigManager::new() {
+            let _ = cm.set_details_view_mode(self.details_view_mode);
+        }
+    }
+
     fn sort_movies(&self, movies: &mut Vec<UserMovieEntry>) {
         match self.sort_by {
             SortMode::Date => {
@@ -185,12 +434,61 @@ impl MovieGrid {
                 movies.sort_by(|a, b| a.movie.title.cmp(&b.movie.title));
             }
             SortMode::Year => {
-                movies.sort_by(|a, b| b.movie.year.cmp(&a.movie.year));
+                // Newest first; unenriched films (no OMDB year yet) sort to the end
+                // rather than jumping to the front the way `None < Some` normally would.
+                movies.sort_by(|a, b| match (a.movie.year, b.movie.year) {
+                    (Some(ya), Some(yb)) => yb.cmp(&ya),
+                    (Some(_), None) => std::cmp::Ordering::Less,
+                    (None, Some(_)) => std::cmp::Ordering::Greater,
+                    (None, None) => std::cmp::Ordering::Equal,
+                });
+            }
+            SortMode::Runtime => {
+                // Shortest first; unenriched films sort to the end, same as Year.
+                movies.sort_by(|a, b| match (a.movie.runtime, b.movie.runtime) {
+                    (Some(ra), Some(rb)) => ra.cmp(&rb),
+                    (Some(_), None) => std::cmp::Ordering::Less,
+                    (None, Some(_)) => std::cmp::Ordering::Greater,
+                    (None, None) => std::cmp::Ordering::Equal,
+                });
+            }
+            SortMode::Added => {
+                // No-op: the order handed in is already the stabilized load order.
+            }
+            SortMode::Recommended => {
+                movies.sort_by(|a, b| {
+                    let a_score = self
+                        .recommendations
+                        .get(&a.movie.title)
+                        .map_or(0.0, |r| r.0);
+                    let b_score = self
+                        .recommendations
+                        .get(&b.movie.title)
+                        .map_or(0.0, |r| r.0);
+                    b_score
+                        .partial_cmp(&a_score)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                });
             }
         }
     }
 
     pub fn render(&mut self, f: &mut Frame, area: Rect, styles: &AppStyles) {
+        if !self.compact_manual {
+            self.compact = area.width < COMPACT_WIDTH_THRESHOLD;
+        }
+
+        if self.compact {
+            if self.fullscreen_detail {
+                self.render_movie_details(f, area, styles);
+            } else {
+                self.render_movie_list(f, area, styles);
+            }
+            return;
+        }
+
+        self.fullscreen_detail = false;
+
         let chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
@@ -209,24 +507,60 @@ impl MovieGrid {
             SortMode::Rating => "⭐ Rating",
             SortMode::Title => "🎬 Title",
             SortMode::Year => "📆 Year",
+            SortMode::Runtime => "⏱ Runtime",
+            SortMode::Recommended => "🔮 Recommended",
+            SortMode::Added => "📥 Added",
+        };
+
+        let filter_indicator = self
+            .rating_filter
+            .map(|r| format!(" [≥{:.0}★, f0 to clear]", r))
+            .unwrap_or_default();
+
+        let title = if self.compact {
+            format!(
+                " Movies (Sorted by {}){} — Enter: details ",
+                sort_indicator, filter_indicator
+            )
+        } else {
+            format!(
+                " Movies (Sorted by {}){} ",
+                sort_indicator, filter_indicator
+            )
         };
 
-        let title = format!(" Movies (Sorted by {}) ", sort_indicator);
+        // The other five columns, the inter-column spaces, the list's own
+        // border, and the "▶ " highlight symbol all take a fixed amount of
+        // width; whatever's left goes to the title column, so it grows or
+        // shrinks with the terminal instead of wasting or running out of space.
+        const OTHER_COLUMNS_WIDTH: usize = 12 + 6 + 8 + 8 + 10 + 8;
+        const CHROME_WIDTH: usize = 2 /* borders */ + 2 /* highlight symbol */ + 6 /* inter-column spaces */;
+        let title_width = (area.width as usize)
+            .saturating_sub(OTHER_COLUMNS_WIDTH + CHROME_WIDTH)
+            .max(10);
 
         let items: Vec<ListItem> = self
             .movies
             .iter()
             .map(|entry| {
-                // Column 1: Title (truncated to fit)
-                let title = if entry.movie.title.len() > 33 {
-                    format!("{}...", &entry.movie.title[..30])
+                let is_shared = self.shared_titles.contains(&entry.movie.title);
+
+                // Column 1: Title (truncated to fit), marked if shared with
+                // the other user's diary in "watch party" mode and/or logged
+                // more than once on the same day (see `same_day_rewatch_count`).
+                let mut title_raw = if is_shared {
+                    format!("🤝 {}", entry.movie.title)
                 } else {
                     entry.movie.title.clone()
                 };
+                if entry.same_day_rewatch_count > 1 {
+                    title_raw = format!("{} ×{}", title_raw, entry.same_day_rewatch_count);
+                }
+                let title = crate::util::truncate_display_text(&title_raw, title_width);
 
                 // Column 2: Date watched
                 let watched_date = if let Some(date) = entry.watched_date {
-                    date.format("%Y-%m-%d").to_string()
+                    crate::util::format_date(&date)
                 } else {
                     "-".to_string()
                 };
@@ -259,26 +593,48 @@ impl MovieGrid {
                     "-".to_string()
                 };
 
+                // Column 7: Personal rating vs. Letterboxd average delta
+                let delta = match (entry.user_rating, entry.movie.letterboxd_rating) {
+                    (Some(user_rating), Some(letterboxd_rating)) => {
+                        Some(user_rating - letterboxd_rating)
+                    }
+                    _ => None,
+                };
+                let delta_text = match delta {
+                    Some(d) => format!("{:+.1}", d),
+                    None => "-".to_string(),
+                };
+
                 // Format as columns with consistent spacing
                 let line = format!(
-                    "{:<35} {:<12} {:<6} {:<8} {:<10} {:<8}",
+                    "{:<title_width$} {:<12} {:<6} {:<8} {:<10} {:<8} ",
                     title, watched_date, release_year, letterboxd_rating, imdb_rating, rt_rating
                 );
 
-                let style = if let Some(rating) = entry.user_rating {
+                let style = if is_shared {
+                    styles.shared_entry_style()
+                } else if let Some(rating) = entry.user_rating {
                     styles.rating_style(rating)
                 } else {
                     styles.text_style()
                 };
 
-                ListItem::new(line).style(style)
+                let delta_style = match delta {
+                    Some(d) => styles.delta_style(d),
+                    None => styles.dim_text_style(),
+                };
+
+                ListItem::new(Line::from(vec![
+                    Span::styled(line, style),
+                    Span::styled(format!("{:<8}", delta_text), delta_style),
+                ]))
             })
             .collect();
 
         // Add header row
         let header = format!(
-            "{:<35} {:<12} {:<6} {:<8} {:<10} {:<8}",
-            "Title", "Watched", "Year", "LB", "IMDb", "RT"
+            "{:<title_width$} {:<12} {:<6} {:<8} {:<10} {:<8} {:<8}",
+            "Title", "Watched", "Year", "LB", "IMDb", "RT", "Δ"
         );
 
         let mut all_items = vec![ListItem::new(header).style(styles.header_style())];
@@ -303,17 +659,55 @@ impl MovieGrid {
     }
 
     fn render_movie_details(&self, f: &mut Frame, area: Rect, styles: &AppStyles) {
-        // Split the details area to show poster and details side by side
-        let detail_chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(20), // Poster area
-                Constraint::Min(10),    // Details area
-            ])
-            .split(area);
+        use crate::config::DetailsViewMode;
+
+        match self.details_view_mode {
+            DetailsViewMode::Full => {
+                // Split the details area to show poster and details side by side
+                let detail_chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([
+                        Constraint::Length(20), // Poster area
+                        Constraint::Min(10),    // Details area
+                    ])
+                    .split(area);
+
+                self.render_movie_poster(f, detail_chunks[0], styles);
+                self.render_movie_info(f, detail_chunks[1], styles);
+            }
+            DetailsViewMode::PosterOnly => {
+                self.render_movie_poster(f, area, styles);
+            }
+            DetailsViewMode::ReviewOnly => {
+                self.render_movie_review(f, area, styles);
+            }
+        }
+    }
+
+    /// Shows just the selected movie's review, for small terminals where the
+    /// combined poster+details view is too cramped to read a long review.
+    fn render_movie_review(&self, f: &mut Frame, area: Rect, styles: &AppStyles) {
+        let block = Block::default()
+            .title(" Review ")
+            .borders(Borders::ALL)
+            .border_style(styles.border_style())
+            .border_type(styles.border_type());
 
-        self.render_movie_poster(f, detail_chunks[0], styles);
-        self.render_movie_info(f, detail_chunks[1], styles);
+        let text = match self.movies.get(self.selected) {
+            Some(entry) => match entry.review.as_ref() {
+                Some(review) => format!("{}\n\n{}", entry.movie.title, review),
+                None => format!("{}\n\n(no review)", entry.movie.title),
+            },
+            None => "No movie selected".to_string(),
+        };
+
+        let paragraph = Paragraph::new(text)
+            .block(block)
+            .wrap(Wrap { trim: true })
+            .scroll((self.details_scroll, 0))
+            .style(styles.text_style());
+
+        f.render_widget(paragraph, area);
     }
 
     fn render_movie_poster(&self, f: &mut Frame, area: Rect, styles: &AppStyles) {
@@ -366,12 +760,20 @@ impl MovieGrid {
                 details.push(format!("Your Rating: ⭐ {:.1}/5", rating));
             }
 
+            if let Some((score, rationale)) = self.recommendations.get(&entry.movie.title) {
+                details.push(format!("Predicted interest: {:.0}% — {}", score, rationale));
+            }
+
             // Letterboxd Rating
             if let Some(rating) = entry.movie.letterboxd_rating {
                 details.push(format!("Letterboxd Rating: ⭐ {:.2}/5", rating));
             }
 
             // OMDB Ratings
+            let has_omdb_rating = entry.movie.imdb_rating.is_some()
+                || entry.movie.rotten_tomatoes_rating.is_some()
+                || entry.movie.metacritic_rating.is_some();
+
             if let Some(rating) = entry.movie.imdb_rating {
                 details.push(format!("IMDb Rating: ⭐ {:.1}/10", rating));
             }
@@ -384,6 +786,10 @@ impl MovieGrid {
                 details.push(format!("Metacritic: 📊 {}/100", rating));
             }
 
+            if !has_omdb_rating && self.ratings_unavailable {
+                details.push("IMDb/RT/Metacritic: unavailable (--no-enrich)".to_string());
+            }
+
             // Director
             if let Some(ref director) = entry.movie.director {
                 details.push(format!("Director: {}", director));
@@ -412,7 +818,10 @@ impl MovieGrid {
 
             // Watch date
             if let Some(date) = entry.watched_date {
-                details.push(format!("Watched: {}", date.format("%B %d, %Y")));
+                details.push(format!(
+                    "Watched: {}",
+                    crate::util::format_watch_date(&date)
+                ));
             }
 
             // Plot/Synopsis (prefer OMDB plot over synopsis)
@@ -443,6 +852,7 @@ impl MovieGrid {
             let paragraph = Paragraph::new(text)
                 .block(block)
                 .wrap(Wrap { trim: true })
+                .scroll((self.details_scroll, 0))
                 .style(styles.text_style());
 
             f.render_widget(paragraph, area);
@@ -484,4 +894,38 @@ impl MovieGrid {
     pub fn set_loading_poster(&mut self, loading: bool) {
         self.loading_poster = loading;
     }
+
+    /// Index of the currently-selected row, used to detect selection changes
+    /// for speculative poster prefetching.
+    pub fn selected_index(&self) -> usize {
+        self.selected
+    }
+
+    pub fn is_poster_cached(&self, title: &str) -> bool {
+        self.poster_cache.contains_key(title)
+    }
+
+    /// The cached ASCII poster art for `title`, if it's been fetched yet.
+    pub fn get_cached_poster(&self, title: &str) -> Option<&str> {
+        self.poster_cache.get(title).map(|s| s.as_str())
+    }
+
+    /// Titles of the selected row and its immediate neighbours (±1), skipping
+    /// anything already cached. Used to speculatively prefetch poster info
+    /// for entries the user is likely to scroll to next.
+    pub fn prefetch_candidates(&self) -> Vec<String> {
+        let indices = [
+            self.selected.checked_sub(1),
+            Some(self.selected),
+            Some(self.selected + 1),
+        ];
+
+        indices
+            .into_iter()
+            .flatten()
+            .filter_map(|i| self.movies.get(i))
+            .map(|entry| entry.movie.title.clone())
+            .filter(|title| !self.is_poster_cached(title))
+            .collect()
+    }
 }
@@ -1,5 +1,6 @@
 use anyhow::Result;
 use crossterm::{
+    cursor::Show,
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
@@ -9,7 +10,9 @@ use ratatui::{
     Terminal,
 };
 use std::io;
-use tokio::sync::mpsc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::sync::{mpsc, Semaphore};
 
 pub mod app;
 pub mod grid;
@@ -21,18 +24,57 @@ pub use grid::*;
 pub use progress::*;
 pub use styles::*;
 
-use crate::profile::{ComprehensiveProfile, LoadingProgress};
+use crate::profile::{ComprehensiveProfile, EnrichmentUpdate, LoadingProgress};
 
 pub async fn run_tui(username: &str) -> Result<()> {
+    run_tui_with_options(username, None, false, false, None, None).await
+}
+
+/// Restores the terminal (raw mode, alternate screen, cursor) on drop rather
+/// than via explicit cleanup code, so the terminal is left in a sane state
+/// even if `run_tui_with_options`'s future is cancelled mid-flight — e.g. by
+/// a `--timeout` firing in `tokio::time::timeout`, which drops the future
+/// without giving it a chance to run any more `.await`s.
+struct TerminalGuard;
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+        let _ = execute!(
+            io::stdout(),
+            LeaveAlternateScreen,
+            DisableMouseCapture,
+            Show
+        );
+    }
+}
+
+/// Like [`run_tui`], but allows skipping the OMDB enrichment pass for a faster load,
+/// opening the watchlist tab pre-sorted by predicted interest or a given `SortMode`,
+/// capping how many of the most recent diary entries are loaded (`None` falls
+/// back to the configured `max_diary_entries` default), and comparing against a
+/// second user's diary in a "Watch Party" tab (`browse alice bob`).
+pub async fn run_tui_with_options(
+    username: &str,
+    compare_username: Option<String>,
+    skip_enrich: bool,
+    recommend: bool,
+    max_diary_entries: Option<u32>,
+    watchlist_sort_by: Option<SortMode>,
+) -> Result<()> {
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    let _terminal_guard = TerminalGuard;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     // Create app state
     let mut app = App::new(username.to_string());
+    app.enrichment_skipped = skip_enrich;
+    app.recommend_watchlist = recommend;
+    app.watchlist_sort_by = watchlist_sort_by;
 
     // Create channels for progress updates
     let (progress_tx, progress_rx) = mpsc::unbounded_channel::<LoadingProgress>();
@@ -44,7 +86,13 @@ pub async fn run_tui(username: &str) -> Result<()> {
             match crate::letterboxd_client_rust::LetterboxdClient::new() {
                 Ok(client) => {
                     client
-                        .get_comprehensive_profile(&username_clone, Some(progress_tx))
+                        .get_comprehensive_profile_with_options(
+                            &username_clone,
+                            Some(progress_tx),
+                            skip_enrich,
+                            max_diary_entries,
+                            false,
+                        )
                         .await
                 }
                 Err(e) => Err(e),
@@ -52,19 +100,157 @@ pub async fn run_tui(username: &str) -> Result<()> {
         })
     });
 
-    // Run the UI
-    let res = run_ui(&mut terminal, &mut app, progress_rx, scraper_handle).await;
+    // Start the comparison user's profile loading concurrently, if given. No
+    // progress channel: the loading screen only ever reflects the primary
+    // user's progress, and this fetch is expected to finish around the same
+    // time.
+    let compare_handle = compare_username.map(|compare_username| {
+        let compare_username_clone = compare_username.clone();
+        let handle = tokio::task::spawn_blocking(move || {
+            tokio::runtime::Handle::current().block_on(async move {
+                match crate::letterboxd_client_rust::LetterboxdClient::new() {
+                    Ok(client) => {
+                        client
+                            .get_comprehensive_profile_with_options(
+                                &compare_username_clone,
+                                None,
+                                skip_enrich,
+                                max_diary_entries,
+                                false,
+                            )
+                            .await
+                    }
+                    Err(e) => Err(e),
+                }
+            })
+        });
+        (compare_username, handle)
+    });
+
+    // Run the UI; terminal cleanup happens via `_terminal_guard`'s `Drop` impl below.
+    run_ui(
+        &mut terminal,
+        &mut app,
+        progress_rx,
+        scraper_handle,
+        compare_handle,
+    )
+    .await
+}
+
+/// Gets human-readable poster info for a movie title from TMDB. Shared by the
+/// on-demand (`p`) load and the speculative prefetcher below so both paths
+/// show identical results once cached.
+async fn fetch_poster_info(title: &str) -> String {
+    let tmdb_client = crate::tmdb::TMDBClient::new();
+
+    match tmdb_client.search_movie(title).await {
+        Ok(Some(movie)) => {
+            if let Some(ref poster_path) = movie.poster_path {
+                let poster_url = tmdb_client.get_poster_url(poster_path);
+                format!("🎬 Poster for {}\n\n[Development Mode]\nPoster URL:\n{}\n\nTMDB ID: {}\nRelease: {}",
+                    title,
+                    poster_url,
+                    movie.id,
+                    movie.release_date.as_deref().unwrap_or("Unknown")
+                )
+            } else {
+                format!(
+                    "🎬 No poster found for {}\n\n[Development Mode]\nNo poster available on TMDB",
+                    title
+                )
+            }
+        }
+        _ => format!(
+            "🎬 Movie not found: {}\n\n[Development Mode]\nTMDB search failed",
+            title
+        ),
+    }
+}
+
+/// Bounds how many background network tasks (poster prefetch, OMDB
+/// enrichment) may run at once, so rapid scrolling or pagination doesn't
+/// fire off a thundering herd of simultaneous requests against TMDB/OMDB.
+/// Shared by cloning between the prefetcher and the enrichment task; the
+/// active count backs the TUI's debug overlay.
+#[derive(Clone)]
+struct BackgroundTaskPool {
+    semaphore: Arc<Semaphore>,
+    active: Arc<AtomicUsize>,
+}
+
+impl BackgroundTaskPool {
+    fn new(limit: usize) -> Self {
+        Self {
+            semaphore: Arc::new(Semaphore::new(limit.max(1))),
+            active: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    fn active_count(&self) -> usize {
+        self.active.load(Ordering::Relaxed)
+    }
+
+    /// Waits for a free slot, then runs `task`, tracking it in
+    /// `active_count` for the duration. Intended to be driven via
+    /// `tokio::spawn(pool.clone().run(task))` so the wait itself doesn't
+    /// block the caller.
+    async fn run<F>(self, task: F)
+    where
+        F: std::future::Future<Output = ()>,
+    {
+        let _permit = self.semaphore.acquire().await;
+        self.active.fetch_add(1, Ordering::Relaxed);
+        task.await;
+        self.active.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Speculatively fetches poster info for the selected grid row and its ±1
+/// neighbours in the background, so it's already cached by the time the user
+/// scrolls there. Bounded to the handful of tasks spawned per selection
+/// change; moving the selection again aborts any still-running tasks from
+/// the previous one rather than letting stale fetches pile up.
+struct PosterPrefetcher {
+    inflight: Vec<tokio::task::JoinHandle<()>>,
+    result_tx: mpsc::UnboundedSender<(String, String)>,
+    result_rx: mpsc::UnboundedReceiver<(String, String)>,
+    pool: BackgroundTaskPool,
+}
+
+impl PosterPrefetcher {
+    fn new(pool: BackgroundTaskPool) -> Self {
+        let (result_tx, result_rx) = mpsc::unbounded_channel();
+        Self {
+            inflight: Vec::new(),
+            result_tx,
+            result_rx,
+            pool,
+        }
+    }
 
-    // Restore terminal
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
+    /// Cancels any prefetches still running from a previous selection and
+    /// kicks off fresh ones for `titles`, each bounded by `self.pool`.
+    fn prefetch(&mut self, titles: Vec<String>) {
+        for handle in self.inflight.drain(..) {
+            handle.abort();
+        }
+
+        for title in titles {
+            let tx = self.result_tx.clone();
+            let pool = self.pool.clone();
+            self.inflight.push(tokio::spawn(pool.run(async move {
+                let info = fetch_poster_info(&title).await;
+                let _ = tx.send((title, info));
+            })));
+        }
+    }
 
-    res
+    fn drain_results(&mut self, app: &mut App) {
+        while let Ok((title, info)) = self.result_rx.try_recv() {
+            app.set_poster_result(title, info);
+        }
+    }
 }
 
 async fn run_ui<B: Backend>(
@@ -72,9 +258,16 @@ async fn run_ui<B: Backend>(
     app: &mut App,
     mut progress_rx: mpsc::UnboundedReceiver<LoadingProgress>,
     mut scraper_handle: tokio::task::JoinHandle<Result<ComprehensiveProfile>>,
+    mut compare_handle: Option<(
+        String,
+        tokio::task::JoinHandle<Result<ComprehensiveProfile>>,
+    )>,
 ) -> Result<()> {
     // Show loading screen while scraper is running
     let mut scraper_complete = false;
+    // No comparison user requested counts as already "complete" so it never
+    // blocks the primary loading screen from finishing.
+    let mut compare_complete = compare_handle.is_none();
 
     loop {
         // Check if scraper is done
@@ -114,11 +307,45 @@ async fn run_ui<B: Backend>(
             }
         }
 
+        // Poll the comparison user's scraper handle, if any, the same way.
+        if !compare_complete {
+            if let Some((compare_username, handle)) = compare_handle.as_mut() {
+                match tokio::time::timeout(tokio::time::Duration::from_millis(10), handle).await {
+                    Ok(task_result) => {
+                        compare_complete = true;
+                        match task_result {
+                            Ok(Ok(profile)) => {
+                                app.set_watch_party_profile(compare_username.clone(), profile);
+                            }
+                            Ok(Err(e)) => {
+                                app.set_watch_party_error(
+                                    compare_username.clone(),
+                                    format!("Failed to load profile: {}", e),
+                                );
+                            }
+                            Err(e) => {
+                                app.set_watch_party_error(
+                                    compare_username.clone(),
+                                    format!("Task failed: {}", e),
+                                );
+                            }
+                        }
+                    }
+                    Err(_) => {
+                        // Timeout - still running, continue with loading UI
+                    }
+                }
+            }
+        }
+
         // Always draw the UI (will show loading screen if not complete)
         terminal.draw(|f| app.render(f))?;
 
         // If loading is complete, continue with main UI loop
-        if scraper_complete && !matches!(app.state, crate::tui::AppState::Loading) {
+        if scraper_complete
+            && compare_complete
+            && !matches!(app.state, crate::tui::AppState::Loading)
+        {
             break;
         }
 
@@ -138,40 +365,56 @@ async fn run_ui<B: Backend>(
     }
 
     let omdb_client = crate::omdb::OMDBClient::new();
-    let tmdb_client = crate::tmdb::TMDBClient::new();
     let mut last_search_query = String::new();
+    let background_task_limit = crate::config::ConfigManager::new()
+        .and_then(|cm| cm.get_tui_background_task_limit())
+        .unwrap_or(4);
+    let background_pool = BackgroundTaskPool::new(background_task_limit);
+    let mut poster_prefetcher = PosterPrefetcher::new(background_pool.clone());
+    let mut last_prefetch_selection: Option<(usize, usize)> = None;
+
+    // Kick off background OMDB enrichment for every loaded row beyond the
+    // first 10 the initial load already covers (see `enrich_with_omdb`), so
+    // the grid's "-" placeholders fill in live as data arrives instead of
+    // only on-demand per page. Skipped entirely in `--skip-enrich` mode.
+    let (enrichment_tx, mut enrichment_rx) = mpsc::unbounded_channel::<EnrichmentUpdate>();
+    if !app.enrichment_skipped {
+        let movies = app.movies_for_background_enrichment();
+        let pool = background_pool.clone();
+        tokio::spawn(pool.run(async move {
+            if let Ok(client) = crate::letterboxd_client_rust::LetterboxdClient::new() {
+                client
+                    .enrich_movies_in_background(movies, enrichment_tx)
+                    .await;
+            }
+        }));
+    }
 
     // Now run the UI loop
     loop {
+        app.background_tasks_active = background_pool.active_count();
+        app.background_task_limit = background_task_limit;
         terminal.draw(|f| app.render(f))?;
 
         // Handle poster loading (simplified for development)
         if let Some(title) = app.get_pending_poster_load() {
             app.clear_pending_poster_load();
+            let info = fetch_poster_info(&title).await;
+            app.set_poster_result(title, info);
+        }
 
-            // Try to get movie details from TMDB and show poster URL
-            let title_clone = title.clone();
-            if let Ok(Some(movie)) = tmdb_client.search_movie(&title_clone).await {
-                if let Some(ref poster_path) = movie.poster_path {
-                    let poster_url = tmdb_client.get_poster_url(poster_path);
-                    let dev_info = format!("🎬 Poster for {}\n\n[Development Mode]\nPoster URL:\n{}\n\nTMDB ID: {}\nRelease: {}", 
-                        title,
-                        poster_url,
-                        movie.id,
-                        movie.release_date.as_deref().unwrap_or("Unknown")
-                    );
-                    app.set_poster_result(title, dev_info);
-                } else {
-                    let fallback = format!("🎬 No poster found for {}\n\n[Development Mode]\nNo poster available on TMDB", title);
-                    app.set_poster_result(title, fallback);
-                }
-            } else {
-                let fallback = format!(
-                    "🎬 Movie not found: {}\n\n[Development Mode]\nTMDB search failed",
-                    title
-                );
-                app.set_poster_result(title, fallback);
-            }
+        // Kick off speculative prefetching when the selection has moved to a
+        // new row, and pick up any prefetches that have finished since.
+        let current_selection = app.active_grid_selection();
+        if current_selection.is_some() && current_selection != last_prefetch_selection {
+            last_prefetch_selection = current_selection;
+            poster_prefetcher.prefetch(app.active_grid_prefetch_candidates());
+        }
+        poster_prefetcher.drain_results(app);
+
+        // Pick up any rows the background enrichment task has finished.
+        while let Ok(update) = enrichment_rx.try_recv() {
+            app.apply_enrichment(update);
         }
 
         // Handle search functionality
@@ -202,7 +445,10 @@ async fn run_ui<B: Backend>(
                         }
                     }
                     KeyCode::Esc => {
-                        if app.is_in_search_mode() {
+                        if app.is_in_search_mode()
+                            || app.is_showing_fullscreen_detail()
+                            || app.is_showing_surprise_reveal()
+                        {
                             app.handle_key(key);
                         } else {
                             break;
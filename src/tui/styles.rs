@@ -88,6 +88,14 @@ impl AppStyles {
         Style::default().fg(self.error_color)
     }
 
+    pub fn warning_border_style(&self) -> Style {
+        Style::default().fg(self.warning_color)
+    }
+
+    pub fn warning_text_style(&self) -> Style {
+        Style::default().fg(self.warning_color)
+    }
+
     pub fn progress_bar_style(&self) -> Style {
         Style::default().fg(self.primary_color)
     }
@@ -116,6 +124,27 @@ impl AppStyles {
         Style::default().fg(color).add_modifier(Modifier::BOLD)
     }
 
+    /// Style for a personal-rating-vs-average delta: green above the crowd,
+    /// red below it, dim text when there's no meaningful difference.
+    pub fn delta_style(&self, delta: f32) -> Style {
+        let color = if delta > 0.05 {
+            self.primary_color
+        } else if delta < -0.05 {
+            self.error_color
+        } else {
+            self.dim_text_color
+        };
+        Style::default().fg(color)
+    }
+
+    /// Style for a list row whose film also appears in the other user's
+    /// diary, in "watch party" side-by-side comparison mode.
+    pub fn shared_entry_style(&self) -> Style {
+        Style::default()
+            .fg(self.secondary_color)
+            .add_modifier(Modifier::BOLD)
+    }
+
     pub fn selected_item_style(&self) -> Style {
         Style::default()
             .bg(Color::Rgb(40, 40, 40))
@@ -0,0 +1,575 @@
+use anyhow::Result;
+use regex::Regex;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::io::{IsTerminal, Write};
+use std::path::Path;
+
+/// Whether stdout is an interactive terminal. When `false` (piped to a file,
+/// redirected in CI, etc.), spinner/animation call sites should fall back to
+/// plain, carriage-return-free line output so logs don't get garbled with
+/// braille frames and `\r` sequences.
+pub fn stdout_is_terminal() -> bool {
+    std::io::stdout().is_terminal()
+}
+
+/// Writes `content` to `path` without ever leaving a truncated/corrupt file in
+/// its place: the data is written to a temp file in the same directory (so the
+/// final `rename` is atomic on the same filesystem) and only swapped into
+/// `path` once the write fully succeeds. A concurrent reader or a crash
+/// mid-write sees either the old file or the new one, never a half-written one.
+pub fn atomic_write(path: &Path, content: &str) -> Result<()> {
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+    let mut temp_file = tempfile::NamedTempFile::new_in(dir)?;
+    temp_file.write_all(content.as_bytes())?;
+    temp_file.flush()?;
+    temp_file.persist(path)?;
+    Ok(())
+}
+
+/// Strips C0/C1 control characters and most zero-width formatting characters
+/// from externally-sourced text (movie titles, reviews, list descriptions)
+/// before it's shown in the terminal or written to an export file.
+/// Letterboxd content is scraped from third-party HTML/RSS and can carry
+/// stray control codes or invisible characters that break terminal layout or
+/// are used for spoofing. Newlines are preserved, and the zero-width joiner
+/// (U+200D) is kept since stripping it would break legitimate multi-codepoint
+/// emoji sequences (e.g. family or flag emoji).
+pub fn sanitize_display_text(text: &str) -> String {
+    text.chars()
+        .filter(|&c| {
+            if c == '\n' || c == '\u{200D}' {
+                return true;
+            }
+            if c.is_control() {
+                return false;
+            }
+            !matches!(
+                c,
+                '\u{200B}' | '\u{200C}' | '\u{200E}' | '\u{200F}' | '\u{FEFF}' | '\u{2060}'
+            )
+        })
+        .collect()
+}
+
+/// Normalizes a movie title for external search lookups (TMDB, OMDB).
+///
+/// Strips trailing asterisks Letterboxd appends to indicate rewatches
+/// (e.g. "Thunderbolts*"), trims surrounding whitespace, and collapses
+/// runs of internal whitespace to a single space.
+pub fn normalize_title(title: &str) -> String {
+    let cleaned = title.trim_end_matches('*').trim();
+    let re = Regex::new(r"\s+").unwrap();
+    re.replace_all(cleaned, " ").to_string()
+}
+
+/// Truncates `text` to at most `max_chars` characters, appending "..." when
+/// truncation actually occurs. Counts by `char`, not byte, so it never splits
+/// a multi-byte UTF-8 sequence (unlike slicing a `str` by byte index).
+pub fn truncate_display_text(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let keep: String = text
+        .chars()
+        .take(max_chars.saturating_sub(3).max(1))
+        .collect();
+    format!("{}...", keep)
+}
+
+/// Extracts the first sentence of `text` (ending at `.`, `!`, or `?`), then
+/// truncates it to `max_chars` via [`truncate_display_text`]. Used for review
+/// excerpts, where showing a sentence fragment reads worse than a clean cut at
+/// punctuation or, failing that, a char boundary.
+pub fn first_sentence_excerpt(text: &str, max_chars: usize) -> String {
+    let trimmed = text.trim();
+    let sentence_end = trimmed
+        .char_indices()
+        .find(|(_, c)| matches!(c, '.' | '!' | '?'))
+        .map(|(i, c)| i + c.len_utf8());
+
+    let sentence = match sentence_end {
+        Some(end) => &trimmed[..end],
+        None => trimmed,
+    };
+
+    truncate_display_text(sentence, max_chars)
+}
+
+/// Deduplicates `items` by a canonical key (e.g. `letterboxd_url`), keeping
+/// whichever duplicate `completeness` scores higher. Ties keep the
+/// first-seen record. Preserves the relative order of first occurrence.
+pub fn dedupe_by_key<T, K, F, C>(items: Vec<T>, mut key_fn: F, mut completeness: C) -> Vec<T>
+where
+    K: Eq + Hash + Clone,
+    F: FnMut(&T) -> K,
+    C: FnMut(&T) -> i32,
+{
+    let mut best: HashMap<K, T> = HashMap::new();
+    let mut order: Vec<K> = Vec::new();
+
+    for item in items {
+        let key = key_fn(&item);
+        match best.get(&key) {
+            Some(existing) if completeness(existing) >= completeness(&item) => {}
+            _ => {
+                if !best.contains_key(&key) {
+                    order.push(key.clone());
+                }
+                best.insert(key, item);
+            }
+        }
+    }
+
+    order.into_iter().filter_map(|k| best.remove(&k)).collect()
+}
+
+/// Deduplicates diary entries from the rustboxd-based profile pipeline by
+/// `(letterboxd_url, watched_date)`, preferring the more-enriched record (more
+/// OMDB/rating fields populated). Guards against the same film appearing
+/// twice across diary pages or re-fetches, while keeping genuine rewatches
+/// (the same film logged again on a different date) as separate entries —
+/// rewatch detection in [`mark_rewatches`] depends on that distinction.
+pub fn dedupe_movie_entries(
+    movies: Vec<crate::profile::UserMovieEntry>,
+) -> Vec<crate::profile::UserMovieEntry> {
+    dedupe_by_key(
+        movies,
+        |m| (m.movie.letterboxd_url.clone(), m.watched_date),
+        |m| {
+            let mut score = 0;
+            if m.movie.director.is_some() {
+                score += 1;
+            }
+            if m.movie.runtime.is_some() {
+                score += 1;
+            }
+            if m.movie.imdb_rating.is_some() {
+                score += 1;
+            }
+            if m.movie.rotten_tomatoes_rating.is_some() {
+                score += 1;
+            }
+            if m.movie.metacritic_rating.is_some() {
+                score += 1;
+            }
+            if m.movie.plot.is_some() {
+                score += 1;
+            }
+            if m.user_rating.is_some() {
+                score += 1;
+            }
+            if m.review.is_some() {
+                score += 1;
+            }
+            score
+        },
+    )
+}
+
+/// Flags entries as rewatches when the same film (matched by normalized,
+/// case-insensitive title) also appears earlier in `movies`. `movies` must be
+/// newest-first, the order diary entries are scraped in, so the
+/// earliest-occurring entry — the true first watch — is the one left
+/// unmarked.
+///
+/// rustboxd doesn't expose Letterboxd's own rewatch marker, so this infers it
+/// purely from repetition within the loaded diary window: a rewatch whose
+/// earlier watch fell outside that window (see `--limit`/`max-diary-entries`)
+/// won't be detected.
+pub fn mark_rewatches(movies: &mut [crate::profile::UserMovieEntry]) {
+    let mut seen = std::collections::HashSet::new();
+    for movie in movies.iter_mut().rev() {
+        let key = normalize_title(&movie.movie.title).to_lowercase();
+        if !seen.insert(key) {
+            movie.rewatched = true;
+        }
+    }
+}
+
+/// Collapses diary entries for the same film logged more than once on the
+/// same calendar day into a single entry, recording how many were merged in
+/// `same_day_rewatch_count` rather than letting per-day/per-film counts get
+/// inflated by what's really one viewing logged in multiple diary entries
+/// (or the reverse: several genuine same-day rewatches double-counted as
+/// distinct films watched). `movies` need not be sorted; the most complete
+/// entry (same scoring as [`dedupe_movie_entries`]) is kept as the
+/// representative.
+pub fn merge_same_day_rewatches(
+    movies: Vec<crate::profile::UserMovieEntry>,
+) -> Vec<crate::profile::UserMovieEntry> {
+    let mut groups: HashMap<(String, chrono::NaiveDate), Vec<crate::profile::UserMovieEntry>> =
+        HashMap::new();
+    let mut order: Vec<(String, chrono::NaiveDate)> = Vec::new();
+    let mut undated = Vec::new();
+
+    for movie in movies {
+        let Some(watched_date) = movie.watched_date else {
+            // No date to group same-day entries by; pass through untouched.
+            undated.push(movie);
+            continue;
+        };
+
+        let key = (
+            normalize_title(&movie.movie.title).to_lowercase(),
+            watched_date.date_naive(),
+        );
+        if !groups.contains_key(&key) {
+            order.push(key.clone());
+        }
+        groups.entry(key).or_default().push(movie);
+    }
+
+    let mut merged: Vec<crate::profile::UserMovieEntry> = order
+        .into_iter()
+        .filter_map(|key| {
+            let mut entries = groups.remove(&key)?;
+            if entries.len() == 1 {
+                return entries.pop();
+            }
+
+            let count = entries.len() as u32;
+            let mut best_index = 0;
+            let mut best_score = -1;
+            for (i, entry) in entries.iter().enumerate() {
+                let score = entry.review.as_ref().map(|r| r.len() as i32).unwrap_or(0)
+                    + entry.user_rating.map(|_| 1).unwrap_or(0)
+                    + entry.liked as i32;
+                if score > best_score {
+                    best_score = score;
+                    best_index = i;
+                }
+            }
+
+            let mut representative = entries.swap_remove(best_index);
+            representative.same_day_rewatch_count = count;
+            Some(representative)
+        })
+        .collect();
+
+    merged.extend(undated);
+    merged
+}
+
+/// Formats a date for display according to the user's configured
+/// `date_format` (ISO/US/EU preset or custom strftime string), falling back
+/// to the ISO preset if config can't be read. The single place `DisplayEngine`,
+/// the TUI, and exports go through so a `config set-date-format` change is
+/// reflected everywhere a date is shown.
+pub fn format_date(date: &chrono::DateTime<chrono::Utc>) -> String {
+    format_naive_date(&date.date_naive())
+}
+
+/// As [`format_date`], for dates that have already been stripped of their
+/// time component (e.g. grouping diary entries by day).
+pub fn format_naive_date(date: &chrono::NaiveDate) -> String {
+    let format = crate::config::ConfigManager::new()
+        .and_then(|cm| cm.get_date_format())
+        .unwrap_or_default();
+    date.format(format.strftime_pattern()).to_string()
+}
+
+/// Renders `date` relative to `now` as "just now", "N minutes/hours ago",
+/// "yesterday", "N days/weeks ago", "last month"/"N months ago". Returns
+/// `None` for dates a year or older, or in the future — callers should fall
+/// back to an absolute date (see [`format_date`]) in that case.
+pub fn humanize_relative_date(
+    date: &chrono::DateTime<chrono::Utc>,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Option<String> {
+    let delta = now.signed_duration_since(*date);
+    if delta.num_seconds() < 0 {
+        return None;
+    }
+
+    let minutes = delta.num_minutes();
+    let hours = delta.num_hours();
+    let days = delta.num_days();
+
+    if minutes < 1 {
+        Some("just now".to_string())
+    } else if minutes < 60 {
+        Some(format!(
+            "{} minute{} ago",
+            minutes,
+            if minutes == 1 { "" } else { "s" }
+        ))
+    } else if hours < 24 {
+        Some(format!(
+            "{} hour{} ago",
+            hours,
+            if hours == 1 { "" } else { "s" }
+        ))
+    } else if days == 1 {
+        Some("yesterday".to_string())
+    } else if days < 7 {
+        Some(format!("{} days ago", days))
+    } else if days < 30 {
+        let weeks = days / 7;
+        Some(format!(
+            "{} week{} ago",
+            weeks,
+            if weeks == 1 { "" } else { "s" }
+        ))
+    } else if days < 365 {
+        let months = days / 30;
+        if months <= 1 {
+            Some("last month".to_string())
+        } else {
+            Some(format!("{} months ago", months))
+        }
+    } else {
+        None
+    }
+}
+
+/// Formats `date` relative to now when `relative_dates` is enabled in the
+/// saved config, falling back to the absolute `date_format` otherwise — or
+/// for dates a year or older, where a relative phrase stops being useful.
+/// The single place `DisplayEngine` and the TUI go through so the setting
+/// is reflected everywhere a watch date is shown.
+pub fn format_watch_date(date: &chrono::DateTime<chrono::Utc>) -> String {
+    let relative_enabled = crate::config::ConfigManager::new()
+        .and_then(|cm| cm.get_relative_dates())
+        .unwrap_or(false);
+
+    if relative_enabled {
+        if let Some(relative) = humanize_relative_date(date, chrono::Utc::now()) {
+            return relative;
+        }
+    }
+
+    format_date(date)
+}
+
+/// Formats a runtime in minutes as e.g. "3h 42m".
+pub fn format_runtime_minutes(minutes: u16) -> String {
+    if minutes >= 60 {
+        format!("{}h {}m", minutes / 60, minutes % 60)
+    } else {
+        format!("{}m", minutes)
+    }
+}
+
+/// Parses a short relative duration like `30s`, `45m`, `1h`, `2d`, or `1w`
+/// into a [`chrono::Duration`], for flags such as `recent --max-age` that
+/// take a human-typed freshness window rather than an absolute timestamp.
+pub fn parse_duration(input: &str) -> Result<chrono::Duration> {
+    let input = input.trim();
+    let split_at = input.find(|c: char| !c.is_ascii_digit()).ok_or_else(|| {
+        anyhow::anyhow!(
+            "Invalid duration '{}': missing unit (e.g. 1h, 30m, 2d)",
+            input
+        )
+    })?;
+    let (amount, unit) = input.split_at(split_at);
+    let amount: i64 = amount.parse().map_err(|_| {
+        anyhow::anyhow!(
+            "Invalid duration '{}': expected a number before the unit",
+            input
+        )
+    })?;
+
+    match unit {
+        "s" => Ok(chrono::Duration::seconds(amount)),
+        "m" => Ok(chrono::Duration::minutes(amount)),
+        "h" => Ok(chrono::Duration::hours(amount)),
+        "d" => Ok(chrono::Duration::days(amount)),
+        "w" => Ok(chrono::Duration::weeks(amount)),
+        other => Err(anyhow::anyhow!(
+            "Invalid duration unit '{}' in '{}': expected one of s, m, h, d, w",
+            other,
+            input
+        )),
+    }
+}
+
+/// Deduplicates RSS feed entries by `letterboxd_url`, preferring the
+/// more-enriched record. Guards against the same film appearing twice when
+/// merging RSS and native diary data for the same user.
+pub fn dedupe_user_entries(
+    entries: Vec<crate::models::UserEntry>,
+) -> Vec<crate::models::UserEntry> {
+    dedupe_by_key(
+        entries,
+        |e| e.movie.letterboxd_url.clone(),
+        |e| {
+            let mut score = 0;
+            if e.movie.director.is_some() {
+                score += 1;
+            }
+            if e.movie.poster_url.is_some() {
+                score += 1;
+            }
+            if e.rating.is_some() {
+                score += 1;
+            }
+            if e.review.is_some() {
+                score += 1;
+            }
+            score
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_title_strips_trailing_rewatch_asterisk() {
+        assert_eq!(normalize_title("Thunderbolts*"), "Thunderbolts");
+    }
+
+    #[test]
+    fn normalize_title_collapses_internal_whitespace() {
+        assert_eq!(normalize_title("The   Dark   Knight"), "The Dark Knight");
+    }
+
+    #[test]
+    fn normalize_title_trims_surrounding_whitespace() {
+        assert_eq!(normalize_title("  Paddington 2  "), "Paddington 2");
+    }
+
+    #[test]
+    fn normalize_title_handles_asterisk_then_whitespace_together() {
+        assert_eq!(normalize_title("  Poor   Things*"), "Poor Things");
+    }
+
+    fn movie_entry(
+        director: Option<&str>,
+        imdb_rating: Option<f32>,
+    ) -> crate::profile::UserMovieEntry {
+        crate::profile::UserMovieEntry {
+            movie: crate::profile::DetailedMovie {
+                title: "Paddington 2".to_string(),
+                year: None,
+                director: director.map(|d| d.to_string()),
+                genres: Vec::new(),
+                runtime: None,
+                poster_url: None,
+                letterboxd_url: "https://letterboxd.com/film/paddington-2/".to_string(),
+                tmdb_url: None,
+                cast: Vec::new(),
+                synopsis: None,
+                letterboxd_rating: None,
+                imdb_rating,
+                rotten_tomatoes_rating: None,
+                metacritic_rating: None,
+                imdb_id: None,
+                release_date: None,
+                plot: None,
+                awards: None,
+            },
+            user_rating: None,
+            review: None,
+            watched_date: None,
+            liked: false,
+            rewatched: false,
+            tags: Vec::new(),
+            same_day_rewatch_count: 1,
+        }
+    }
+
+    #[test]
+    fn sanitize_display_text_strips_c0_and_c1_controls() {
+        assert_eq!(
+            sanitize_display_text("a\u{0007}b\u{001B}c\u{0080}d"),
+            "abcd"
+        );
+    }
+
+    #[test]
+    fn sanitize_display_text_strips_each_zero_width_codepoint() {
+        assert_eq!(
+            sanitize_display_text("a\u{200B}b\u{200C}c\u{200E}d\u{200F}e\u{FEFF}f\u{2060}g"),
+            "abcdefg"
+        );
+    }
+
+    #[test]
+    fn sanitize_display_text_preserves_newline() {
+        assert_eq!(
+            sanitize_display_text("line one\nline two"),
+            "line one\nline two"
+        );
+    }
+
+    #[test]
+    fn sanitize_display_text_preserves_zwj_emoji_sequence() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        assert_eq!(sanitize_display_text(family), family);
+    }
+
+    #[test]
+    fn dedupe_movie_entries_keeps_the_more_complete_near_duplicate() {
+        let sparse = movie_entry(None, None);
+        let enriched = movie_entry(Some("Paul King"), Some(7.8));
+
+        let deduped = dedupe_movie_entries(vec![sparse, enriched]);
+
+        assert_eq!(deduped.len(), 1);
+        assert_eq!(deduped[0].movie.director.as_deref(), Some("Paul King"));
+        assert_eq!(deduped[0].movie.imdb_rating, Some(7.8));
+    }
+
+    fn titled_entry(
+        title: &str,
+        watched_date: chrono::DateTime<chrono::Utc>,
+    ) -> crate::profile::UserMovieEntry {
+        let mut entry = movie_entry(None, None);
+        entry.movie.title = title.to_string();
+        entry.watched_date = Some(watched_date);
+        entry
+    }
+
+    #[test]
+    fn mark_rewatches_leaves_the_earliest_watch_of_each_film_unmarked() {
+        use chrono::TimeZone;
+        let newest = chrono::Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let oldest = chrono::Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+
+        // Correct input order: newest-first, as scraped.
+        let mut movies = vec![
+            titled_entry("Paddington 2", newest),
+            titled_entry("Paddington 2", oldest),
+        ];
+
+        mark_rewatches(&mut movies);
+
+        assert!(movies[0].rewatched, "the more recent watch is a rewatch");
+        assert!(
+            !movies[1].rewatched,
+            "the earliest watch is the true first viewing"
+        );
+    }
+
+    #[test]
+    fn mark_rewatches_on_an_oldest_first_list_marks_the_wrong_entry() {
+        use chrono::TimeZone;
+        let newest = chrono::Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let oldest = chrono::Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+
+        // `mark_rewatches` requires a newest-first list; feeding it the
+        // reverse order flips which occurrence gets left unmarked, so the
+        // true first viewing (by `watched_date`) ends up flagged as the
+        // rewatch instead. This documents that failure mode rather than
+        // asserting desired behavior — callers must sort newest-first
+        // before calling.
+        let mut movies = vec![
+            titled_entry("Paddington 2", oldest),
+            titled_entry("Paddington 2", newest),
+        ];
+
+        mark_rewatches(&mut movies);
+
+        assert!(
+            movies[0].rewatched,
+            "the true first viewing is wrongly marked as a rewatch"
+        );
+        assert!(
+            !movies[1].rewatched,
+            "the actual rewatch is wrongly left unmarked"
+        );
+    }
+}
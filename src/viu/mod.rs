@@ -1,8 +1,21 @@
 use anyhow::Result;
 use std::io::Write;
 use std::process::Command;
+use std::sync::OnceLock;
 use tempfile::NamedTempFile;
 
+/// Whether the `viu` binary was found the first time it was checked.
+/// Spawning a subprocess just to probe for `--help` on every poster in a
+/// listing is wasteful and, on a system without `viu` installed, was
+/// printing the full installation instructions once per poster. Caching the
+/// determination for the life of the process fixes both.
+static VIU_AVAILABLE: OnceLock<bool> = OnceLock::new();
+
+/// Set once [`ViuViewer::is_available`] has reported `false` and the caller
+/// has shown the installation instructions, so repeated poster attempts fall
+/// back silently instead of repeating the same message.
+static WARNED_UNAVAILABLE: OnceLock<()> = OnceLock::new();
+
 pub struct ViuViewer {
     client: reqwest::Client,
 }
@@ -23,16 +36,24 @@ impl ViuViewer {
         Self { client }
     }
 
-    /// Check if viu is available on the system
+    /// Check if viu is available on the system. The result of the first
+    /// check is cached for the rest of the process's lifetime, since `viu`
+    /// isn't installed or removed mid-run.
     pub fn is_available() -> bool {
-        // Check for viu command
-        if let Ok(output) = Command::new("viu").arg("--help").output() {
-            if output.status.success() {
-                return true;
-            }
-        }
+        *VIU_AVAILABLE.get_or_init(|| {
+            Command::new("viu")
+                .arg("--help")
+                .output()
+                .map(|output| output.status.success())
+                .unwrap_or(false)
+        })
+    }
 
-        false
+    /// Returns `true` the first time it's called after `viu` was found
+    /// unavailable, and `false` on every subsequent call, so a missing `viu`
+    /// binary produces one informative message instead of one per poster.
+    pub fn should_warn_unavailable() -> bool {
+        WARNED_UNAVAILABLE.set(()).is_ok()
     }
 
     /// Display an image using viu with optimal settings
@@ -41,9 +62,15 @@ impl ViuViewer {
         image_url: &str,
         width: u32,
         use_pixelated_mode: bool,
+        grayscale: bool,
     ) -> Result<()> {
         // Download the image to a temporary file
         let image_data = self.fetch_image(image_url).await?;
+        let image_data = if grayscale {
+            Self::desaturate(&image_data).unwrap_or(image_data)
+        } else {
+            image_data
+        };
 
         // Create temporary file
         let mut temp_file = NamedTempFile::new()?;
@@ -92,6 +119,44 @@ impl ViuViewer {
         Err(anyhow::anyhow!("Failed to display image with viu"))
     }
 
+    /// Displays an image via `viu` if it's installed, falling back to the
+    /// pure-Rust ASCII renderer in [`crate::ascii`] otherwise, so a poster
+    /// still shows up on a machine without `viu` rather than just a warning.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn display_image_url_or_ascii(
+        &self,
+        image_url: &str,
+        width: u32,
+        use_pixelated_mode: bool,
+        grayscale: bool,
+        ascii_depth: crate::config::AsciiColorDepth,
+        poster_style: crate::config::PosterStyle,
+        braille_threshold: u8,
+    ) -> Result<()> {
+        if Self::is_available() {
+            return self
+                .display_image_url(image_url, width, use_pixelated_mode, grayscale)
+                .await;
+        }
+
+        let image_data = self.fetch_image(image_url).await?;
+        let image_data = if grayscale {
+            Self::desaturate(&image_data).unwrap_or(image_data)
+        } else {
+            image_data
+        };
+        let art = match poster_style {
+            crate::config::PosterStyle::Blocks => {
+                crate::ascii::render(&image_data, width, ascii_depth)?
+            }
+            crate::config::PosterStyle::Braille => {
+                crate::ascii::render_braille(&image_data, width, braille_threshold)?
+            }
+        };
+        print!("{}", art);
+        Ok(())
+    }
+
     /// Display an image from local file path using viu
     pub fn display_image_file(&self, file_path: &str, width: u32) -> Result<()> {
         // Try -b first, then --blocks for compatibility
@@ -149,8 +214,22 @@ Alternative: Use --ascii flag for ASCII art display
         .to_string()
     }
 
+    /// Converts encoded image bytes to a grayscale PNG, so `ColorMode::Grayscale`
+    /// (or an explicit `PosterGrayscale::On` override) actually desaturates the
+    /// poster `viu` displays, not just surrounding text colors. Returns `Err`
+    /// on a format `image` can't decode; callers fall back to the original bytes.
+    fn desaturate(image_data: &[u8]) -> Result<Vec<u8>> {
+        let image = image::load_from_memory(image_data)?;
+        let mut buffer = Vec::new();
+        image.grayscale().write_to(
+            &mut std::io::Cursor::new(&mut buffer),
+            image::ImageFormat::Png,
+        )?;
+        Ok(buffer)
+    }
+
     async fn fetch_image(&self, url: &str) -> Result<Vec<u8>> {
-        let response = self.client.get(url).send().await?;
+        let mut response = self.client.get(url).send().await?;
 
         if !response.status().is_success() {
             return Err(anyhow::anyhow!(
@@ -159,7 +238,43 @@ Alternative: Use --ascii flag for ASCII art display
             ));
         }
 
-        let bytes = response.bytes().await?;
-        Ok(bytes.to_vec())
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("");
+        if !content_type.is_empty() && !content_type.starts_with("image/") {
+            return Err(anyhow::anyhow!(
+                "Refusing to decode non-image content-type: {}",
+                content_type
+            ));
+        }
+
+        let max_bytes = crate::config::ConfigManager::new()
+            .and_then(|cm| cm.get_max_image_download_bytes())
+            .unwrap_or(10 * 1024 * 1024);
+
+        if let Some(content_length) = response.content_length() {
+            if content_length > max_bytes {
+                return Err(anyhow::anyhow!(
+                    "Image too large: {} bytes exceeds the {} byte limit",
+                    content_length,
+                    max_bytes
+                ));
+            }
+        }
+
+        let mut bytes = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            bytes.extend_from_slice(&chunk);
+            if bytes.len() as u64 > max_bytes {
+                return Err(anyhow::anyhow!(
+                    "Image download aborted: exceeded the {} byte limit",
+                    max_bytes
+                ));
+            }
+        }
+
+        Ok(bytes)
     }
 }
@@ -0,0 +1,276 @@
+//! Renders the `lbxd wrapped` shareable year-in-review card: a single PNG
+//! combining headline stats with a mosaic of the year's top-rated posters,
+//! in the spirit of Spotify Wrapped.
+
+use crate::profile::{EnhancedStatistics, UserMovieEntry};
+use crate::tmdb::TMDBClient;
+use anyhow::Result;
+use image::{DynamicImage, Rgba, RgbaImage};
+
+/// Visual theme for the card: background and text colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WrappedTheme {
+    #[default]
+    Dark,
+    Light,
+    Vibrant,
+}
+
+impl WrappedTheme {
+    fn background(self) -> Rgba<u8> {
+        match self {
+            WrappedTheme::Dark => Rgba([18, 18, 20, 255]),
+            WrappedTheme::Light => Rgba([245, 245, 245, 255]),
+            WrappedTheme::Vibrant => Rgba([255, 88, 93, 255]),
+        }
+    }
+
+    fn text(self) -> Rgba<u8> {
+        match self {
+            WrappedTheme::Dark => Rgba([255, 255, 255, 255]),
+            WrappedTheme::Light => Rgba([20, 20, 20, 255]),
+            WrappedTheme::Vibrant => Rgba([255, 255, 255, 255]),
+        }
+    }
+}
+
+/// Dimensions and styling for the generated card. Defaults to a 9:16
+/// portrait size suited to Instagram/TikTok stories.
+#[derive(Debug, Clone, Copy)]
+pub struct WrappedConfig {
+    pub width: u32,
+    pub height: u32,
+    pub theme: WrappedTheme,
+}
+
+impl Default for WrappedConfig {
+    fn default() -> Self {
+        Self {
+            width: 1080,
+            height: 1920,
+            theme: WrappedTheme::Dark,
+        }
+    }
+}
+
+/// How many top-rated posters to feature in the mosaic.
+const MOSAIC_COUNT: usize = 6;
+const MOSAIC_COLUMNS: u32 = 3;
+const MOSAIC_CELL_SIZE: u32 = 300;
+const MOSAIC_GAP: u32 = 16;
+
+/// Generates the wrapped card for `username`'s `year` and writes it as a PNG
+/// to `output_path`. `movies` should already be filtered to the target year.
+pub async fn generate_wrapped_card(
+    username: &str,
+    year: i32,
+    movies: &[UserMovieEntry],
+    stats: &EnhancedStatistics,
+    config: WrappedConfig,
+) -> Result<RgbaImage> {
+    let mut canvas = RgbaImage::from_pixel(config.width, config.height, config.theme.background());
+
+    let font = load_font();
+    let text_color = config.theme.text();
+
+    let mut y = 80i32;
+    let title = format!("{username}'s {year} Wrapped");
+    draw_line(&mut canvas, font.as_ref(), &title, 48.0, text_color, &mut y);
+    y += 40;
+
+    draw_line(
+        &mut canvas,
+        font.as_ref(),
+        &format!("{} films watched", movies.len()),
+        36.0,
+        text_color,
+        &mut y,
+    );
+    draw_line(
+        &mut canvas,
+        font.as_ref(),
+        &format!(
+            "{:.0} hours watched",
+            stats.basic_stats.total_viewing_time_hours
+        ),
+        36.0,
+        text_color,
+        &mut y,
+    );
+    draw_line(
+        &mut canvas,
+        font.as_ref(),
+        &format!("Average rating: {:.1}★", stats.basic_stats.average_rating),
+        36.0,
+        text_color,
+        &mut y,
+    );
+    if let Some(genre) = stats.genre_breakdown.first() {
+        draw_line(
+            &mut canvas,
+            font.as_ref(),
+            &format!("Top genre: {} ({} films)", genre.name, genre.count),
+            36.0,
+            text_color,
+            &mut y,
+        );
+    }
+    if let Some(director) = stats.director_stats.first() {
+        draw_line(
+            &mut canvas,
+            font.as_ref(),
+            &format!(
+                "Top director: {} ({} films)",
+                director.name, director.film_count
+            ),
+            36.0,
+            text_color,
+            &mut y,
+        );
+    }
+
+    y += 40;
+    let tmdb_client = TMDBClient::new();
+    let posters = fetch_top_rated_posters(&tmdb_client, movies).await;
+    draw_poster_mosaic(&mut canvas, &posters, y, config.width);
+
+    Ok(canvas)
+}
+
+fn draw_line(
+    canvas: &mut RgbaImage,
+    font: Option<&ab_glyph::FontVec>,
+    text: &str,
+    scale: f32,
+    color: Rgba<u8>,
+    y: &mut i32,
+) {
+    if let Some(font) = font {
+        imageproc::drawing::draw_text_mut(
+            canvas,
+            color,
+            60,
+            *y,
+            ab_glyph::PxScale::from(scale),
+            font,
+            text,
+        );
+    }
+    *y += scale as i32 + 20;
+}
+
+/// Loads a system font for text rendering. No font ships with the crate
+/// (binary font assets don't belong in the repo), so this checks a handful of
+/// common per-platform install locations and returns `None` if none are
+/// found, in which case the card is still generated with the poster mosaic
+/// but no overlaid text.
+fn load_font() -> Option<ab_glyph::FontVec> {
+    const CANDIDATE_PATHS: &[&str] = &[
+        "/usr/share/fonts/truetype/dejavu/DejaVuSans-Bold.ttf",
+        "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf",
+        "/usr/share/fonts/truetype/liberation/LiberationSans-Bold.ttf",
+        "/System/Library/Fonts/Supplemental/Arial Bold.ttf",
+        "/System/Library/Fonts/Supplemental/Arial.ttf",
+        "C:\\Windows\\Fonts\\arialbd.ttf",
+        "C:\\Windows\\Fonts\\arial.ttf",
+    ];
+
+    for path in CANDIDATE_PATHS {
+        if let Ok(bytes) = std::fs::read(path) {
+            if let Ok(font) = ab_glyph::FontVec::try_from_vec(bytes) {
+                return Some(font);
+            }
+        }
+    }
+
+    None
+}
+
+/// Fetches poster art for the year's top-rated films (by personal rating),
+/// via TMDB title search, for the mosaic. Films with no TMDB match or no
+/// poster are simply skipped rather than failing the whole card.
+async fn fetch_top_rated_posters(
+    tmdb_client: &TMDBClient,
+    movies: &[UserMovieEntry],
+) -> Vec<DynamicImage> {
+    let mut rated: Vec<&UserMovieEntry> =
+        movies.iter().filter(|m| m.user_rating.is_some()).collect();
+    rated.sort_by(|a, b| b.user_rating.partial_cmp(&a.user_rating).unwrap());
+    rated.truncate(MOSAIC_COUNT);
+
+    let max_bytes = crate::config::ConfigManager::new()
+        .and_then(|cm| cm.get_max_image_download_bytes())
+        .unwrap_or(10 * 1024 * 1024);
+    let http_client = reqwest::Client::new();
+
+    let mut posters = Vec::new();
+    for entry in rated {
+        let year = entry.movie.year.map(i32::from);
+        let Ok(Some(tmdb_movie)) = tmdb_client
+            .search_movie_with_year(&entry.movie.title, year)
+            .await
+        else {
+            continue;
+        };
+
+        let Some(poster_url) = tmdb_movie.get_high_quality_poster_url() else {
+            continue;
+        };
+
+        if let Some(image) = fetch_image(&http_client, &poster_url, max_bytes).await {
+            posters.push(image);
+        }
+    }
+
+    posters
+}
+
+async fn fetch_image(client: &reqwest::Client, url: &str, max_bytes: u64) -> Option<DynamicImage> {
+    let mut response = client.get(url).send().await.ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+
+    if let Some(content_length) = response.content_length() {
+        if content_length > max_bytes {
+            return None;
+        }
+    }
+
+    let mut bytes = Vec::new();
+    while let Some(chunk) = response.chunk().await.ok()? {
+        bytes.extend_from_slice(&chunk);
+        if bytes.len() as u64 > max_bytes {
+            return None;
+        }
+    }
+
+    image::load_from_memory(&bytes).ok()
+}
+
+/// Composites `posters` into a grid starting at `start_y`, centered across
+/// `canvas_width`.
+fn draw_poster_mosaic(
+    canvas: &mut RgbaImage,
+    posters: &[DynamicImage],
+    start_y: i32,
+    canvas_width: u32,
+) {
+    let columns = MOSAIC_COLUMNS.min(posters.len().max(1) as u32);
+    let grid_width = columns * MOSAIC_CELL_SIZE + (columns.saturating_sub(1)) * MOSAIC_GAP;
+    let start_x = (canvas_width as i32 - grid_width as i32).max(0) / 2;
+
+    for (i, poster) in posters.iter().enumerate() {
+        let col = i as u32 % columns;
+        let row = i as u32 / columns;
+        let x = start_x + (col * (MOSAIC_CELL_SIZE + MOSAIC_GAP)) as i32;
+        let y = start_y + (row * (MOSAIC_CELL_SIZE + MOSAIC_GAP)) as i32;
+
+        let thumbnail = poster.resize_to_fill(
+            MOSAIC_CELL_SIZE,
+            MOSAIC_CELL_SIZE,
+            image::imageops::FilterType::Lanczos3,
+        );
+        image::imageops::overlay(canvas, &thumbnail.to_rgba8(), x as i64, y as i64);
+    }
+}